@@ -0,0 +1,83 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: benches/mesh_generation.rs                     ║
+// ║  Mesure des temps de génération des meshes                               ║
+// ║  Rôle : Repérer les régressions de performance de la géométrie          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Benchmarks de génération des meshes
+//! 
+//! Lancement : `cargo bench`
+//! 
+//! Mesures faites par Criterion : chaque groupe compare plusieurs tailles
+//! d'entrée, et Criterion signale lui-même les écarts avec l'exécution
+//! précédente (rapports dans `target/criterion`).
+
+use std::hint::black_box;
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use LogoRust_Bevy_20250929::geometry;
+
+/// Contour régulier de `count` sommets (polygone convexe)
+fn regular_polygon(count: usize, radius: f32) -> Vec<Vec2> {
+    (0..count)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / count as f32;
+            Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Anneaux et disques, selon le nombre de segments
+fn circles(c: &mut Criterion) {
+    // === ANNEAUX ===
+    let mut group = c.benchmark_group("create_circle_mesh");
+    for segments in [32, 64, 128, 256, 512] {
+        group.bench_with_input(BenchmarkId::from_parameter(segments), &segments, |b, &segments| {
+            b.iter(|| geometry::create_circle_mesh(200.0, 150.0, black_box(segments)))
+        });
+    }
+    group.finish();
+    
+    // === DISQUES ===
+    let mut group = c.benchmark_group("create_filled_circle_mesh");
+    for segments in [16, 32, 64, 128, 256] {
+        group.bench_with_input(BenchmarkId::from_parameter(segments), &segments, |b, &segments| {
+            b.iter(|| geometry::create_filled_circle_mesh(20.0, black_box(segments)))
+        });
+    }
+    group.finish();
+}
+
+/// Polygones convexes (contour régulier) et concaves (cœur)
+fn polygons(c: &mut Criterion) {
+    let mut group = c.benchmark_group("create_polygon_from_points");
+    for count in [8, 32, 128, 512] {
+        let points = regular_polygon(count, 100.0);
+        group.bench_with_input(BenchmarkId::new("regulier", count), &points, |b, points| {
+            b.iter(|| geometry::create_polygon_from_points(black_box(points)))
+        });
+    }
+    for segments in [16, 64, 256] {
+        let points = geometry::heart_outline(200.0, segments);
+        group.bench_with_input(BenchmarkId::new("coeur", segments), &points, |b, points| {
+            b.iter(|| geometry::create_polygon_from_points(black_box(points)))
+        });
+    }
+    group.finish();
+}
+
+/// Lettre complète : définition des parties + un mesh par partie, comme au setup
+fn letter(c: &mut Criterion) {
+    c.bench_function("get_all_r_parts + meshes", |b| {
+        b.iter(|| {
+            geometry::get_all_r_parts()
+                .iter()
+                .map(|part| geometry::create_polygon_from_points(&part.points))
+                .collect::<Vec<Mesh>>()
+        })
+    });
+}
+
+criterion_group!(benches, circles, polygons, letter);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: examples/embed.rs                         ║
+// ║  Exemple : le logo intégré dans une application Bevy existante           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Ajoute `LogoPlugin` à une application qui a sa propre caméra et son
+//! propre contenu (un carré qui rebondit sur les bords de la fenêtre)
+//! 
+//! Seul le cœur du logo est ajouté : ni `LogoInteractionPlugin` (clavier,
+//! souris, caméra), ni `LogoDebugPlugin`. La caméra et les entrées restent
+//! entièrement à l'application.
+//! 
+//! ```text
+//! cargo run --example embed
+//! ```
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::systems::setup::setup_system;
+use LogoRust_Bevy_20250929::{LogoConfig, LogoPlugin, MainCamera};
+
+/// Vitesse du carré, en pixels par seconde
+#[derive(Component)]
+struct Velocity(Vec2);
+
+fn main() {
+    let config = LogoConfig::builder()
+        .exterior_count(24)
+        .background(Color::srgb(0.1, 0.1, 0.15))
+        .drop_shadow(true)
+        .build()
+        .expect("configuration valide");
+    
+    App::new()
+        .add_plugins((DefaultPlugins, LogoPlugin::new(config)))
+        // Caméra de l'application : marquée MainCamera avant le setup du
+        // logo, qui n'en crée alors pas de seconde
+        .add_systems(Startup, spawn_camera_system.before(setup_system))
+        .add_systems(Startup, spawn_bouncer_system)
+        .add_systems(Update, bounce_system)
+        .run();
+}
+
+/// Crée la caméra de l'application
+fn spawn_camera_system(mut commands: Commands) {
+    commands.spawn((Camera2d, MainCamera));
+}
+
+/// Crée le carré rebondissant, devant le logo
+fn spawn_bouncer_system(mut commands: Commands) {
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.2, 0.7, 1.0), Vec2::splat(40.0)),
+        Transform::from_xyz(0.0, 0.0, 10.0),
+        Velocity(Vec2::new(220.0, 160.0)),
+    ));
+}
+
+/// Déplace le carré et le fait rebondir sur les bords de la fenêtre
+fn bounce_system(
+    time: Res<Time>,
+    windows: Query<&Window>,
+    mut bouncers: Query<(&mut Transform, &mut Velocity)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let half = window.size() / 2.0 - Vec2::splat(20.0);
+    
+    for (mut transform, mut velocity) in &mut bouncers {
+        transform.translation += (velocity.0 * time.delta_secs()).extend(0.0);
+        if transform.translation.x.abs() > half.x {
+            velocity.0.x = -velocity.0.x;
+            transform.translation.x = transform.translation.x.clamp(-half.x, half.x);
+        }
+        if transform.translation.y.abs() > half.y {
+            velocity.0.y = -velocity.0.y;
+            transform.translation.y = transform.translation.y.clamp(-half.y, half.y);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                      FICHIER: examples/smiley.rs                         ║
+// ║  Exemple : un smiley dessiné avec le module draw                         ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Dessine un smiley avec `spawn_circle`, `spawn_polygon` et `spawn_line`
+//! 
+//! ```text
+//! cargo run --example smiley
+//! ```
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::{spawn_circle, spawn_line, spawn_polygon};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, smiley_system)
+        .run();
+}
+
+/// Crée la caméra et le smiley
+fn smiley_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2d);
+    
+    let yellow = Color::srgb(1.0, 0.85, 0.1);
+    let dark = Color::srgb(0.2, 0.1, 0.05);
+    
+    // === VISAGE ===
+    spawn_circle(&mut commands, &mut meshes, &mut materials, Vec2::ZERO, 200.0, yellow, 0.0);
+    
+    // === YEUX ===
+    for x in [-70.0, 70.0] {
+        spawn_circle(&mut commands, &mut meshes, &mut materials, Vec2::new(x, 60.0), 25.0, dark, 0.1);
+    }
+    
+    // === NEZ ===
+    let nose = [Vec2::new(0.0, 20.0), Vec2::new(-15.0, -20.0), Vec2::new(15.0, -20.0)];
+    spawn_polygon(&mut commands, &mut meshes, &mut materials, &nose, Color::srgb(0.9, 0.5, 0.1), 0.1);
+    
+    // === BOUCHE ===
+    // Arc de cercle sous le nez, découpé en segments droits
+    let arc: Vec<Vec2> = (0..=12)
+        .map(|i| {
+            let angle = (200.0 + 140.0 * i as f32 / 12.0_f32).to_radians();
+            Vec2::from_angle(angle) * 120.0
+        })
+        .collect();
+    for pair in arc.windows(2) {
+        spawn_line(&mut commands, &mut meshes, &mut materials, (pair[0], pair[1]), 12.0, dark, 0.1);
+    }
+}
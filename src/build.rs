@@ -0,0 +1,538 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/build.rs                             ║
+// ║  Construction des éléments du logo, sans ECS                             ║
+// ║  Rôle : Calculer meshes, couleurs et positions (testable sans App)      ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module de construction
+//! 
+//! Chaque fonction `build_*` calcule, à partir de la configuration, ce qui
+//! doit être affiché : un mesh, un matériau et un `Transform` par élément.
+//! Aucune entité n'est créée ici : le module `systems::setup` se contente
+//! d'ajouter ces éléments aux assets et de les spawner avec leurs marqueurs.
+//! 
+//! Cette séparation permet de vérifier "ce qui est construit" (nombre
+//! d'éléments, positions, couleurs) dans de simples tests unitaires.
+//! 
+//! Organisation du rendu par profondeur Z (valeurs par défaut, modifiables
+//! par `LogoConfig::z_ring`, `z_exterior`, `z_interior`, `z_hub`, `z_stars`, `z_dots`) :
+//! - Z = 0.0  : Cercle principal (arrière-plan)
+//! - Z = 0.1  : Triangles extérieurs (ou anneau en dégradé)
+//! - Z = 0.2  : Triangles intérieurs
+//! - Z = 0.22 : Disque central (optionnel)
+//! - Z = 0.25 : Étoiles sous les petits cercles (optionnelles)
+//! - Z = 0.3  : Petits cercles
+//! - Z = 0.4+ : Lettre du logo (voir `LogoConfig::r_base_z`)
+
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+use crate::{materials, geometry};
+use crate::geometry::{PolygonDefect, RPartDefinition, Winding};
+use crate::config::{self, LogoConfig};
+
+/// Un élément prêt à être spawné : mesh, matériau et position
+pub type BuiltElement = (Mesh, ColorMaterial, Transform);
+
+// === PROFONDEURS DES COUCHES ===
+// Valeurs par défaut des champs `z_*` de `LogoConfig`, définies dans config
+pub use crate::config::{Z_CENTER_DOTS, Z_CENTER_HUB, Z_CENTER_STARS, Z_EXTERIOR_TRIANGLES, Z_INTERIOR_TRIANGLES, Z_MAIN_CIRCLE};
+
+/// Couches du logo, de l'arrière vers l'avant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LayerKind {
+    /// Grille de référence (optionnelle)
+    Grid,
+    
+    /// Spirale décorative (optionnelle)
+    Spiral,
+    
+    /// Cercle principal (anneau épais)
+    MainCircle,
+    
+    /// Triangles extérieurs ou anneau arc-en-ciel en dégradé
+    ExteriorTriangles,
+    
+    /// Triangles intérieurs
+    InteriorTriangles,
+    
+    /// Disque central (optionnel)
+    CenterHub,
+    
+    /// Étoiles sous les petits cercles (optionnelles)
+    CenterStars,
+    
+    /// Petits cercles centraux
+    CenterDots,
+    
+    /// Lettre du logo (première partie ; les suivantes sont espacées de `r_z_step`)
+    Letter,
+}
+
+impl LayerKind {
+    /// Nom de la couche, pour les messages de la console
+    pub fn label(self) -> &'static str {
+        match self {
+            LayerKind::Grid => "Grille de référence",
+            LayerKind::Spiral => "Spirale de fond",
+            LayerKind::MainCircle => "Cercle principal",
+            LayerKind::ExteriorTriangles => "Triangles extérieurs",
+            LayerKind::InteriorTriangles => "Triangles intérieurs",
+            LayerKind::CenterHub => "Disque central",
+            LayerKind::CenterStars => "Étoiles centrales",
+            LayerKind::CenterDots => "Petits cercles",
+            LayerKind::Letter => "Lettre du logo",
+        }
+    }
+}
+
+/// Parcourt les couches du logo avec leur profondeur Z
+/// 
+/// Source unique des profondeurs : les couches sont listées dans l'ordre
+/// canonique (de la grille à la lettre), avec le Z de la configuration.
+/// Avec `LogoConfig::default()`, les Z sont strictement croissants.
+/// La profondeur de la lettre est celle de sa première partie
+/// (`LogoConfig::r_base_z`).
+pub fn logo_layers(config: &LogoConfig) -> impl Iterator<Item = (LayerKind, f32)> {
+    [
+        (LayerKind::Grid, config::GRID_Z),
+        (LayerKind::Spiral, config::SPIRAL_Z),
+        (LayerKind::MainCircle, config.z_ring),
+        (LayerKind::ExteriorTriangles, config.z_exterior),
+        (LayerKind::InteriorTriangles, config.z_interior),
+        (LayerKind::CenterHub, config.z_hub),
+        (LayerKind::CenterStars, config.z_stars),
+        (LayerKind::CenterDots, config.z_dots),
+        (LayerKind::Letter, config.r_base_z),
+    ]
+    .into_iter()
+}
+
+/// Profondeur de chaque couche, la lettre étant détaillée partie par partie
+/// 
+/// # Arguments
+/// * `config` - Configuration (profondeurs des couches)
+/// * `letter_parts` - Parties de la lettre, avec leurs z_order attribués
+/// 
+/// # Retour
+/// `(nom, Z)` pour chaque couche puis chaque partie, prêt pour
+/// `geometry::find_z_collisions`
+pub fn layer_depths(config: &LogoConfig, letter_parts: &[RPartDefinition]) -> Vec<(Cow<'static, str>, f32)> {
+    let mut layers: Vec<(Cow<'static, str>, f32)> = logo_layers(config)
+        .filter(|(kind, _)| *kind != LayerKind::Letter)
+        .map(|(kind, z)| (kind.label().into(), z))
+        .collect();
+    layers.extend(letter_parts.iter().map(|part| (part.name.clone(), part.z_order)));
+    layers
+}
+
+/// Couleur de remplissage de la lettre du logo
+/// 
+/// Exemples :
+/// - Rouge : Color::srgba(1.0, 0.0, 0.0, 0.8)
+/// - Bleu : Color::srgba(0.2, 0.6, 1.0, 0.8)
+/// - Vert : Color::srgba(0.2, 0.8, 0.3, 0.8)
+pub const LETTER_COLOR: Color = Color::srgba(1.0, 0.5, 0.0, 0.8); // Orange
+
+/// Couleur du contour de sélection d'une partie de la lettre
+pub const SELECTION_COLOR: Color = Color::WHITE;
+
+/// Construit la grille de référence
+/// 
+/// Grille grise semi-transparente de `GRID_EXTENT` pixels de côté,
+/// placée à `GRID_Z`, derrière tous les éléments.
+pub fn build_grid(config: &LogoConfig) -> BuiltElement {
+    let mesh = geometry::create_grid_mesh(
+        config::GRID_EXTENT,
+        config::GRID_EXTENT,
+        config.grid_cell_size
+    );
+    
+    (
+        mesh,
+        ColorMaterial::from(Color::srgba(0.5, 0.5, 0.5, 0.4)),
+        Transform::from_xyz(0.0, 0.0, config::GRID_Z),
+    )
+}
+
+/// Construit la spirale décorative de fond
+/// 
+/// Spirale d'Archimède du centre jusqu'à `spiral_radius`, en gris
+/// semi-transparent, placée à `SPIRAL_Z` (devant la grille).
+/// `SPIRAL_SEGMENTS_PER_TURN` segments par tour.
+pub fn build_spiral(config: &LogoConfig) -> BuiltElement {
+    let segments = (config.spiral_turns * config::SPIRAL_SEGMENTS_PER_TURN).ceil() as usize;
+    let mesh = geometry::create_spiral_mesh(
+        config.spiral_turns,
+        0.0,
+        config.spiral_radius,
+        config.spiral_thickness,
+        segments
+    );
+    
+    (
+        mesh,
+        ColorMaterial::from(Color::srgba(0.6, 0.6, 0.7, 0.5)),
+        Transform::from_xyz(0.0, 0.0, config::SPIRAL_Z),
+    )
+}
+
+/// Construit le cercle principal (anneau épais)
+/// 
+/// Nombre de segments : `ring_segments`, ou calcul adaptatif selon le
+/// rayon si `adaptive_segments` est actif. Opacité : `main_circle_alpha`.
+pub fn build_main_circle(config: &LogoConfig) -> BuiltElement {
+    // === CALCUL DES DIMENSIONS ===
+    let outer_radius = config.circle_radius;
+    let segments = if config.adaptive_segments {
+        geometry::recommended_segments(outer_radius, config.max_chord_error)
+    } else {
+        config.ring_segments
+    };
+    
+    let mesh = geometry::create_circle_mesh(outer_radius, config.inner_radius(), segments);
+    
+    (
+        mesh,
+        materials::get_main_circle_color_with_alpha(config.main_circle_alpha),
+        Transform::from_xyz(0.0, 0.0, config.z_ring),
+    )
+}
+
+/// Calcule les sommets de tous les triangles extérieurs
+/// 
+/// Un triangle tous les 360° / `exterior_triangles_count`
+/// (`exterior_triangle_angle_degrees`), base posée sur le cercle principal
+/// et rétrécie de `exterior_gap_degrees` (espace entre triangles voisins).
+/// 
+/// # Retour
+/// Les 3 sommets (base, base, pointe) du triangle d'index i, à l'index i
+pub fn exterior_triangle_transforms(config: &LogoConfig) -> Vec<(Vec2, Vec2, Vec2)> {
+    (0..config.exterior_triangles_count)
+        .map(|i| {
+            let angle_degrees = geometry::exterior_triangle_angle_degrees(i, config.exterior_triangles_count);
+            geometry::calculate_exterior_triangle_points_with_gap(
+                geometry::degrees_to_radians(angle_degrees),
+                config.circle_radius,
+                config.small_triangle_side,
+                geometry::degrees_to_radians(config.exterior_gap_degrees),
+                config.exterior_height_ratio,
+            )
+        })
+        .collect()
+}
+
+/// Profondeur de l'élément `index` d'une couche à la profondeur `layer_z`
+/// 
+/// Avec `LogoConfig::stable_layer_sort`, chaque élément est décalé de
+/// `index × LAYER_Z_STEP` : les éléments d'une couche ont des profondeurs
+/// distinctes et un ordre de dessin stable. Sinon, `layer_z` pour tous.
+pub fn layer_element_z(config: &LogoConfig, layer_z: f32, index: usize) -> f32 {
+    if config.stable_layer_sort {
+        layer_z + index as f32 * config::LAYER_Z_STEP
+    } else {
+        layer_z
+    }
+}
+
+/// Construit les triangles extérieurs arc-en-ciel
+/// 
+/// Sommets donnés par `exterior_triangle_transforms`, teinte donnée par
+/// `get_oriented_rainbow_color`.
+/// L'élément d'index i correspond au triangle d'index i.
+pub fn build_exterior_triangles(config: &LogoConfig) -> Vec<BuiltElement> {
+    exterior_triangle_transforms(config)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (p1, p2, p3))| {
+            // === COULEUR ARC-EN-CIEL ===
+            let material = materials::get_oriented_rainbow_color(
+                i,
+                config.exterior_triangles_count,
+                config.rainbow_start_hue,
+                config.rainbow_clockwise
+            );
+            
+            (
+                geometry::create_triangle_with_winding(p1, p2, p3, Winding::AutoCcw),
+                material,
+                Transform::from_xyz(0.0, 0.0, layer_element_z(config, config.z_exterior, i)),
+            )
+        })
+        .collect()
+}
+
+/// Construit l'anneau arc-en-ciel en dégradé
+/// 
+/// Occupe la même bande que les triangles extérieurs : du bord du
+/// cercle principal jusqu'à la pointe des triangles (rayon +
+/// `LogoConfig::exterior_height`).
+/// Le matériau est blanc pour conserver les couleurs des vertices.
+pub fn build_rainbow_ring(config: &LogoConfig) -> BuiltElement {
+    let mesh = geometry::create_oriented_rainbow_ring_mesh(
+        config.circle_radius + config.exterior_height(),
+        config.circle_radius,
+        config.ring_segments,
+        config.rainbow_start_hue,
+        config.rainbow_clockwise
+    );
+    
+    (
+        mesh,
+        ColorMaterial::from(Color::WHITE),
+        Transform::from_xyz(0.0, 0.0, config.z_exterior),
+    )
+}
+
+/// Retourne les 3 sommets du triangle intérieur d'index `index`
+/// 
+/// Le sens du sommet suit `LogoConfig::interior_apex_inward` ; la base est
+/// posée sur le cercle de rayon `inner_radius() × interior_radius_fraction`.
+pub fn interior_triangle_points(config: &LogoConfig, index: usize) -> (Vec2, Vec2, Vec2) {
+    let angle_degrees = geometry::interior_triangle_angle_degrees(index, config.interior_triangles_count);
+    geometry::calculate_interior_triangle_points_directed(
+        geometry::degrees_to_radians(angle_degrees),
+        config.inner_radius() * config.interior_radius_fraction,
+        config.large_triangle_side,
+        config.interior_apex_inward,
+        config.interior_height_ratio,
+    )
+}
+
+/// Couleurs des triangles intérieurs, à l'index de chaque triangle
+/// 
+/// `interior_colors` (palette répétée au-delà de 5 triangles),
+/// `random_palette` si `random_palette_seed` est défini, ou
+/// `interior_gradient_colors` si `interior_gradient` est actif.
+pub fn interior_triangle_colors(config: &LogoConfig) -> Vec<Color> {
+    let count = config.interior_triangles_count;
+    match (config.interior_gradient, config.random_palette_seed) {
+        (true, _) => materials::interior_gradient_colors(count, config.rainbow_start_hue),
+        (false, Some(seed)) => materials::random_palette(count, seed),
+        (false, None) => materials::interior_colors(count),
+    }
+}
+
+/// Construit les triangles intérieurs (polygone régulier central)
+/// 
+/// Couleurs : voir `interior_triangle_colors`.
+pub fn build_interior_triangles(config: &LogoConfig) -> Vec<BuiltElement> {
+    interior_triangle_colors(config)
+        .into_iter()
+        .enumerate()
+        .map(|(i, color)| {
+            let (p1, p2, p3) = interior_triangle_points(config, i);
+            (
+                geometry::create_triangle_with_winding(p1, p2, p3, Winding::AutoCcw),
+                ColorMaterial::from(color),
+                Transform::from_xyz(0.0, 0.0, layer_element_z(config, config.z_interior, i)),
+            )
+        })
+        .collect()
+}
+
+/// Construit les petits cercles placés au centroïde des triangles intérieurs
+/// 
+/// L'élément d'index i est centré sur le triangle intérieur d'index i.
+pub fn build_center_dots(config: &LogoConfig) -> Vec<BuiltElement> {
+    (0..config.interior_triangles_count)
+        .map(|i| {
+            let (p1, p2, p3) = interior_triangle_points(config, i);
+            let center = geometry::calculate_triangle_centroid(p1, p2, p3);
+            (
+                geometry::create_filled_circle_mesh(config.small_circle_radius, config.dot_segments),
+                materials::get_small_circle_color(),
+                Transform::from_xyz(center.x, center.y, layer_element_z(config, config.z_dots, i)),
+            )
+        })
+        .collect()
+}
+
+/// Construit les repères de débogage des centroïdes des triangles
+/// 
+/// Un petit disque (`CENTROID_MARKER_RADIUS`, `CENTROID_MARKER_COLOR`) au
+/// centroïde de chaque triangle extérieur puis de chaque triangle
+/// intérieur, devant la lettre (`CENTROID_MARKER_Z`). Les sommets sont
+/// ceux de `exterior_triangle_transforms` et `interior_triangle_points`.
+pub fn build_centroid_markers(config: &LogoConfig) -> Vec<BuiltElement> {
+    let interior = (0..config.interior_triangles_count).map(|i| interior_triangle_points(config, i));
+    
+    exterior_triangle_transforms(config)
+        .into_iter()
+        .chain(interior)
+        .map(|(p1, p2, p3)| {
+            let center = geometry::calculate_triangle_centroid(p1, p2, p3);
+            (
+                geometry::create_filled_circle_mesh(config::CENTROID_MARKER_RADIUS, config.dot_segments),
+                ColorMaterial::from(config::CENTROID_MARKER_COLOR),
+                Transform::from_xyz(center.x, center.y, config::CENTROID_MARKER_Z),
+            )
+        })
+        .collect()
+}
+
+/// Construit le disque central placé derrière la lettre
+/// 
+/// Cercle plein de `center_hub_radius` centré sur l'origine, de couleur
+/// `center_hub_color`, à la profondeur `z_hub`. La création est
+/// conditionnée par `LogoConfig::center_hub` (voir `systems::setup`).
+pub fn build_center_hub(config: &LogoConfig) -> BuiltElement {
+    (
+        geometry::create_filled_circle_mesh(config.center_hub_radius, config.hub_segments),
+        ColorMaterial::from(config.center_hub_color),
+        Transform::from_xyz(0.0, 0.0, config.z_hub),
+    )
+}
+
+/// Construit les étoiles placées sous les petits cercles
+/// 
+/// Une étoile à `center_star_points` branches par triangle intérieur,
+/// centrée sur son centroïde, de taille proportionnelle à
+/// `small_circle_radius` (`CENTER_STAR_OUTER_SCALE`, `CENTER_STAR_INNER_SCALE`).
+/// Une pointe est dirigée vers l'extérieur du logo (direction centre du
+/// logo → centroïde). Couleur : jaune pâle, légèrement transparent.
+/// 
+/// # Retour
+/// Vide si `center_star_points` < 3 (petits cercles seuls).
+pub fn build_center_stars(config: &LogoConfig) -> Vec<BuiltElement> {
+    if config.center_star_points < 3 {
+        return Vec::new();
+    }
+    
+    (0..config.interior_triangles_count)
+        .map(|i| {
+            let (p1, p2, p3) = interior_triangle_points(config, i);
+            let center = geometry::calculate_triangle_centroid(p1, p2, p3);
+            
+            // La première pointe du mesh est sur +X : rotation vers l'extérieur
+            let outward = geometry::angle_between_points(Vec2::ZERO, center);
+            let transform = Transform::from_xyz(center.x, center.y, config.z_stars)
+                .with_rotation(Quat::from_rotation_z(outward));
+            
+            (
+                geometry::create_star_mesh(
+                    config.center_star_points,
+                    config.small_circle_radius * config::CENTER_STAR_OUTER_SCALE,
+                    config.small_circle_radius * config::CENTER_STAR_INNER_SCALE
+                ),
+                ColorMaterial::from(Color::srgba(1.0, 0.95, 0.6, 0.9)),
+                transform,
+            )
+        })
+        .collect()
+}
+
+/// Retourne les parties de la lettre ignorées par le mode sûr
+/// 
+/// Index dans `parts` et défaut trouvé par `geometry::polygon_defect`,
+/// pour chaque partie présentant un défaut. Vide si
+/// `LogoConfig::safe_mode` est désactivé.
+pub fn safe_mode_rejections(config: &LogoConfig, parts: &[RPartDefinition]) -> Vec<(usize, PolygonDefect)> {
+    if !config.safe_mode {
+        return Vec::new();
+    }
+    parts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, part)| geometry::polygon_defect(&part.points).map(|defect| (index, defect)))
+        .collect()
+}
+
+/// Retourne les parties de la lettre à dessiner, avec leur index
+/// 
+/// Écarte les parties de moins de 3 points, et en mode sûr celles
+/// refusées par `safe_mode_rejections`. L'index est celui de la partie
+/// dans `parts` (voir `RPart::index`).
+pub fn drawable_letter_parts(config: &LogoConfig, parts: &[RPartDefinition]) -> Vec<(usize, RPartDefinition)> {
+    let rejections = safe_mode_rejections(config, parts);
+    parts
+        .iter()
+        .enumerate()
+        .filter(|(index, part)| part.points.len() >= 3 && !rejections.iter().any(|(rejected, _)| rejected == index))
+        .map(|(index, part)| (index, part.clone()))
+        .collect()
+}
+
+/// Construit une partie de la lettre du logo
+/// 
+/// Mesh polygonal à l'origine, à la profondeur `part.z_order`.
+/// 
+/// # Panic
+/// Panic si la partie a moins de 3 points (voir `create_polygon_from_points`)
+pub fn build_letter_part(part: &RPartDefinition) -> BuiltElement {
+    (
+        geometry::create_polygon_from_points(&part.points),
+        ColorMaterial::from(LETTER_COLOR),
+        Transform::from_xyz(0.0, 0.0, part.z_order),
+    )
+}
+
+/// Construit la lettre entière en un seul mesh (option `merge_r_parts`)
+/// 
+/// Les meshes de `build_letter_part` (triangulés par découpage
+/// d'oreilles) sont fusionnés avec `merge_meshes`, dans l'ordre de
+/// profondeur des parties : le recouvrement des parties
+/// semi-transparentes est identique à celui des entités séparées.
+/// Toutes les parties ont la couleur `LETTER_COLOR` : un seul matériau.
+/// Le `Transform` est à la profondeur de la partie la plus en arrière ;
+/// l'écart de profondeur des autres parties est conservé dans le mesh.
+/// 
+/// # Retour
+/// `None` sans partie
+/// 
+/// # Panic
+/// Panic si une partie a moins de 3 points (voir `build_letter_part`)
+pub fn build_merged_letter(parts: &[RPartDefinition]) -> Option<BuiltElement> {
+    let mut built: Vec<BuiltElement> = parts.iter().map(build_letter_part).collect();
+    let material = built.first()?.1.clone();
+    
+    // === FUSION, DE L'ARRIÈRE VERS L'AVANT ===
+    built.sort_by(|a, b| a.2.translation.z.total_cmp(&b.2.translation.z));
+    let base_z = built[0].2.translation.z;
+    let placed: Vec<(Mesh, Transform)> = built
+        .into_iter()
+        .map(|(mesh, _, transform)| (mesh, transform.with_translation(transform.translation - Vec3::Z * base_z)))
+        .collect();
+    
+    Some((geometry::merge_meshes(&placed), material, Transform::from_xyz(0.0, 0.0, base_z)))
+}
+
+/// Construit le contour d'une partie de la lettre
+/// 
+/// Trait de `r_outline_width` centré sur le bord de la partie. Le
+/// `Transform` est relatif à la partie : le contour est placé juste
+/// derrière elle (moitié de `r_z_step`, quel que soit son signe), pour
+/// que seule la moitié extérieure du trait dépasse du remplissage.
+pub fn build_letter_outline(config: &LogoConfig, part: &RPartDefinition) -> BuiltElement {
+    (
+        geometry::create_polyline_mesh(&part.points, config.r_outline_width, true),
+        ColorMaterial::from(config.r_outline_color),
+        Transform::from_xyz(0.0, 0.0, -config.r_z_step.abs() / 2.0),
+    )
+}
+
+/// Construit le contour de sélection d'une partie de la lettre
+/// 
+/// Pointillés blancs (tirets et espaces de `selection_dash_length`),
+/// décalés de `offset` le long du contour. Le `Transform` est relatif à
+/// la partie : le contour est placé juste devant elle.
+pub fn build_selection_outline(config: &LogoConfig, part: &RPartDefinition, offset: f32) -> BuiltElement {
+    (
+        selection_outline_mesh(config, part, offset),
+        ColorMaterial::from(SELECTION_COLOR),
+        Transform::from_xyz(0.0, 0.0, config.r_z_step / 2.0),
+    )
+}
+
+/// Mesh du contour de sélection d'une partie (voir `build_selection_outline`)
+/// 
+/// Séparé pour que l'animation puisse régénérer le mesh seul.
+pub fn selection_outline_mesh(config: &LogoConfig, part: &RPartDefinition, offset: f32) -> Mesh {
+    geometry::create_dashed_outline_mesh(
+        &part.points,
+        config::SELECTION_OUTLINE_THICKNESS,
+        config.selection_dash_length,
+        config.selection_dash_length,
+        offset,
+    )
+}
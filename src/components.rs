@@ -0,0 +1,230 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                      FICHIER: src/components.rs                          ║
+// ║  Composants marqueurs des éléments du logo                               ║
+// ║  Rôle : Identifier chaque entité créée pour pouvoir la retrouver        ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module des composants
+//! 
+//! Chaque élément visuel créé par le setup reçoit un composant "marqueur".
+//! Ces composants ne contiennent presque pas de données : ils servent à
+//! filtrer les entités dans les requêtes Bevy, par exemple :
+//! 
+//! ```ignore
+//! fn mon_systeme(query: Query<&Transform, With<MainCircle>>) { ... }
+//! ```
+//! 
+//! Ils sont la base de toutes les fonctionnalités interactives
+//! (animation, changement de couleur, visibilité, ...).
+
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+/// Marqueur de la caméra principale
+/// 
+/// Permet aux systèmes de déplacement (glisser, zoom) de retrouver
+/// la caméra sans toucher à d'éventuelles autres caméras.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MainCamera;
+
+/// Marqueur de l'entité racine du logo
+/// 
+/// Tous les éléments du logo sont des enfants de cette entité :
+/// déplacer, tourner ou mettre à l'échelle son `Transform`
+/// s'applique donc au logo entier.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogoRoot;
+
+/// Marqueur du cercle principal (anneau épais)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MainCircle;
+
+/// Marqueur d'un triangle extérieur (anneau arc-en-ciel)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExteriorTriangle {
+    /// Position du triangle autour du cercle (0 à nombre-1)
+    pub index: usize,
+}
+
+/// Marqueur de l'anneau arc-en-ciel en dégradé
+/// 
+/// Remplace les triangles extérieurs quand `LogoConfig::rainbow_style`
+/// vaut `RainbowStyle::Gradient`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RainbowRing;
+
+/// Marqueur d'un triangle intérieur (polygone central)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteriorTriangle {
+    /// Position du triangle dans le polygone (0 à nombre-1)
+    pub index: usize,
+}
+
+/// Marqueur d'un petit cercle placé au centroïde d'un triangle intérieur
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CenterDot {
+    /// Index du triangle intérieur associé
+    pub index: usize,
+}
+
+/// Repère de débogage placé au centroïde d'un triangle (option `show_centroids`)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CentroidMarker;
+
+/// Marqueur du disque central placé derrière la lettre (option `center_hub`)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CenterHub;
+
+/// Marqueur d'une étoile placée sous un petit cercle central
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CenterStar {
+    /// Index du triangle intérieur associé
+    pub index: usize,
+}
+
+/// Marqueur d'une partie du logo "R"
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct RPart {
+    /// Nom de la partie (voir `RPartDefinition::name`)
+    pub name: Cow<'static, str>,
+    
+    /// Position de la partie dans `get_letter_parts(letter)`
+    pub index: usize,
+}
+
+/// Fondu d'apparition d'un élément (animation d'introduction)
+/// 
+/// Ajouté pendant le setup quand `LogoConfig::intro_animation` est actif.
+/// Le système d'animation fait passer l'alpha du matériau de 0 à sa valeur
+/// d'origine, puis retire le composant.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct IntroFade {
+    /// Délai avant le début du fondu (secondes), dépend de la couche
+    pub delay: f32,
+    
+    /// Temps écoulé depuis la création de l'élément (secondes)
+    pub elapsed: f32,
+    
+    /// Alpha final du matériau, lu au premier passage du système
+    /// (le même pour tous les éléments qui partagent le matériau)
+    pub target_alpha: Option<f32>,
+}
+
+impl IntroFade {
+    /// Crée un fondu qui démarre après `delay` secondes
+    pub fn new(delay: f32) -> Self {
+        Self { delay, elapsed: 0.0, target_alpha: None }
+    }
+}
+
+/// Marqueur de la lettre dessinée en un seul mesh (option `merge_r_parts`)
+/// 
+/// Remplace les entités `RPart` : les contours éventuels sont ses enfants.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergedLetter;
+
+/// Marqueur du contour d'une partie du "R"
+/// 
+/// Le contour est un enfant de l'entité `RPart` correspondante :
+/// il suit donc ses déplacements (éclatement, ...).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ROutline {
+    /// Index de la partie entourée
+    pub index: usize,
+}
+
+/// Marqueur d'une partie du "R" sélectionnée
+/// 
+/// Inséré ou retiré par un clic sur la partie (ou à la main) pour afficher
+/// ou masquer son contour de sélection animé (voir `systems::selection`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selected;
+
+/// Contour de sélection en pointillés défilants ("fourmis en marche")
+/// 
+/// Enfant de la partie `Selected` : suit ses déplacements.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SelectionOutline {
+    /// Index de la partie entourée
+    pub index: usize,
+    
+    /// Avancée courante des tirets le long du contour (pixels)
+    pub offset: f32,
+}
+
+/// Case d'une racine `LogoRoot` dans la mosaïque (`LogoConfig::tile`)
+/// 
+/// Présent sur chaque racine, y compris sans mosaïque (décalage nul).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct LogoTile {
+    /// Décalage du logo par rapport au centre de la mosaïque, en unités du logo
+    pub offset: Vec2,
+}
+
+/// Marqueur de l'image de fond (`LogoConfig::background_image`)
+/// 
+/// Ne fait pas partie du logo : elle n'est pas enfant de `LogoRoot`
+/// et reste en place lors d'une régénération.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackgroundImage;
+
+/// Marqueur du texte de distance de la règle de mesure (voir `Ruler`)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RulerLabel;
+
+/// Marqueur de la grille de référence affichée derrière le logo
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridBackground;
+
+/// Marqueur de la spirale décorative affichée derrière le logo
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpiralBackground;
+
+/// Marqueur d'une ombre portée
+/// 
+/// L'ombre est un enfant de `LogoRoot` qui réutilise le mesh
+/// de l'élément ombré, avec un matériau noir semi-transparent.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropShadow;
+
+/// Position d'origine d'une partie du "R" (pour l'éclatement)
+/// 
+/// Mémorise le `Transform` initial de la partie : le réassemblage
+/// revient exactement à cette valeur, sans dérive numérique.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RPartHome {
+    /// Transform de la partie au repos
+    pub transform: Transform,
+    
+    /// Direction d'éclatement : du centre du logo vers le centroïde de la partie
+    pub direction: Vec2,
+}
+
+/// Alpha d'origine du matériau d'un élément
+/// 
+/// Mémorisé au premier passage de `global_opacity_system` : l'opacité
+/// globale est toujours appliquée à cette valeur, jamais à l'alpha
+/// courant, pour que les couleurs de base ne soient pas altérées.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BaseAlpha(pub f32);
+
+/// Filtre de requête sélectionnant tous les éléments du logo
+/// 
+/// Regroupe tous les marqueurs ci-dessus (la caméra n'en fait pas partie) :
+/// ```ignore
+/// fn mon_systeme(query: Query<Entity, LogoElementFilter>) { ... }
+/// ```
+pub type LogoElementFilter = Or<(
+    With<MainCircle>,
+    With<ExteriorTriangle>,
+    With<RainbowRing>,
+    With<InteriorTriangle>,
+    With<CenterDot>,
+    With<CenterStar>,
+    With<CenterHub>,
+    With<RPart>,
+    With<MergedLetter>,
+    With<ROutline>,
+)>;
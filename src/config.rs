@@ -12,6 +12,17 @@
 //! - Modification facile des paramètres sans toucher au code métier
 //! - Vue d'ensemble des dimensions et quantités
 //! - Évite les "magic numbers" dispersés dans le code
+//! 
+//! Les constantes servent de valeurs par défaut à la ressource `LogoConfig`,
+//! utilisée par les systèmes à l'exécution.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::error::LogoError;
 
 // === CONFIGURATION DU CERCLE PRINCIPAL ===
 
@@ -38,6 +49,65 @@ pub const CIRCLE_THICKNESS: f32 = 30.0;
 /// - 128 : qualité maximale (pour zoom ou export)
 pub const CIRCLE_SEGMENTS: usize = 64;
 
+/// Nombre minimal de segments d'un cercle plein
+/// 
+/// En dessous, le disque est dégénéré : `create_filled_circle_mesh`
+/// relève automatiquement la valeur à ce minimum.
+pub const MIN_FILLED_CIRCLE_SEGMENTS: usize = 3;
+
+/// Nombre minimal de segments en mode adaptatif
+pub const MIN_ADAPTIVE_SEGMENTS: usize = 16;
+
+/// Nombre maximal de segments en mode adaptatif
+pub const MAX_ADAPTIVE_SEGMENTS: usize = 512;
+
+/// Écart maximal (en pixels) entre le cercle et ses segments en mode adaptatif
+/// 
+/// 0.5 px : les facettes restent invisibles à l'œil nu
+pub const MAX_CHORD_ERROR: f32 = 0.5;
+
+// === CONFIGURATION DE LA VARIANTE LUMINEUSE ===
+
+/// Intensité des éléments lumineux (multiplicateur de couleur, 1.0 = aucun bloom)
+pub const GLOW_STRENGTH: f32 = 3.0;
+
+// === CONFIGURATION DU FOND ===
+
+/// Couleur de fond de la fenêtre (gris anthracite, celle de Bevy par défaut)
+pub const BACKGROUND_COLOR: Color = Color::srgb(0.169, 0.173, 0.184);
+
+/// Profondeur Z de l'image de fond, derrière la grille et la spirale
+pub const BACKGROUND_IMAGE_Z: f32 = -200.0;
+
+// === CONFIGURATION DES ÉTOILES CENTRALES ===
+
+/// Rayon des pointes d'une étoile centrale, en multiples de `SMALL_CIRCLE_RADIUS`
+pub const CENTER_STAR_OUTER_SCALE: f32 = 2.0;
+
+/// Rayon des creux d'une étoile centrale, en multiples de `SMALL_CIRCLE_RADIUS`
+pub const CENTER_STAR_INNER_SCALE: f32 = 1.0;
+
+/// Rayon du disque central placé derrière la lettre (option `center_hub`)
+pub const CENTER_HUB_RADIUS: f32 = 120.0;
+
+/// Couleur du disque central : gris foncé légèrement transparent
+pub const CENTER_HUB_COLOR: Color = Color::srgba(0.12, 0.12, 0.14, 0.85);
+
+// === CONFIGURATION DES ROUES DENTÉES ===
+
+/// Rapport entre la largeur du sommet et celle du pied d'une dent
+/// 
+/// 1.0 donnerait des dents rectangulaires, 0.6 des trapèzes bien marqués.
+pub const GEAR_TIP_TAPER: f32 = 0.6;
+
+/// Nombre de segments du corps dans chaque creux entre deux dents
+pub const GEAR_GAP_SUBDIVISIONS: usize = 3;
+
+/// Nombre minimal de dents accepté par `geometry::create_gear_mesh`
+/// 
+/// À vérifier avant l'appel pour un nombre saisi par l'utilisateur.
+pub const GEAR_MIN_TEETH: usize = 3;
+
 // === CONFIGURATION DES TRIANGLES EXTÉRIEURS ===
 
 /// Nombre de triangles extérieurs disposés autour du cercle
@@ -54,12 +124,21 @@ pub const EXTERIOR_TRIANGLES_COUNT: usize = 36;
 /// Recommandation : 10-15% du rayon pour un rendu harmonieux
 pub const SMALL_TRIANGLE_SIDE: f32 = 25.0;
 
+/// Nombres de triangles extérieurs proposés par les touches +/-
+/// 
+/// Tous divisent 360 : l'écart entre deux triangles est un nombre
+/// entier de degrés et l'anneau reste régulier.
+pub const EXTERIOR_COUNT_STEPS: [usize; 17] = [
+    6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120,
+];
+
 // === CONFIGURATION DES TRIANGLES INTÉRIEURS ===
 
 /// Nombre de triangles intérieurs formant un pentagone
 /// 
-/// Valeur fixe : 5 triangles pour créer une étoile à 5 branches
-/// Modification déconseillée sauf pour créer d'autres formes :
+/// Valeur actuelle : 5 triangles pour créer une étoile à 5 branches
+/// L'espacement est calculé automatiquement (360° / nombre), d'autres
+/// valeurs donnent donc d'autres formes régulières :
 /// - 3 : triangle
 /// - 4 : carré
 /// - 6 : hexagone
@@ -71,6 +150,12 @@ pub const INTERIOR_TRIANGLES_COUNT: usize = 5;
 /// Recommandation : 35-45% du rayon pour remplir l'espace intérieur
 pub const LARGE_TRIANGLE_SIDE: f32 = 80.0;
 
+/// Rapport hauteur / côté d'un triangle équilatéral (√3 / 2)
+/// 
+/// Valeur par défaut de `exterior_height_ratio` et `interior_height_ratio`.
+/// Au-dessus, les triangles sont plus pointus (isocèles) ; en dessous, plus plats.
+pub const EQUILATERAL_HEIGHT_RATIO: f32 = 0.866_025_4;
+
 // === CONFIGURATION DES PETITS CERCLES ===
 
 /// Rayon des petits cercles au centre de chaque triangle intérieur
@@ -86,3 +171,1332 @@ pub const SMALL_CIRCLE_RADIUS: f32 = 15.0;
 pub const SMALL_CIRCLE_SEGMENTS: usize = 32;
 
 
+
+// === CONFIGURATION DE L'ANTICRÉNELAGE ===
+
+/// Nombre d'échantillons MSAA (anticrénelage) de la caméra
+/// 
+/// Valeurs acceptées : 1 (désactivé), 2, 4 ou 8.
+/// 4 lisse les bords des triangles pour un coût GPU modeste.
+pub const MSAA_SAMPLES: u32 = 4;
+
+// === CONFIGURATION DU RENDU SANS FENÊTRE ===
+
+/// Côté (en pixels) de l'image générée par `run_headless`
+pub const HEADLESS_IMAGE_SIZE: u32 = 512;
+
+/// Nombre de frames rendues avant la capture
+/// 
+/// Laisse au GPU le temps de compiler les pipelines de rendu :
+/// une capture trop précoce peut donner une image vide.
+pub const HEADLESS_WARMUP_FRAMES: u32 = 30;
+
+/// Nombre de frames au-delà duquel `run_headless` abandonne la capture
+pub const HEADLESS_TIMEOUT_FRAMES: u32 = 600;
+
+// === CONFIGURATION DES PROFONDEURS DES COUCHES ===
+// Valeurs par défaut des champs z_* de LogoConfig (plus élevé = devant)
+
+/// Profondeur du cercle principal (arrière-plan)
+pub const Z_MAIN_CIRCLE: f32 = 0.0;
+
+/// Profondeur des triangles extérieurs
+pub const Z_EXTERIOR_TRIANGLES: f32 = 0.1;
+
+/// Profondeur des triangles intérieurs
+pub const Z_INTERIOR_TRIANGLES: f32 = 0.2;
+
+/// Profondeur du disque central (entre les triangles intérieurs et les étoiles)
+pub const Z_CENTER_HUB: f32 = 0.22;
+
+/// Profondeur des étoiles placées sous les petits cercles
+pub const Z_CENTER_STARS: f32 = 0.25;
+
+/// Profondeur des petits cercles centraux
+pub const Z_CENTER_DOTS: f32 = 0.3;
+
+// === CONFIGURATION DES PROFONDEURS DU LOGO "R" ===
+
+/// Profondeur Z de la première partie du logo "R"
+/// 
+/// Doit être supérieure à celle des petits cercles (Z=0.3)
+/// pour que le "R" soit dessiné devant.
+pub const R_BASE_Z: f32 = 0.40;
+
+/// Écart de profondeur entre deux parties consécutives du "R"
+/// 
+/// Avec 8 parties : Z de 0.40 à 0.47
+pub const R_Z_STEP: f32 = 0.01;
+
+/// Écart de profondeur entre deux éléments voisins d'une même couche
+/// (triangles, petits cercles) quand `stable_layer_sort` est actif
+/// 
+/// Assez petit pour rester imperceptible : 36 triangles occupent 0.0035,
+/// bien moins que l'écart entre deux couches (0.1).
+pub const LAYER_Z_STEP: f32 = 1e-4;
+
+// === TOLÉRANCES GÉOMÉTRIQUES ===
+
+/// Distance (en pixels) sous laquelle le dernier point d'un contour est
+/// considéré comme une répétition du premier (voir `create_polygon_auto_close`)
+pub const POLYGON_CLOSE_EPSILON: f32 = 1e-3;
+
+// === CONFIGURATION DE LA GRILLE DE RÉFÉRENCE ===
+
+/// Taille (en pixels) du carré couvert par la grille de référence
+pub const GRID_EXTENT: f32 = 1000.0;
+
+/// Profondeur Z de la grille, très en arrière de tous les éléments
+pub const GRID_Z: f32 = -100.0;
+
+// === CONFIGURATION DES REPÈRES DE CENTROÏDES ===
+
+/// Rayon (en pixels) des repères placés au centroïde des triangles
+pub const CENTROID_MARKER_RADIUS: f32 = 2.5;
+
+/// Couleur des repères de centroïdes : magenta, visible sur toutes les couleurs du logo
+pub const CENTROID_MARKER_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+/// Profondeur Z des repères de centroïdes, devant la lettre
+pub const CENTROID_MARKER_Z: f32 = 1.0;
+
+/// Couleur de la règle de mesure (touche M) : jaune vif
+pub const RULER_COLOR: Color = Color::srgb(1.0, 0.9, 0.0);
+
+/// Taille du texte de la distance affichée par la règle
+pub const RULER_FONT_SIZE: f32 = 18.0;
+
+/// Profondeur Z du texte de la règle, devant tous les éléments du logo
+pub const RULER_LABEL_Z: f32 = 10.0;
+
+// === CONFIGURATION DE LA SPIRALE DE FOND ===
+
+/// Nombre de segments de la spirale par tour
+pub const SPIRAL_SEGMENTS_PER_TURN: f32 = 64.0;
+
+/// Profondeur Z de la spirale : devant la grille, derrière le logo
+pub const SPIRAL_Z: f32 = -50.0;
+
+// === CONFIGURATION DE L'ANIMATION D'INTRODUCTION ===
+
+/// Délai (en secondes) entre l'apparition de deux couches successives
+/// 
+/// Ordre d'apparition : anneau, triangles extérieurs, triangles intérieurs,
+/// petits cercles, logo "R". Avec 0.3 s, le "R" commence à 1.2 s.
+pub const INTRO_LAYER_DELAY: f32 = 0.3;
+
+/// Durée (en secondes) du fondu d'un élément, de transparent à opaque
+pub const INTRO_FADE_DURATION: f32 = 0.5;
+
+// === CONTRÔLE DES ANIMATIONS ===
+
+/// Facteur appliqué à la vitesse des animations par les touches [ et ]
+pub const ANIMATION_SPEED_FACTOR: f32 = 2.0;
+
+/// Vitesse minimale des animations (8 fois plus lent)
+pub const ANIMATION_SPEED_MIN: f32 = 0.125;
+
+/// Vitesse maximale des animations (8 fois plus rapide)
+pub const ANIMATION_SPEED_MAX: f32 = 8.0;
+
+// === DÉPLACEMENT DE LA CAMÉRA ===
+
+/// Friction par défaut de l'inertie de la caméra (par seconde)
+/// 
+/// La vitesse est multipliée par e^(-friction × dt) à chaque frame :
+/// avec 5.0, elle perd environ 99 % de sa valeur en une seconde.
+pub const PAN_FRICTION: f32 = 5.0;
+
+/// Vitesse maximale par défaut de la caméra (unités du monde par seconde)
+pub const PAN_MAX_VELOCITY: f32 = 3000.0;
+
+/// Vitesse sous laquelle l'inertie s'arrête (unités du monde par seconde)
+pub const PAN_MIN_VELOCITY: f32 = 1.0;
+
+/// Poids d'un nouvel échantillon dans la vitesse lissée du glisser (0 à 1)
+pub const PAN_VELOCITY_SMOOTHING: f32 = 0.5;
+
+/// Marge par défaut (en unités du monde) autour du logo pour le déplacement
+pub const PAN_MARGIN: f32 = 100.0;
+
+/// Facteur de zoom appliqué par cran de molette
+pub const ZOOM_STEP: f32 = 1.1;
+
+/// Zoom minimal par défaut (0.25 : logo 4 fois plus petit)
+pub const ZOOM_MIN: f32 = 0.25;
+
+/// Zoom maximal par défaut (8.0 : logo 8 fois plus gros)
+pub const ZOOM_MAX: f32 = 8.0;
+
+/// Nombre de pixels de défilement équivalents à un cran de molette
+pub const ZOOM_PIXELS_PER_LINE: f32 = 100.0;
+
+/// Durée (en secondes) du recadrage de la caméra sur le logo (touche F)
+pub const CAMERA_FIT_DURATION: f32 = 0.35;
+
+/// Fichier où la caméra est enregistrée (Ctrl+S) et relue au démarrage
+pub const CAMERA_STATE_FILE: &str = "camera.json";
+
+// === CONFIGURATION DE L'ÉCLATEMENT DU "R" ===
+
+/// Distance (en pixels) parcourue par chaque partie du "R" quand il éclate
+pub const EXPLODE_DISTANCE: f32 = 120.0;
+
+/// Durée (en secondes) de l'éclatement ou du réassemblage
+pub const EXPLODE_DURATION: f32 = 0.5;
+
+// === CONFIGURATION DE LA ROTATION MANUELLE ===
+
+/// Vitesse de rotation du logo (en degrés par seconde) avec Q et E
+pub const MANUAL_ROTATION_SPEED: f32 = 90.0;
+
+// === CONFIGURATION DU CONTOUR DE SÉLECTION ===
+
+/// Longueur (en pixels) d'un tiret du contour de sélection (et des espaces)
+pub const SELECTION_DASH_LENGTH: f32 = 8.0;
+
+/// Vitesse de défilement des tirets (en pixels par seconde, "fourmis")
+pub const SELECTION_DASH_SPEED: f32 = 30.0;
+
+/// Épaisseur (en pixels) du contour de sélection
+pub const SELECTION_OUTLINE_THICKNESS: f32 = 2.0;
+
+// === CONFIGURATION DES OMBRES PORTÉES ===
+
+/// Recul en profondeur d'une ombre par rapport à son élément
+/// 
+/// Assez petit pour que l'ombre reste devant la couche précédente
+/// (les couches sont espacées d'au moins 0.01).
+pub const SHADOW_Z_OFFSET: f32 = 0.001;
+
+// ═══════════════════════════════════════════════════════════════════════════
+//                    CONFIGURATION MODIFIABLE À L'EXÉCUTION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Configuration du logo sous forme de ressource Bevy
+/// 
+/// Regroupe les constantes ci-dessus dans une structure pour pouvoir
+/// les modifier sans recompiler (ou depuis un autre système).
+/// `LogoConfig::default()` reproduit exactement les constantes du module.
+/// 
+/// # Utilisation
+/// ```ignore
+/// App::new().insert_resource(LogoConfig {
+///     circle_radius: 250.0,
+///     ..Default::default()
+/// });
+/// ```
+/// 
+/// La configuration peut aussi être lue depuis un fichier TOML
+/// (`LogoConfig::from_toml_path`) ; les champs absents du fichier
+/// gardent leur valeur par défaut.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogoConfig {
+    /// Rayon du cercle principal (voir `CIRCLE_RADIUS`)
+    pub circle_radius: f32,
+    
+    /// Épaisseur de l'anneau (voir `CIRCLE_THICKNESS`)
+    pub circle_thickness: f32,
+    
+    /// Nombre de segments de l'anneau principal et de l'anneau en dégradé
+    /// (voir `CIRCLE_SEGMENTS`)
+    pub ring_segments: usize,
+    
+    /// Nombre de triangles extérieurs (voir `EXTERIOR_TRIANGLES_COUNT`)
+    pub exterior_triangles_count: usize,
+    
+    /// Côté des triangles extérieurs (voir `SMALL_TRIANGLE_SIDE`)
+    pub small_triangle_side: f32,
+    
+    /// Espace angulaire entre deux triangles extérieurs voisins, en degrés
+    /// 
+    /// 0 par défaut (triangles serrés). Une valeur positive rétrécit la
+    /// base de chaque triangle pour laisser un espace visible ; une valeur
+    /// négative l'élargit (chevauchement). Le sommet ne bouge pas.
+    pub exterior_gap_degrees: f32,
+    
+    /// Hauteur des triangles extérieurs, en fraction de leur côté
+    /// 
+    /// `EQUILATERAL_HEIGHT_RATIO` par défaut (triangles équilatéraux).
+    /// La bande de l'anneau arc-en-ciel suit cette hauteur.
+    pub exterior_height_ratio: f32,
+    
+    /// Nombre de triangles intérieurs (voir `INTERIOR_TRIANGLES_COUNT`)
+    pub interior_triangles_count: usize,
+    
+    /// Côté des triangles intérieurs (voir `LARGE_TRIANGLE_SIDE`)
+    pub large_triangle_side: f32,
+    
+    /// Sens du sommet des triangles intérieurs
+    /// 
+    /// `true` (défaut) : vers le centre. `false` : vers l'extérieur (effet "soleil").
+    pub interior_apex_inward: bool,
+    
+    /// Position de la base des triangles intérieurs, en fraction du rayon intérieur
+    /// 
+    /// 1.0 (défaut) : base sur le bord intérieur de l'anneau. En dessous,
+    /// le polygone central est resserré et laisse un espace sous l'anneau.
+    pub interior_radius_fraction: f32,
+    
+    /// Hauteur des triangles intérieurs, en fraction de leur côté
+    /// 
+    /// `EQUILATERAL_HEIGHT_RATIO` par défaut (triangles équilatéraux).
+    pub interior_height_ratio: f32,
+    
+    /// Rayon des petits cercles (voir `SMALL_CIRCLE_RADIUS`)
+    pub small_circle_radius: f32,
+    
+    /// Nombre de segments des petits cercles et des repères de centroïdes
+    /// (voir `SMALL_CIRCLE_SEGMENTS`)
+    pub dot_segments: usize,
+    
+    /// Teinte (en degrés, 0-360) du premier triangle extérieur
+    /// 
+    /// 0° = rouge, 120° = vert, 240° = bleu.
+    /// Permet d'aligner l'arc-en-ciel sur une couleur de marque.
+    pub rainbow_start_hue: f32,
+    
+    /// Sens de parcours de l'arc-en-ciel autour de l'anneau
+    /// 
+    /// - `false` (défaut) : la teinte augmente avec l'angle du triangle,
+    ///   c'est-à-dire dans le sens antihoraire à l'écran (Y vers le haut)
+    /// - `true` : la teinte augmente dans le sens horaire
+    pub rainbow_clockwise: bool,
+    
+    /// Marge (en pixels) conservée autour du logo quand il est ajusté
+    /// à la taille de la fenêtre
+    pub fit_margin: f32,
+    
+    /// Décalage du logo par rapport au centre de la fenêtre (en pixels)
+    /// 
+    /// `Vec2::ZERO` (défaut) : logo centré. Appliqué à la translation de
+    /// `LogoRoot`, après l'ajustement à la fenêtre (pour une bannière, ...).
+    pub logo_offset: Vec2,
+    
+    /// Étirement du logo entier, appliqué au `Transform` de `LogoRoot`
+    /// 
+    /// `Vec2::ONE` par défaut. Par exemple `Vec2::new(1.5, 0.8)` donne un
+    /// logo aplati pour un bandeau, sans régénérer les meshes.
+    /// L'ajustement à la fenêtre tient compte de l'étirement.
+    pub logo_scale: Vec2,
+    
+    /// Répète le logo en mosaïque : `(lignes, colonnes, espacement)`
+    /// 
+    /// Chaque case reçoit un logo complet (sa propre `LogoRoot`), les
+    /// meshes et matériaux étant partagés entre les copies. L'espacement
+    /// sépare les boîtes englobantes de deux logos voisins, en unités du
+    /// logo. La mosaïque entière est ajustée à la fenêtre.
+    /// `None` (défaut) : un seul logo centré.
+    pub tile: Option<(usize, usize, f32)>,
+    
+    /// Active l'apparition progressive des éléments au démarrage
+    /// 
+    /// Désactivée par défaut : le logo s'affiche alors immédiatement.
+    pub intro_animation: bool,
+    
+    /// Profondeur Z du cercle principal (voir `Z_MAIN_CIRCLE`)
+    /// 
+    /// Les champs `z_*`, `r_base_z` et `r_z_step` fixent l'ordre de dessin
+    /// des couches : par exemple, un `r_base_z` inférieur à `z_exterior`
+    /// place la lettre derrière les triangles. `validate` refuse deux
+    /// couches à la même profondeur.
+    pub z_ring: f32,
+    
+    /// Profondeur Z des triangles extérieurs ou de l'anneau en dégradé (voir `Z_EXTERIOR_TRIANGLES`)
+    pub z_exterior: f32,
+    
+    /// Profondeur Z des triangles intérieurs (voir `Z_INTERIOR_TRIANGLES`)
+    pub z_interior: f32,
+    
+    /// Profondeur Z du disque central (voir `Z_CENTER_HUB`)
+    pub z_hub: f32,
+    
+    /// Profondeur Z des étoiles centrales (voir `Z_CENTER_STARS`)
+    pub z_stars: f32,
+    
+    /// Profondeur Z des petits cercles (voir `Z_CENTER_DOTS`)
+    pub z_dots: f32,
+    
+    /// Profondeur Z de la première partie du "R" (voir `R_BASE_Z`)
+    pub r_base_z: f32,
+    
+    /// Écart de profondeur entre les parties du "R" (voir `R_Z_STEP`)
+    pub r_z_step: f32,
+    
+    /// Décale chaque triangle et petit cercle d'une couche de
+    /// `index × LAYER_Z_STEP` en profondeur
+    /// 
+    /// Activé par défaut : deux éléments d'une même couche qui se
+    /// chevauchent n'ont jamais la même profondeur, leur ordre de dessin
+    /// est donc stable (pas de scintillement selon le GPU).
+    pub stable_layer_sort: bool,
+    
+    /// Affiche les arêtes des meshes en mode fil de fer (touche W)
+    /// 
+    /// Désactivé par défaut. Utile pour vérifier les triangulations.
+    pub wireframe: bool,
+    
+    /// Dessine les repères de débogage (centroïdes, boîte englobante) (touche F4)
+    pub debug_gizmos: bool,
+    
+    /// Affiche au démarrage les parties de la lettre qui se chevauchent
+    /// 
+    /// Désactivé par défaut. Voir `geometry::find_part_overlaps`.
+    pub debug_geometry: bool,
+    
+    /// Place un petit disque au centroïde de chaque triangle (touche F5)
+    /// 
+    /// Désactivé par défaut. Contrairement aux gizmos, les repères sont
+    /// des entités `CentroidMarker`, enfants de la racine du logo.
+    pub show_centroids: bool,
+    
+    /// Ignore les parties de la lettre invalides au lieu de les afficher
+    /// 
+    /// Désactivé par défaut : une partie de moins de 3 points est ignorée
+    /// et un contour qui se croise est affiché (mal triangulé) avec un
+    /// avertissement. Activé, toute partie présentant un défaut
+    /// (`geometry::polygon_defect` : moins de 3 points, aire nulle, contour
+    /// croisé) est ignorée, avec un bilan à la fin de la création.
+    /// Utile pour des parties personnalisées (`custom_parts`).
+    pub safe_mode: bool,
+    
+    /// Dessine la lettre en un seul mesh au lieu d'une entité par partie
+    /// 
+    /// Désactivé par défaut. Activé, les parties sont fusionnées
+    /// (`build::build_merged_letter`) en une seule entité `MergedLetter` :
+    /// un seul appel de dessin pour toute la lettre, au prix de
+    /// l'interactivité par partie (pas d'`RPart` : ni éclatement, ni
+    /// inspection, ni sélection).
+    pub merge_r_parts: bool,
+    
+    /// Épaisseur (en pixels) du contour tracé autour de chaque partie du "R"
+    /// 
+    /// 0 (défaut) : pas de contour
+    pub r_outline_width: f32,
+    
+    /// Couleur du contour des parties du "R"
+    pub r_outline_color: Color,
+    
+    /// Ajoute une ombre portée sous chaque élément du logo
+    /// 
+    /// Désactivée par défaut. Prise en compte à la création du logo
+    /// (démarrage ou régénération).
+    pub drop_shadow: bool,
+    
+    /// Décalage de l'ombre par rapport à l'élément (en pixels, Y vers le haut)
+    pub shadow_offset: Vec2,
+    
+    /// Opacité de l'ombre (0.0 invisible, 1.0 noir opaque)
+    pub shadow_opacity: f32,
+    
+    /// Affiche une grille de référence derrière le logo
+    /// 
+    /// Désactivée par défaut. Une ligne de la grille passe par (0, 0).
+    pub show_grid: bool,
+    
+    /// Écart (en pixels) entre deux lignes de la grille
+    pub grid_cell_size: f32,
+    
+    /// Affiche une spirale décorative derrière le logo
+    /// 
+    /// Désactivée par défaut. La spirale part du centre du logo.
+    pub show_spiral: bool,
+    
+    /// Nombre de tours de la spirale
+    pub spiral_turns: f32,
+    
+    /// Rayon extérieur de la spirale (en pixels)
+    pub spiral_radius: f32,
+    
+    /// Épaisseur du trait de la spirale (en pixels)
+    pub spiral_thickness: f32,
+    
+    /// Lettre affichée au centre du logo
+    /// 
+    /// 'R' par défaut. Voir `geometry::SUPPORTED_LETTERS` pour la liste
+    /// des lettres disponibles ; une lettre inconnue n'affiche rien.
+    pub letter: char,
+    
+    /// Parties personnalisées dessinées à la place de celles de `letter`
+    /// 
+    /// `None` par défaut. Coordonnées à l'échelle 1.0 (mises à l'échelle
+    /// `letter_scale`), z_order réattribués ; par exemple chargées avec
+    /// `geometry::load_r_parts`. Voir `safe_mode` pour les parties invalides.
+    pub custom_parts: Option<Vec<crate::geometry::RPartDefinition>>,
+    
+    /// Échelle de la lettre (1.0 = coordonnées de `geometry::get_letter_parts`)
+    pub letter_scale: f32,
+    
+    /// Nombre d'échantillons MSAA de la caméra (1, 2, 4 ou 8)
+    /// 
+    /// Lu à la création de la caméra. Toute autre valeur est remplacée
+    /// par `MSAA_SAMPLES` (voir `LogoConfig::msaa`).
+    pub msaa_samples: u32,
+    
+    /// Calcule le nombre de segments de l'anneau selon son rayon
+    /// 
+    /// Si vrai, `ring_segments` est ignoré au profit de
+    /// `geometry::recommended_segments(circle_radius, max_chord_error)`.
+    pub adaptive_segments: bool,
+    
+    /// Écart maximal (en pixels) toléré en mode adaptatif
+    pub max_chord_error: f32,
+    
+    /// Rendu de l'anneau arc-en-ciel
+    /// 
+    /// `Discrete` (par défaut) : triangles extérieurs séparés.
+    /// `Gradient` : un seul anneau en dégradé continu.
+    pub rainbow_style: RainbowStyle,
+    
+    /// Friction de l'inertie de la caméra (0 = glisse sans fin)
+    pub pan_friction: f32,
+    
+    /// Vitesse maximale de la caméra après un relâchement
+    pub pan_max_velocity: f32,
+    
+    /// Zoom minimal de la molette (inverse de l'échelle de projection)
+    pub zoom_min: f32,
+    
+    /// Zoom maximal de la molette
+    pub zoom_max: f32,
+    
+    /// Marge autour du logo dans laquelle la caméra peut se déplacer
+    pub pan_margin: f32,
+    
+    /// Opacité globale de départ du logo (0.0 invisible, 1.0 opaque)
+    /// 
+    /// Valeur initiale de la ressource `GlobalOpacity`, qui multiplie
+    /// l'alpha de tous les éléments.
+    pub global_opacity: f32,
+    
+    /// Nombre de branches de l'étoile placée sous chaque petit cercle
+    /// 
+    /// 0 (par défaut) : pas d'étoile, seul le petit cercle est affiché.
+    /// Une étoile a au moins 3 branches (1 et 2 sont traités comme 0).
+    pub center_star_points: usize,
+    
+    /// Affiche un disque plein au centre du logo, derrière la lettre
+    /// 
+    /// Désactivé par défaut. Placé à `z_hub`, entre les triangles
+    /// intérieurs et les étoiles / petits cercles.
+    pub center_hub: bool,
+    
+    /// Rayon du disque central (voir `CENTER_HUB_RADIUS`)
+    pub center_hub_radius: f32,
+    
+    /// Couleur du disque central (voir `CENTER_HUB_COLOR`)
+    pub center_hub_color: Color,
+    
+    /// Nombre de segments du disque central (voir `CIRCLE_SEGMENTS`)
+    pub hub_segments: usize,
+    
+    /// Variante "néon" : anneau arc-en-ciel et petits cercles lumineux
+    /// 
+    /// Désactivée par défaut. Lue à la création de la caméra (HDR + bloom)
+    /// et des éléments (voir `systems::glow`).
+    pub glow: bool,
+    
+    /// Intensité des éléments lumineux (voir `GLOW_STRENGTH`)
+    pub glow_strength: f32,
+    
+    /// Couleur de fond de la fenêtre (`ClearColor`)
+    pub background_color: Color,
+    
+    /// Image affichée derrière le logo, à la taille de la fenêtre
+    /// 
+    /// Chemin relatif au dossier `assets` (chargement par `AssetServer`).
+    /// `None` (défaut) : fond uni `background_color`, utilisé aussi si
+    /// l'image ne peut pas être chargée.
+    pub background_image: Option<PathBuf>,
+    
+    /// Fond transparent pour les images exportées
+    /// 
+    /// `false` (défaut) : les exports (`run_headless`, `raster::rasterize`)
+    /// sont peints sur `background_color`. `true` : fond à alpha 0, seuls
+    /// les pixels du logo sont opaques (incrustation sur d'autres images).
+    /// La fenêtre n'est pas concernée.
+    pub transparent_export: bool,
+    
+    /// Opacité du cercle principal (1.0 opaque, en dessous le fond se voit)
+    pub main_circle_alpha: f32,
+    
+    /// Vitesse de rotation manuelle du logo (degrés par seconde, touches Q/E)
+    pub manual_rotation_speed: f32,
+    
+    /// Vitesse de rotation de la teinte de l'anneau principal (degrés par seconde)
+    /// 
+    /// 0 (défaut) : anneau rouge fixe.
+    pub ring_hue_cycle_speed: f32,
+    
+    /// Longueur d'un tiret du contour de sélection (voir `SELECTION_DASH_LENGTH`)
+    pub selection_dash_length: f32,
+    
+    /// Vitesse de défilement des tirets de sélection (voir `SELECTION_DASH_SPEED`)
+    pub selection_dash_speed: f32,
+    
+    /// Enregistrement de l'animation en séquence d'images PNG
+    /// 
+    /// `None` (par défaut) : fonctionnement normal.
+    /// `Some(spec)` : voir `RecordSpec` ; l'application se ferme une
+    /// fois toutes les images écrites.
+    pub record_frames: Option<RecordSpec>,
+    
+    /// Graine de la palette aléatoire des triangles intérieurs
+    /// 
+    /// `None` (par défaut) : palette fixe `INTERIOR_PALETTE`.
+    /// `Some(graine)` : `materials::random_palette`, identique d'une
+    /// exécution à l'autre pour une même graine.
+    pub random_palette_seed: Option<u64>,
+    
+    /// Colore les triangles intérieurs en dégradé selon leur position
+    /// 
+    /// `false` (défaut) : palette fixe (ou aléatoire, voir
+    /// `random_palette_seed`). `true` : `materials::interior_gradient_colors`,
+    /// à partir de `rainbow_start_hue` ; prioritaire sur la palette aléatoire.
+    pub interior_gradient: bool,
+}
+
+/// Rendu de l'anneau arc-en-ciel autour du cercle principal
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RainbowStyle {
+    /// Triangles séparés, un par teinte (rendu d'origine)
+    #[default]
+    Discrete,
+    
+    /// Anneau unique dont la couleur est interpolée par le GPU
+    /// 
+    /// Plus lisse et moins coûteux : un seul mesh au lieu de
+    /// `exterior_triangles_count` entités.
+    Gradient,
+}
+
+/// Tailles de sortie prédéfinies (voir `LogoConfig::preset`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Preset {
+    /// Vignette : petit et léger, le moins de vertices possible
+    Thumbnail,
+    
+    /// Valeurs par défaut du module
+    #[default]
+    Default,
+    
+    /// Affiche : grand, très finement découpé, MSAA maximal
+    Poster,
+}
+
+/// Paramètres d'enregistrement d'une séquence d'images
+/// 
+/// Le temps avance d'exactement 1 / `fps` seconde par frame (et non selon
+/// l'horloge réelle) : les images sont identiques d'une exécution à l'autre,
+/// quelle que soit la vitesse de la machine.
+/// 
+/// # Nombre d'images
+/// ⌈fps × duration⌉ images, nommées `frame_0000.png`, `frame_0001.png`, ...
+/// Exemple : 30 images/s pendant 2 s donnent 60 images (frame_0000 à frame_0059).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordSpec {
+    /// Images par seconde de la séquence
+    pub fps: u32,
+    
+    /// Durée enregistrée, en secondes
+    pub duration: f32,
+    
+    /// Dossier de destination (créé s'il n'existe pas)
+    pub output_dir: PathBuf,
+}
+
+impl RecordSpec {
+    /// Nombre d'images de la séquence : ⌈fps × duration⌉
+    pub fn frame_count(&self) -> usize {
+        (self.fps as f32 * self.duration).ceil().max(0.0) as usize
+    }
+    
+    /// Chemin de l'image d'index `index` (`frame_0000.png`, ...)
+    pub fn frame_path(&self, index: usize) -> PathBuf {
+        self.output_dir.join(format!("frame_{:04}.png", index))
+    }
+}
+
+impl Default for LogoConfig {
+    fn default() -> Self {
+        Self {
+            circle_radius: CIRCLE_RADIUS,
+            circle_thickness: CIRCLE_THICKNESS,
+            ring_segments: CIRCLE_SEGMENTS,
+            exterior_triangles_count: EXTERIOR_TRIANGLES_COUNT,
+            small_triangle_side: SMALL_TRIANGLE_SIDE,
+            exterior_gap_degrees: 0.0,
+            exterior_height_ratio: EQUILATERAL_HEIGHT_RATIO,
+            interior_triangles_count: INTERIOR_TRIANGLES_COUNT,
+            large_triangle_side: LARGE_TRIANGLE_SIDE,
+            interior_apex_inward: true,
+            interior_radius_fraction: 1.0,
+            interior_height_ratio: EQUILATERAL_HEIGHT_RATIO,
+            small_circle_radius: SMALL_CIRCLE_RADIUS,
+            dot_segments: SMALL_CIRCLE_SEGMENTS,
+            rainbow_start_hue: 0.0,
+            rainbow_clockwise: false,
+            fit_margin: 40.0,
+            logo_offset: Vec2::ZERO,
+            logo_scale: Vec2::ONE,
+            tile: None,
+            intro_animation: false,
+            z_ring: Z_MAIN_CIRCLE,
+            z_exterior: Z_EXTERIOR_TRIANGLES,
+            z_interior: Z_INTERIOR_TRIANGLES,
+            z_hub: Z_CENTER_HUB,
+            z_stars: Z_CENTER_STARS,
+            z_dots: Z_CENTER_DOTS,
+            r_base_z: R_BASE_Z,
+            r_z_step: R_Z_STEP,
+            stable_layer_sort: true,
+            wireframe: false,
+            debug_gizmos: false,
+            debug_geometry: false,
+            show_centroids: false,
+            safe_mode: false,
+            merge_r_parts: false,
+            r_outline_width: 0.0,
+            r_outline_color: Color::srgb(0.25, 0.1, 0.0), // Brun foncé
+            drop_shadow: false,
+            shadow_offset: Vec2::new(6.0, -6.0),
+            shadow_opacity: 0.35,
+            show_grid: false,
+            grid_cell_size: 20.0,
+            show_spiral: false,
+            spiral_turns: 6.0,
+            spiral_radius: 450.0,
+            spiral_thickness: 3.0,
+            letter: 'R',
+            custom_parts: None,
+            letter_scale: 1.0,
+            msaa_samples: MSAA_SAMPLES,
+            adaptive_segments: false,
+            max_chord_error: MAX_CHORD_ERROR,
+            rainbow_style: RainbowStyle::Discrete,
+            pan_friction: PAN_FRICTION,
+            pan_max_velocity: PAN_MAX_VELOCITY,
+            zoom_min: ZOOM_MIN,
+            zoom_max: ZOOM_MAX,
+            pan_margin: PAN_MARGIN,
+            global_opacity: 1.0,
+            center_star_points: 0,
+            center_hub: false,
+            center_hub_radius: CENTER_HUB_RADIUS,
+            center_hub_color: CENTER_HUB_COLOR,
+            hub_segments: CIRCLE_SEGMENTS,
+            glow: false,
+            glow_strength: GLOW_STRENGTH,
+            background_color: BACKGROUND_COLOR,
+            background_image: None,
+            transparent_export: false,
+            main_circle_alpha: 1.0,
+            manual_rotation_speed: MANUAL_ROTATION_SPEED,
+            ring_hue_cycle_speed: 0.0,
+            selection_dash_length: SELECTION_DASH_LENGTH,
+            selection_dash_speed: SELECTION_DASH_SPEED,
+            record_frames: None,
+            random_palette_seed: None,
+            interior_gradient: false,
+        }
+    }
+}
+
+impl LogoConfig {
+    /// Rayon intérieur de l'anneau (rayon - épaisseur)
+    pub fn inner_radius(&self) -> f32 {
+        self.circle_radius - self.circle_thickness
+    }
+    
+    /// Hauteur des triangles extérieurs (côté × `exterior_height_ratio`)
+    pub fn exterior_height(&self) -> f32 {
+        self.small_triangle_side * self.exterior_height_ratio
+    }
+    
+    /// Couleur de fond des images exportées
+    /// 
+    /// Transparente (`Color::NONE`) avec `transparent_export`,
+    /// `background_color` sinon.
+    pub fn export_background(&self) -> Color {
+        if self.transparent_export {
+            Color::NONE
+        } else {
+            self.background_color
+        }
+    }
+    
+    /// Composant `Msaa` correspondant à `msaa_samples`
+    /// 
+    /// Une valeur non supportée affiche un avertissement et utilise
+    /// `MSAA_SAMPLES` (Bevy paniquerait sinon).
+    pub fn msaa(&self) -> Msaa {
+        match self.msaa_samples {
+            1 => Msaa::Off,
+            2 => Msaa::Sample2,
+            4 => Msaa::Sample4,
+            8 => Msaa::Sample8,
+            other => {
+                println!("⚠️ MSAA à {} échantillons non supporté, utilisation de {}",
+                         other, MSAA_SAMPLES);
+                Msaa::from_samples(MSAA_SAMPLES)
+            }
+        }
+    }
+    
+    /// Parties de la lettre `letter`, mises à l'échelle `letter_scale`
+    /// 
+    /// `custom_parts` si elles sont fournies. Vide si la lettre n'est pas
+    /// supportée. Les z_order ne sont pas attribués (voir
+    /// `geometry::assign_z_orders`).
+    pub fn letter_parts(&self) -> Vec<crate::geometry::RPartDefinition> {
+        let mut parts = match &self.custom_parts {
+            Some(parts) => parts.clone(),
+            None => crate::geometry::get_letter_parts(self.letter).unwrap_or_default(),
+        };
+        crate::geometry::scale_parts(&mut parts, self.letter_scale);
+        parts
+    }
+    
+    /// Configuration prédéfinie pour une taille de sortie
+    /// 
+    /// Toutes les dimensions (rayon, épaisseur, triangles, petits cercles,
+    /// lettre, ombre, ...) sont multipliées par le même facteur : les
+    /// proportions du logo sont conservées.
+    /// 
+    /// | Preset      | Échelle | Segments anneau / cercles / disque | Triangles ext. | MSAA |
+    /// |-------------|---------|------------------------------------|----------------|------|
+    /// | `Thumbnail` | 0.25    | 24 / 8 / 24                        | 12             | 4    |
+    /// | `Default`   | 1.0     | 64 / 32 / 64                       | 36             | 4    |
+    /// | `Poster`    | 4.0     | 256 / 64 / 256                     | 36             | 8    |
+    pub fn preset(preset: Preset) -> Self {
+        match preset {
+            Preset::Thumbnail => Self {
+                ring_segments: 24,
+                dot_segments: 8,
+                hub_segments: 24,
+                exterior_triangles_count: 12,
+                ..Self::default().scaled(0.25)
+            },
+            Preset::Default => Self::default(),
+            Preset::Poster => Self {
+                ring_segments: 256,
+                dot_segments: 64,
+                hub_segments: 256,
+                msaa_samples: 8,
+                ..Self::default().scaled(4.0)
+            },
+        }
+    }
+    
+    /// Multiplie toutes les dimensions en pixels par `factor`
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            circle_radius: self.circle_radius * factor,
+            circle_thickness: self.circle_thickness * factor,
+            small_triangle_side: self.small_triangle_side * factor,
+            large_triangle_side: self.large_triangle_side * factor,
+            small_circle_radius: self.small_circle_radius * factor,
+            center_hub_radius: self.center_hub_radius * factor,
+            letter_scale: self.letter_scale * factor,
+            r_outline_width: self.r_outline_width * factor,
+            selection_dash_length: self.selection_dash_length * factor,
+            shadow_offset: self.shadow_offset * factor,
+            grid_cell_size: self.grid_cell_size * factor,
+            spiral_radius: self.spiral_radius * factor,
+            spiral_thickness: self.spiral_thickness * factor,
+            pan_margin: self.pan_margin * factor,
+            ..self
+        }
+    }
+    
+    /// Configuration intermédiaire entre `a` (t = 0) et `b` (t = 1)
+    /// 
+    /// - dimensions, opacités, décalages : interpolation linéaire
+    /// - nombres de segments et de triangles : interpolés puis arrondis
+    ///   à l'entier le plus proche
+    /// - couleurs : mélange dans l'espace de couleur de `a` (`Mix`)
+    /// - autres réglages (lettre, options, profondeurs, ...) : ceux de `a`
+    ///   jusqu'à t = 0.5, puis ceux de `b`
+    /// 
+    /// `t` est ramené dans [0, 1].
+    pub fn lerp(a: &LogoConfig, b: &LogoConfig, t: f32) -> LogoConfig {
+        let t = t.clamp(0.0, 1.0);
+        let number = |x: f32, y: f32| x + (y - x) * t;
+        let count = |x: usize, y: usize| number(x as f32, y as f32).round() as usize;
+        let vector = |x: Vec2, y: Vec2| x.lerp(y, t);
+        let color = |x: Color, y: Color| x.mix(&y, t);
+        
+        LogoConfig {
+            // === DIMENSIONS ===
+            circle_radius: number(a.circle_radius, b.circle_radius),
+            circle_thickness: number(a.circle_thickness, b.circle_thickness),
+            small_triangle_side: number(a.small_triangle_side, b.small_triangle_side),
+            exterior_gap_degrees: number(a.exterior_gap_degrees, b.exterior_gap_degrees),
+            exterior_height_ratio: number(a.exterior_height_ratio, b.exterior_height_ratio),
+            large_triangle_side: number(a.large_triangle_side, b.large_triangle_side),
+            interior_radius_fraction: number(a.interior_radius_fraction, b.interior_radius_fraction),
+            interior_height_ratio: number(a.interior_height_ratio, b.interior_height_ratio),
+            small_circle_radius: number(a.small_circle_radius, b.small_circle_radius),
+            center_hub_radius: number(a.center_hub_radius, b.center_hub_radius),
+            letter_scale: number(a.letter_scale, b.letter_scale),
+            r_outline_width: number(a.r_outline_width, b.r_outline_width),
+            grid_cell_size: number(a.grid_cell_size, b.grid_cell_size),
+            spiral_turns: number(a.spiral_turns, b.spiral_turns),
+            spiral_radius: number(a.spiral_radius, b.spiral_radius),
+            spiral_thickness: number(a.spiral_thickness, b.spiral_thickness),
+            fit_margin: number(a.fit_margin, b.fit_margin),
+            rainbow_start_hue: number(a.rainbow_start_hue, b.rainbow_start_hue),
+            glow_strength: number(a.glow_strength, b.glow_strength),
+            logo_offset: vector(a.logo_offset, b.logo_offset),
+            logo_scale: vector(a.logo_scale, b.logo_scale),
+            shadow_offset: vector(a.shadow_offset, b.shadow_offset),
+            
+            // === OPACITÉS ===
+            shadow_opacity: number(a.shadow_opacity, b.shadow_opacity),
+            global_opacity: number(a.global_opacity, b.global_opacity),
+            main_circle_alpha: number(a.main_circle_alpha, b.main_circle_alpha),
+            
+            // === NOMBRES (ARRONDIS) ===
+            ring_segments: count(a.ring_segments, b.ring_segments),
+            dot_segments: count(a.dot_segments, b.dot_segments),
+            hub_segments: count(a.hub_segments, b.hub_segments),
+            exterior_triangles_count: count(a.exterior_triangles_count, b.exterior_triangles_count),
+            interior_triangles_count: count(a.interior_triangles_count, b.interior_triangles_count),
+            center_star_points: count(a.center_star_points, b.center_star_points),
+            
+            // === COULEURS ===
+            background_color: color(a.background_color, b.background_color),
+            r_outline_color: color(a.r_outline_color, b.r_outline_color),
+            center_hub_color: color(a.center_hub_color, b.center_hub_color),
+            
+            ..if t < 0.5 { a.clone() } else { b.clone() }
+        }
+    }
+    
+    /// Crée un constructeur partant des valeurs par défaut
+    /// 
+    /// ```ignore
+    /// let config = LogoConfig::builder()
+    ///     .circle_radius(250.0)
+    ///     .exterior_count(24)
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> LogoConfigBuilder {
+        LogoConfigBuilder::default()
+    }
+    
+    /// Lit une configuration depuis un fichier TOML et la vérifie
+    /// 
+    /// Les champs portent le nom de ceux de `LogoConfig` ; les champs
+    /// absents gardent leur valeur par défaut :
+    /// ```toml
+    /// circle_radius = 250.0
+    /// exterior_triangles_count = 24
+    /// letter = "S"
+    /// ```
+    /// 
+    /// # Erreurs
+    /// - `LogoError::Io` si le fichier ne peut pas être lu
+    /// - `LogoError::Parse` si le TOML est invalide ou ne correspond pas
+    ///   aux champs de `LogoConfig`
+    /// - `LogoError::InvalidConfig` si `validate` refuse la configuration
+    pub fn from_toml_path(path: impl AsRef<Path>) -> Result<Self, LogoError> {
+        let text = std::fs::read_to_string(path)?;
+        let config: LogoConfig = toml::from_str(&text)?;
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Écrit la configuration au format TOML (relu par `from_toml_path`)
+    /// 
+    /// # Erreurs
+    /// `LogoError::Parse` si une valeur n'a pas d'équivalent TOML
+    /// (graine `random_palette_seed` au-delà de `i64::MAX`)
+    pub fn to_toml_string(&self) -> Result<String, LogoError> {
+        toml::to_string(self).map_err(|error| LogoError::Parse(error.to_string()))
+    }
+    
+    /// Vérifie la cohérence de la configuration
+    /// 
+    /// # Règles
+    /// - rayons et tailles strictement positifs et finis (grille et
+    ///   spirale comprises, même masquées), ainsi que `letter_scale` et
+    ///   `r_z_step`
+    /// - marges de la caméra et espacement de la mosaïque positifs ou nuls
+    /// - opacités dans [0, 1]
+    /// - épaisseur de l'anneau dans ]0, rayon]
+    /// - au moins 3 segments par cercle
+    /// - au moins 1 triangle extérieur et 3 triangles intérieurs
+    /// - espaces entre triangles extérieurs inférieurs à un tour complet
+    /// - lettre présente dans `geometry::SUPPORTED_LETTERS`
+    /// - MSAA à 1, 2, 4 ou 8 échantillons
+    /// - zoom minimal positif et inférieur au zoom maximal
+    /// 
+    /// # Erreurs
+    /// La première règle non respectée, sous forme de `ConfigError`.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        // === DIMENSIONS ===
+        let dimensions = [
+            ("circle_radius", self.circle_radius),
+            ("small_triangle_side", self.small_triangle_side),
+            ("large_triangle_side", self.large_triangle_side),
+            ("small_circle_radius", self.small_circle_radius),
+            ("center_hub_radius", self.center_hub_radius),
+            ("interior_radius_fraction", self.interior_radius_fraction),
+            ("exterior_height_ratio", self.exterior_height_ratio),
+            ("interior_height_ratio", self.interior_height_ratio),
+            ("logo_scale.x", self.logo_scale.x),
+            ("logo_scale.y", self.logo_scale.y),
+            ("grid_cell_size", self.grid_cell_size),
+            ("spiral_turns", self.spiral_turns),
+            ("spiral_radius", self.spiral_radius),
+            ("spiral_thickness", self.spiral_thickness),
+            ("letter_scale", self.letter_scale),
+            ("r_z_step", self.r_z_step),
+        ];
+        for (field, value) in dimensions {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(ConfigError::NonPositive { field, value });
+            }
+        }
+        let mut margins = vec![("pan_margin", self.pan_margin), ("fit_margin", self.fit_margin)];
+        if let Some((_, _, spacing)) = self.tile {
+            margins.push(("tile", spacing));
+        }
+        for (field, value) in margins {
+            if !(value >= 0.0 && value.is_finite()) {
+                return Err(ConfigError::Negative { field, value });
+            }
+        }
+        for (field, value) in [("shadow_opacity", self.shadow_opacity), ("global_opacity", self.global_opacity)] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(ConfigError::OutOfUnitRange { field, value });
+            }
+        }
+        if !(self.circle_thickness > 0.0 && self.circle_thickness <= self.circle_radius) {
+            return Err(ConfigError::InvalidThickness {
+                thickness: self.circle_thickness,
+                radius: self.circle_radius,
+            });
+        }
+        
+        // === NOMBRES DE SEGMENTS ET DE TRIANGLES ===
+        let counts = [
+            ("ring_segments", self.ring_segments, 3),
+            ("dot_segments", self.dot_segments, 3),
+            ("hub_segments", self.hub_segments, 3),
+            ("exterior_triangles_count", self.exterior_triangles_count, 1),
+            ("interior_triangles_count", self.interior_triangles_count, 3),
+        ];
+        for (field, value, min) in counts {
+            if value < min {
+                return Err(ConfigError::TooFew { field, value, min });
+            }
+        }
+        if let Some((rows, cols, _)) = self.tile {
+            let value = rows.min(cols);
+            if value < 1 {
+                return Err(ConfigError::TooFew { field: "tile", value, min: 1 });
+            }
+        }
+        let gap = self.exterior_gap_degrees;
+        if !(gap.is_finite() && gap * (self.exterior_triangles_count as f32) < 360.0) {
+            return Err(ConfigError::InvalidExteriorGap { gap, count: self.exterior_triangles_count });
+        }
+        
+        // === LETTRE, RENDU, CAMÉRA ===
+        if self.custom_parts.is_none() && crate::geometry::get_letter_parts(self.letter).is_none() {
+            return Err(ConfigError::UnsupportedLetter(self.letter));
+        }
+        if !matches!(self.msaa_samples, 1 | 2 | 4 | 8) {
+            return Err(ConfigError::InvalidMsaa(self.msaa_samples));
+        }
+        if !(self.zoom_min > 0.0 && self.zoom_min <= self.zoom_max) {
+            return Err(ConfigError::InvalidZoomRange {
+                min: self.zoom_min,
+                max: self.zoom_max,
+            });
+        }
+        
+        // === PROFONDEURS DES COUCHES ===
+        let mut letter_parts = self.letter_parts();
+        crate::geometry::assign_z_orders(&mut letter_parts, self.r_base_z, self.r_z_step);
+        let layers = crate::build::layer_depths(self, &letter_parts);
+        let z_values: Vec<f32> = layers.iter().map(|(_, z)| *z).collect();
+        if let Some(&(i, j)) = crate::geometry::find_z_collisions(&z_values).first() {
+            return Err(ConfigError::LayerCollision {
+                first: layers[i].0.clone(),
+                second: layers[j].0.clone(),
+                z: layers[i].1,
+            });
+        }
+        
+        Ok(())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//                    VALIDATION ET CONSTRUCTION DE LA CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Erreur de cohérence détectée par `LogoConfig::validate`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// Une dimension est nulle, négative, infinie ou NaN
+    NonPositive {
+        /// Nom du champ de `LogoConfig`
+        field: &'static str,
+        /// Valeur refusée
+        value: f32,
+    },
+    
+    /// Une marge est négative, infinie ou NaN
+    Negative {
+        /// Nom du champ de `LogoConfig`
+        field: &'static str,
+        /// Valeur refusée
+        value: f32,
+    },
+    
+    /// Une opacité sort de [0, 1]
+    OutOfUnitRange {
+        /// Nom du champ de `LogoConfig`
+        field: &'static str,
+        /// Valeur refusée
+        value: f32,
+    },
+    
+    /// L'épaisseur de l'anneau est nulle ou dépasse son rayon
+    InvalidThickness {
+        /// Épaisseur demandée
+        thickness: f32,
+        /// Rayon du cercle principal
+        radius: f32,
+    },
+    
+    /// Pas assez de segments ou de triangles
+    TooFew {
+        /// Nom du champ de `LogoConfig`
+        field: &'static str,
+        /// Valeur refusée
+        value: usize,
+        /// Minimum accepté
+        min: usize,
+    },
+    
+    /// Les espaces entre triangles extérieurs couvrent un tour complet
+    InvalidExteriorGap {
+        /// Espace demandé (degrés)
+        gap: f32,
+        /// Nombre de triangles extérieurs
+        count: usize,
+    },
+    
+    /// Lettre absente de `geometry::SUPPORTED_LETTERS`
+    UnsupportedLetter(char),
+    
+    /// Nombre d'échantillons MSAA autre que 1, 2, 4 ou 8
+    InvalidMsaa(u32),
+    
+    /// Bornes du zoom incohérentes
+    InvalidZoomRange {
+        /// Zoom minimal
+        min: f32,
+        /// Zoom maximal
+        max: f32,
+    },
+    
+    /// Deux couches (ou parties de la lettre) à la même profondeur
+    LayerCollision {
+        /// Première couche
+        first: Cow<'static, str>,
+        /// Seconde couche
+        second: Cow<'static, str>,
+        /// Profondeur partagée
+        z: f32,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NonPositive { field, value } => {
+                write!(f, "{} doit être strictement positif et fini (reçu {})", field, value)
+            }
+            ConfigError::Negative { field, value } => {
+                write!(f, "{} doit être positif ou nul et fini (reçu {})", field, value)
+            }
+            ConfigError::OutOfUnitRange { field, value } => {
+                write!(f, "{} doit être compris entre 0 et 1 (reçu {})", field, value)
+            }
+            ConfigError::InvalidThickness { thickness, radius } => {
+                write!(f, "épaisseur {} invalide pour un rayon de {}", thickness, radius)
+            }
+            ConfigError::TooFew { field, value, min } => {
+                write!(f, "{} doit valoir au moins {} (reçu {})", field, min, value)
+            }
+            ConfigError::InvalidExteriorGap { gap, count } => {
+                write!(f, "{} espaces de {}° dépassent un tour complet", count, gap)
+            }
+            ConfigError::UnsupportedLetter(letter) => {
+                write!(f, "lettre '{}' non supportée", letter)
+            }
+            ConfigError::InvalidMsaa(samples) => {
+                write!(f, "MSAA à {} échantillons non supporté (1, 2, 4 ou 8)", samples)
+            }
+            ConfigError::InvalidZoomRange { min, max } => {
+                write!(f, "bornes de zoom invalides ({} à {})", min, max)
+            }
+            ConfigError::LayerCollision { first, second, z } => {
+                write!(f, "'{}' et '{}' partagent la même profondeur (Z={})", first, second, z)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Constructeur de `LogoConfig` avec validation
+/// 
+/// Part de `LogoConfig::default()` ; chaque méthode modifie un réglage
+/// et renvoie le constructeur. `build` vérifie l'ensemble avec
+/// `LogoConfig::validate`.
+#[derive(Clone, Debug, Default)]
+pub struct LogoConfigBuilder {
+    config: LogoConfig,
+}
+
+impl LogoConfigBuilder {
+    /// Rayon du cercle principal
+    pub fn circle_radius(mut self, radius: f32) -> Self {
+        self.config.circle_radius = radius;
+        self
+    }
+    
+    /// Épaisseur de l'anneau
+    pub fn circle_thickness(mut self, thickness: f32) -> Self {
+        self.config.circle_thickness = thickness;
+        self
+    }
+    
+    /// Nombre de segments de l'anneau
+    pub fn ring_segments(mut self, segments: usize) -> Self {
+        self.config.ring_segments = segments;
+        self
+    }
+    
+    /// Nombre de triangles extérieurs
+    pub fn exterior_count(mut self, count: usize) -> Self {
+        self.config.exterior_triangles_count = count;
+        self
+    }
+    
+    /// Côté des triangles extérieurs
+    pub fn small_triangle_side(mut self, side: f32) -> Self {
+        self.config.small_triangle_side = side;
+        self
+    }
+    
+    /// Nombre de triangles intérieurs
+    pub fn interior_count(mut self, count: usize) -> Self {
+        self.config.interior_triangles_count = count;
+        self
+    }
+    
+    /// Côté des triangles intérieurs
+    pub fn large_triangle_side(mut self, side: f32) -> Self {
+        self.config.large_triangle_side = side;
+        self
+    }
+    
+    /// Rayon et nombre de segments des petits cercles centraux
+    pub fn small_circles(mut self, radius: f32, segments: usize) -> Self {
+        self.config.small_circle_radius = radius;
+        self.config.dot_segments = segments;
+        self
+    }
+    
+    /// Lettre affichée au centre
+    pub fn letter(mut self, letter: char) -> Self {
+        self.config.letter = letter;
+        self
+    }
+    
+    /// Parties personnalisées dessinées à la place de la lettre
+    pub fn custom_parts(mut self, parts: Vec<crate::geometry::RPartDefinition>) -> Self {
+        self.config.custom_parts = Some(parts);
+        self
+    }
+    
+    /// Couleur de fond de la fenêtre
+    pub fn background(mut self, color: Color) -> Self {
+        self.config.background_color = color;
+        self
+    }
+    
+    /// Rendu de l'anneau arc-en-ciel
+    pub fn rainbow_style(mut self, style: RainbowStyle) -> Self {
+        self.config.rainbow_style = style;
+        self
+    }
+    
+    /// Nombre d'échantillons MSAA
+    pub fn msaa_samples(mut self, samples: u32) -> Self {
+        self.config.msaa_samples = samples;
+        self
+    }
+    
+    /// Active ou non l'animation d'introduction
+    pub fn intro_animation(mut self, enabled: bool) -> Self {
+        self.config.intro_animation = enabled;
+        self
+    }
+    
+    /// Active ou non les ombres portées
+    pub fn drop_shadow(mut self, enabled: bool) -> Self {
+        self.config.drop_shadow = enabled;
+        self
+    }
+    
+    /// Affiche ou non la grille de référence
+    pub fn show_grid(mut self, enabled: bool) -> Self {
+        self.config.show_grid = enabled;
+        self
+    }
+    
+    /// Active le disque central avec son rayon et sa couleur
+    pub fn center_hub(mut self, radius: f32, color: Color) -> Self {
+        self.config.center_hub = true;
+        self.config.center_hub_radius = radius;
+        self.config.center_hub_color = color;
+        self
+    }
+    
+    /// Nombre de segments du disque central
+    pub fn hub_segments(mut self, segments: usize) -> Self {
+        self.config.hub_segments = segments;
+        self
+    }
+    
+    /// Étirement du logo entier (largeur, hauteur)
+    pub fn logo_scale(mut self, scale: Vec2) -> Self {
+        self.config.logo_scale = scale;
+        self
+    }
+    
+    /// Décalage du logo par rapport au centre de la fenêtre
+    pub fn logo_offset(mut self, offset: Vec2) -> Self {
+        self.config.logo_offset = offset;
+        self
+    }
+    
+    /// Vérifie et renvoie la configuration
+    /// 
+    /// # Erreurs
+    /// Voir `LogoConfig::validate`.
+    pub fn build(self) -> Result<LogoConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
@@ -0,0 +1,177 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: src/describe.rs                           ║
+// ║  Description textuelle de la scène                                       ║
+// ║  Rôle : Lister ce qui serait affiché, sans fenêtre ni ECS               ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module describe
+//! 
+//! Construit, à partir des fonctions `build_*`, la liste des éléments que
+//! `setup_system` afficherait : type, couleur, position et nombre de
+//! vertices. La description est sérialisable (serde) : en JSON, elle
+//! permet de comparer deux versions de la scène dans un script ou en CI.
+//! 
+//! Seule la scène initiale est décrite (pas les animations, ni les
+//! ombres portées ajoutées après coup). En mosaïque, chaque case est une
+//! copie du même logo : ses éléments sont décrits une fois, et la
+//! position de chaque racine est listée dans `SceneDescription::tiles`.
+
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+use serde::Serialize;
+use crate::build::{self, BuiltElement};
+use crate::config::{LogoConfig, RainbowStyle};
+use crate::geometry::RPartDefinition;
+use crate::systems::layout;
+use crate::systems::setup::letter_parts_for;
+
+/// Description d'un élément de la scène
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ElementDescription {
+    /// Type d'élément (ex : "main_circle", "letter_part")
+    pub kind: &'static str,
+    
+    /// Nom de la partie de la lettre (vide pour les autres éléments)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'static, str>>,
+    
+    /// Couleur du matériau en hexadécimal sRGB (#RRGGBB ou #RRGGBBAA)
+    pub color: String,
+    
+    /// Position (x, y, z) relative à la racine du logo
+    pub translation: [f32; 3],
+    
+    /// Rotation autour de Z (en degrés)
+    pub rotation_degrees: f32,
+    
+    /// Échelle (x, y)
+    pub scale: [f32; 2],
+    
+    /// Nombre de vertices du mesh
+    pub vertex_count: usize,
+}
+
+/// Description complète de la scène (voir `describe_scene`)
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SceneDescription {
+    /// Lettre affichée au centre du logo
+    pub letter: char,
+    
+    /// Position (x, y) de la racine de chaque logo de la mosaïque
+    /// 
+    /// Une seule entrée sans mosaïque. Décalage du logo compris, avant
+    /// l'ajustement à la fenêtre.
+    pub tiles: Vec<[f32; 2]>,
+    
+    /// Éléments d'un logo, de l'arrière-plan vers l'avant (ordre de création)
+    /// 
+    /// Positions relatives à la racine : chaque case de `tiles` affiche
+    /// les mêmes éléments.
+    pub elements: Vec<ElementDescription>,
+}
+
+impl SceneDescription {
+    /// Éléments d'un type donné
+    pub fn elements_of_kind<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a ElementDescription> + 'a {
+        self.elements.iter().filter(move |element| element.kind == kind)
+    }
+}
+
+/// Décrit la scène que `setup_system` créerait pour une configuration
+/// 
+/// Suit le même ordre et les mêmes conditions que la création du logo
+/// (grille, spirale, style de l'arc-en-ciel, étoiles, contours, repères
+/// de centroïdes, mosaïque, ...).
+/// 
+/// # Arguments
+/// * `config` - Configuration du logo
+pub fn describe_scene(config: &LogoConfig) -> SceneDescription {
+    let mut elements = Vec::new();
+    let mut push = |kind, name, element: BuiltElement| {
+        elements.push(describe_element(kind, name, &element));
+    };
+    
+    // === FOND ===
+    if config.show_grid {
+        push("grid", None, build::build_grid(config));
+    }
+    if config.show_spiral {
+        push("spiral", None, build::build_spiral(config));
+    }
+    
+    // === ANNEAU ET TRIANGLES ===
+    push("main_circle", None, build::build_main_circle(config));
+    match config.rainbow_style {
+        RainbowStyle::Gradient => push("rainbow_ring", None, build::build_rainbow_ring(config)),
+        RainbowStyle::Discrete => {
+            for element in build::build_exterior_triangles(config) {
+                push("exterior_triangle", None, element);
+            }
+        }
+    }
+    for element in build::build_interior_triangles(config) {
+        push("interior_triangle", None, element);
+    }
+    if config.center_hub {
+        push("center_hub", None, build::build_center_hub(config));
+    }
+    for element in build::build_center_stars(config) {
+        push("center_star", None, element);
+    }
+    for element in build::build_center_dots(config) {
+        push("center_dot", None, element);
+    }
+    
+    // === LETTRE ===
+    let letter_parts = letter_parts_for(config).unwrap_or_default();
+    let drawable: Vec<RPartDefinition> = build::drawable_letter_parts(config, &letter_parts)
+        .into_iter()
+        .map(|(_, part)| part)
+        .collect();
+    let merged = config.merge_r_parts.then(|| build::build_merged_letter(&drawable)).flatten();
+    let is_merged = merged.is_some();
+    if let Some(element) = merged {
+        push("merged_letter", None, element);
+    }
+    for part in drawable {
+        if !is_merged {
+            push("letter_part", Some(part.name.clone()), build::build_letter_part(&part));
+        }
+        if config.r_outline_width > 0.0 {
+            push("letter_outline", Some(part.name.clone()), build::build_letter_outline(config, &part));
+        }
+    }
+    
+    // === REPÈRES DE DÉBOGAGE ===
+    if config.show_centroids {
+        for element in build::build_centroid_markers(config) {
+            push("centroid_marker", None, element);
+        }
+    }
+    
+    // === MOSAÏQUE ===
+    let tiles = layout::tile_offsets(config)
+        .into_iter()
+        .map(|offset| (config.logo_offset + offset).to_array())
+        .collect();
+    
+    SceneDescription { letter: config.letter, tiles, elements }
+}
+
+/// Décrit un élément construit
+fn describe_element(kind: &'static str, name: Option<Cow<'static, str>>, element: &BuiltElement) -> ElementDescription {
+    let (mesh, material, transform) = element;
+    let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+    
+    ElementDescription {
+        kind,
+        name,
+        color: material.color.to_srgba().to_hex(),
+        translation: transform.translation.to_array(),
+        // + 0.0 : évite d'afficher "-0.0" pour une rotation nulle
+        rotation_degrees: angle.to_degrees() + 0.0,
+        scale: transform.scale.truncate().to_array(),
+        vertex_count: mesh.count_vertices(),
+    }
+}
@@ -0,0 +1,102 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: src/draw.rs                             ║
+// ║  Dessin de formes simples à la demande                                   ║
+// ║  Rôle : Créer cercles, polygones et traits en une seule ligne           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module draw
+//! 
+//! Petites fonctions pour ajouter des formes à la scène sans répéter
+//! l'assemblage `Mesh2d` + `MeshMaterial2d` + `Transform` :
+//! 
+//! ```ignore
+//! fn ma_scene(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<ColorMaterial>>) {
+//!     draw::spawn_circle(&mut commands, &mut meshes, &mut materials, Vec2::ZERO, 50.0, Color::WHITE, 0.0);
+//! }
+//! ```
+//! 
+//! Les coordonnées sont celles du monde (ou du parent, si l'entité
+//! retournée est rattachée à une autre avec `ChildOf`). Le matériau est
+//! en mode transparent dès que l'alpha de la couleur est inférieur à 1.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+
+/// Crée un disque plein
+/// 
+/// Le nombre de segments est choisi selon le rayon
+/// (`geometry::recommended_segments` avec `config::MAX_CHORD_ERROR`).
+/// 
+/// # Arguments
+/// * `center` - Centre du disque
+/// * `radius` - Rayon du disque
+/// * `color` - Couleur de remplissage
+/// * `z` - Profondeur de dessin
+/// 
+/// # Retour
+/// Les commandes de l'entité créée (pour lui ajouter des composants)
+pub fn spawn_circle<'a>(
+    commands: &'a mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    center: Vec2,
+    radius: f32,
+    color: Color,
+    z: f32,
+) -> EntityCommands<'a> {
+    let segments = geometry::recommended_segments(radius, config::MAX_CHORD_ERROR);
+    
+    commands.spawn((
+        Mesh2d(meshes.add(geometry::create_filled_circle_mesh(radius, segments))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+        Transform::from_translation(center.extend(z)),
+    ))
+}
+
+/// Crée un polygone plein à partir de ses sommets
+/// 
+/// # Arguments
+/// * `points` - Sommets du polygone (sens horaire ou antihoraire)
+/// * `color` - Couleur de remplissage
+/// * `z` - Profondeur de dessin
+/// 
+/// # Panics
+/// Si `points` contient moins de 3 sommets (voir `create_polygon_from_points`)
+pub fn spawn_polygon<'a>(
+    commands: &'a mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    points: &[Vec2],
+    color: Color,
+    z: f32,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Mesh2d(meshes.add(geometry::create_polygon_from_points(points))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+        Transform::from_xyz(0.0, 0.0, z),
+    ))
+}
+
+/// Crée un trait épais entre deux points
+/// 
+/// # Arguments
+/// * `(start, end)` - Extrémités du trait
+/// * `thickness` - Épaisseur du trait
+/// * `color` - Couleur du trait
+/// * `z` - Profondeur de dessin
+pub fn spawn_line<'a>(
+    commands: &'a mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    (start, end): (Vec2, Vec2),
+    thickness: f32,
+    color: Color,
+    z: f32,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Mesh2d(meshes.add(geometry::create_thick_line_mesh(start, end, thickness))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+        Transform::from_xyz(0.0, 0.0, z),
+    ))
+}
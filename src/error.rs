@@ -0,0 +1,85 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/error.rs                             ║
+// ║  Type d'erreur commun de la bibliothèque                                 ║
+// ║  Rôle : Regrouper les échecs des fonctions faillibles en un seul type   ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module error
+//! 
+//! `LogoError` est renvoyé par les fonctions qui peuvent échouer
+//! (`try_create_polygon_from_points`, `load_r_parts`, `to_obj`,
+//! `LogoConfig::from_toml_path`, ...) :
+//! un seul type à traiter plutôt que des panics ou des valeurs corrigées
+//! en silence.
+
+use std::fmt;
+use std::io;
+use crate::config::ConfigError;
+
+/// Erreur des fonctions faillibles de la bibliothèque
+#[derive(Debug)]
+pub enum LogoError {
+    /// Pas assez de points pour former un polygone (ou un mesh)
+    TooFewPoints {
+        /// Nombre de points reçus
+        count: usize,
+        /// Minimum nécessaire
+        min: usize,
+    },
+    
+    /// Configuration refusée par `LogoConfig::validate`
+    InvalidConfig(ConfigError),
+    
+    /// Erreur de lecture ou d'écriture d'un fichier
+    Io(io::Error),
+    
+    /// Contenu de fichier illisible (format, valeurs)
+    Parse(String),
+}
+
+impl fmt::Display for LogoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogoError::TooFewPoints { count, min } => {
+                write!(f, "au moins {} points nécessaires (reçu {})", min, count)
+            }
+            LogoError::InvalidConfig(error) => write!(f, "configuration invalide : {}", error),
+            LogoError::Io(error) => write!(f, "erreur d'entrée/sortie : {}", error),
+            LogoError::Parse(message) => write!(f, "fichier illisible : {}", message),
+        }
+    }
+}
+
+impl std::error::Error for LogoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LogoError::InvalidConfig(error) => Some(error),
+            LogoError::Io(error) => Some(error),
+            LogoError::TooFewPoints { .. } | LogoError::Parse(_) => None,
+        }
+    }
+}
+
+impl From<ConfigError> for LogoError {
+    fn from(error: ConfigError) -> Self {
+        LogoError::InvalidConfig(error)
+    }
+}
+
+impl From<io::Error> for LogoError {
+    fn from(error: io::Error) -> Self {
+        LogoError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for LogoError {
+    fn from(error: serde_json::Error) -> Self {
+        LogoError::Parse(error.to_string())
+    }
+}
+
+impl From<toml::de::Error> for LogoError {
+    fn from(error: toml::de::Error) -> Self {
+        LogoError::Parse(error.to_string())
+    }
+}
@@ -11,12 +11,21 @@
 //! de formes géométriques. Il est divisé en plusieurs sections :
 //! 
 //! 1. Fonctions utilitaires (conversion d'angles)
-//! 2. Création de mesh basiques (cercles, triangles)
-//! 3. Calculs de positions pour les triangles extérieurs/intérieurs
+//! 2. Création de mesh basiques (cercles, triangles, polygones, traits)
+//! 3. Calculs de positions (triangles, centroïdes, boîtes englobantes)
 //! 4. Définition du logo "R" personnalisé
+//! 5. Traitement des chemins de points (lissage, simplification, ...)
 
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Write as _;
+use std::path::Path;
 use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
+use crate::config;
+use crate::error::LogoError;
 
 // ═══════════════════════════════════════════════════════════════════════════
 //                        SECTION 1 : FONCTIONS UTILITAIRES
@@ -39,6 +48,80 @@ pub fn degrees_to_radians(degrees: f32) -> f32 {
     degrees * PI / 180.0
 }
 
+/// Ramène un angle dans l'intervalle [0, 2π[
+/// 
+/// # Exemples
+/// - 3π → π
+/// - -π/2 → 3π/2
+/// - 2π → 0
+pub fn normalize_angle(radians: f32) -> f32 {
+    let angle = radians.rem_euclid(2.0 * PI);
+    // rem_euclid peut renvoyer 2π pour un angle négatif très petit (arrondi)
+    if angle >= 2.0 * PI { 0.0 } else { angle }
+}
+
+/// Direction du segment `from` → `to`, en radians
+/// 
+/// Angle mesuré depuis l'axe +X, dans le sens antihoraire, dans ]-π, π]
+/// (`atan2`). Par exemple, de l'origine vers (0, 1) : π/2.
+/// Des points confondus donnent 0.
+pub fn angle_between_points(from: Vec2, to: Vec2) -> f32 {
+    let direction = to - from;
+    direction.y.atan2(direction.x)
+}
+
+/// Calcule le nombre de segments nécessaire pour un cercle lisse
+/// 
+/// Un cercle découpé en n segments s'écarte du vrai cercle d'au plus
+/// la flèche d'une corde : r × (1 - cos(π / n)). Cette fonction renvoie
+/// le plus petit n pour lequel cet écart reste sous `max_error_px`.
+/// 
+/// # Arguments
+/// * `radius` - Rayon du cercle (en pixels)
+/// * `max_error_px` - Écart maximal toléré entre corde et cercle
+/// 
+/// # Formule
+/// n = ⌈π / acos(1 - erreur / rayon)⌉
+/// 
+/// # Bornes
+/// Le résultat est limité à [MIN_ADAPTIVE_SEGMENTS, MAX_ADAPTIVE_SEGMENTS]
+/// (16 à 512 par défaut). Une erreur nulle ou négative donne le maximum.
+pub fn recommended_segments(radius: f32, max_error_px: f32) -> usize {
+    if max_error_px <= 0.0 {
+        return config::MAX_ADAPTIVE_SEGMENTS;
+    }
+    if radius <= max_error_px {
+        return config::MIN_ADAPTIVE_SEGMENTS;
+    }
+    
+    let half_angle = (1.0 - max_error_px / radius).acos();
+    let segments = (PI / half_angle).ceil() as usize;
+    
+    segments.clamp(config::MIN_ADAPTIVE_SEGMENTS, config::MAX_ADAPTIVE_SEGMENTS)
+}
+
+/// Aligne un point sur la grille la plus proche
+/// 
+/// Chaque coordonnée est arrondie au multiple de `cell` le plus proche :
+/// utile pour garder des coordonnées propres lors de l'édition des
+/// parties de la lettre.
+/// 
+/// # Arguments
+/// * `point` - Point à aligner
+/// * `cell` - Pas de la grille (voir `LogoConfig::grid_cell_size`)
+/// 
+/// # Exemple
+/// (12.3, -7.8) avec un pas de 5 → (10.0, -10.0)
+/// 
+/// # Cas dégénérés
+/// Un pas nul ou négatif laisse le point inchangé.
+pub fn snap_to_grid(point: Vec2, cell: f32) -> Vec2 {
+    if cell <= 0.0 {
+        return point;
+    }
+    (point / cell).round() * cell
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //                      SECTION 2 : CRÉATION DE MESH BASIQUES
 // ═══════════════════════════════════════════════════════════════════════════
@@ -64,15 +147,36 @@ pub fn degrees_to_radians(degrees: f32) -> f32 {
 /// - 2N vertices (N extérieurs + N intérieurs)
 /// - 2N triangles (2 triangles par segment)
 /// - 6N indices (3 indices par triangle)
+/// 
+/// # Sens des triangles
+/// Tous les triangles sont émis dans le sens antihoraire (aire signée
+/// positive, Y vers le haut), comme ceux de `create_filled_circle_mesh` :
+/// un même réglage de `cull_mode` convient aux deux meshes.
 pub fn create_circle_mesh(outer_radius: f32, inner_radius: f32, segments: usize) -> Mesh {
+    create_circle_mesh_from(outer_radius, inner_radius, segments, 0.0)
+}
+
+/// Crée un mesh d'anneau dont le premier vertex est à l'angle `start_angle`
+/// 
+/// Identique à `create_circle_mesh`, avec tous les vertices tournés de
+/// `start_angle` : le vertex 0 (et le raccord du contour) est placé à cet
+/// angle. Utile pour aligner l'anneau sur les triangles extérieurs, une
+/// texture ou un arc partiel.
+/// 
+/// # Arguments
+/// * `outer_radius` - Rayon du cercle extérieur
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `segments` - Nombre de segments (qualité du cercle)
+/// * `start_angle` - Angle du premier vertex, en radians (0 = axe +X)
+pub fn create_circle_mesh_from(outer_radius: f32, inner_radius: f32, segments: usize, start_angle: f32) -> Mesh {
     let mut positions = Vec::new();
     let mut indices = Vec::new();
 
     // === GÉNÉRATION DES VERTICES DU CERCLE EXTÉRIEUR ===
-    // On parcourt l'angle de 0 à 2π pour faire le tour complet
+    // On parcourt l'angle de start_angle à start_angle + 2π pour faire le tour complet
     for i in 0..segments {
         // Angle du segment actuel
-        let angle = 2.0 * PI * i as f32 / segments as f32;
+        let angle = start_angle + 2.0 * PI * i as f32 / segments as f32;
         
         // Position du vertex sur le cercle extérieur
         // x = rayon × cos(angle), y = rayon × sin(angle)
@@ -86,7 +190,7 @@ pub fn create_circle_mesh(outer_radius: f32, inner_radius: f32, segments: usize)
     // === GÉNÉRATION DES VERTICES DU CERCLE INTÉRIEUR ===
     // Même principe mais avec le rayon intérieur
     for i in 0..segments {
-        let angle = 2.0 * PI * i as f32 / segments as f32;
+        let angle = start_angle + 2.0 * PI * i as f32 / segments as f32;
         positions.push([
             inner_radius * angle.cos(),
             inner_radius * angle.sin(),
@@ -96,21 +200,22 @@ pub fn create_circle_mesh(outer_radius: f32, inner_radius: f32, segments: usize)
 
     // === CRÉATION DES TRIANGLES ===
     // Pour chaque segment, on crée un quadrilatère puis on le divise en 2 triangles
+    // (sommets dans le sens antihoraire, comme create_filled_circle_mesh)
     for i in 0..segments {
         // Index du prochain segment (avec retour au début)
         let next = (i + 1) % segments;
         
         // Triangle 1 : coin inférieur gauche du quadrilatère
-        // Vertices : extérieur_i, intérieur_i, extérieur_next
+        // Vertices : extérieur_i, extérieur_next, intérieur_i
         indices.push(i as u32);
-        indices.push((segments + i) as u32);
         indices.push(next as u32);
+        indices.push((segments + i) as u32);
         
         // Triangle 2 : coin supérieur droit du quadrilatère
-        // Vertices : extérieur_next, intérieur_i, intérieur_next
+        // Vertices : extérieur_next, intérieur_next, intérieur_i
         indices.push(next as u32);
-        indices.push((segments + i) as u32);
         indices.push((segments + next) as u32);
+        indices.push((segments + i) as u32);
     }
 
     // === CRÉATION DU MESH BEVY ===
@@ -134,8 +239,26 @@ pub fn create_circle_mesh(outer_radius: f32, inner_radius: f32, segments: usize)
 /// 
 /// # Arguments
 /// * `radius` - Rayon du cercle
-/// * `segments` - Nombre de segments du contour
+/// * `segments` - Nombre de segments du contour (minimum 3)
+/// 
+/// # Minimum de segments
+/// En dessous de 3 segments le disque n'a pas de surface et l'éventail
+/// référencerait des vertices inexistants : la valeur est alors ramenée
+/// à `MIN_FILLED_CIRCLE_SEGMENTS` (3) avec un avertissement.
+/// 
+/// # Sens des triangles
+/// Chaque triangle (centre, vertex_i, vertex_suivant) est antihoraire :
+/// même convention que `create_circle_mesh`.
 pub fn create_filled_circle_mesh(radius: f32, segments: usize) -> Mesh {
+    // === VALIDATION ===
+    let segments = if segments < config::MIN_FILLED_CIRCLE_SEGMENTS {
+        println!("⚠️ Cercle plein à {} segments : minimum {} utilisé",
+                 segments, config::MIN_FILLED_CIRCLE_SEGMENTS);
+        config::MIN_FILLED_CIRCLE_SEGMENTS
+    } else {
+        segments
+    };
+    
     let mut positions = Vec::new();
     let mut indices = Vec::new();
 
@@ -160,7 +283,7 @@ pub fn create_filled_circle_mesh(radius: f32, segments: usize) -> Mesh {
         // Calcul de l'index suivant (retour au début pour le dernier)
         let next = if i == segments - 1 { 1 } else { i + 2 };
         
-        // Triangle : centre, vertex_i, vertex_suivant
+        // Triangle antihoraire : centre, vertex_i, vertex_suivant
         indices.push(0);              // Centre (index 0)
         indices.push((i + 1) as u32); // Vertex actuel
         indices.push(next as u32);    // Vertex suivant
@@ -174,70 +297,164 @@ pub fn create_filled_circle_mesh(radius: f32, segments: usize) -> Mesh {
     .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
 }
 
-/// Crée un triangle à partir de trois points 2D
+/// Crée un mesh de roue dentée (engrenage)
 /// 
-/// Fonction simple qui convertit 3 points Vec2 en un mesh triangulaire.
+/// La roue est un anneau (le corps, de `inner_radius` à `root_radius`)
+/// entouré de dents trapézoïdales qui montent jusqu'à `tip_radius`.
+/// Comme pour `create_circle_mesh`, on parcourt le cercle angle par angle.
 /// 
 /// # Arguments
-/// * `p1`, `p2`, `p3` - Les trois sommets du triangle
+/// * `teeth` - Nombre de dents (minimum `GEAR_MIN_TEETH`, soit 3)
+/// * `inner_radius` - Rayon du trou central
+/// * `root_radius` - Rayon du pied des dents (bord extérieur du corps)
+/// * `tip_radius` - Rayon de la pointe des dents
+/// * `tooth_width_ratio` - Part du pas angulaire occupée par le pied
+///   d'une dent (borné à [0.05, 0.95], 0.5 = dents et creux égaux)
 /// 
-/// # Note
-/// L'ordre des points définit l'orientation du triangle (sens horaire/antihoraire)
-/// ce qui affecte la face visible (culling).
-pub fn create_triangle_from_points(p1: Vec2, p2: Vec2, p3: Vec2) -> Mesh {
-    // Conversion des points 2D en positions 3D (Z=0)
-    let positions = vec![
-        [p1.x, p1.y, 0.0],
-        [p2.x, p2.y, 0.0],
-        [p3.x, p3.y, 0.0],
-    ];
-
-    // Indices des 3 sommets dans l'ordre
-    let indices = vec![0u32, 1, 2];
-
+/// # Algorithme
+/// Pour chaque dent :
+/// 1. Deux vertices du corps aux coins du pied de la dent
+/// 2. `GEAR_GAP_SUBDIVISIONS - 1` vertices intermédiaires dans le creux
+/// 3. Deux vertices au sommet, plus resserrés (`GEAR_TIP_TAPER`)
+/// 
+/// Les coins du pied sont partagés entre le corps et la dent :
+/// aucun interstice n'apparaît à la jonction.
+/// 
+/// # Détails techniques
+/// Pour N dents et S subdivisions du creux :
+/// - N × (S + 1) vertices par cercle du corps (extérieur et intérieur)
+/// - 2N vertices de sommet
+/// - Total : N × (2S + 4) vertices
+/// 
+/// # Panic
+/// Panic si moins de `GEAR_MIN_TEETH` (3) dents sont demandées : avec
+/// moins de 3 dents, le corps n'a plus assez de sommets pour former un
+/// anneau. Un nombre venant de l'utilisateur doit être vérifié avant.
+pub fn create_gear_mesh(
+    teeth: usize,
+    inner_radius: f32,
+    root_radius: f32,
+    tip_radius: f32,
+    tooth_width_ratio: f32,
+) -> Mesh {
+    // === VALIDATION ===
+    if teeth < config::GEAR_MIN_TEETH {
+        panic!("Une roue dentée doit avoir au moins {} dents", config::GEAR_MIN_TEETH);
+    }
+    
+    // === ANGLES ===
+    let pitch = 2.0 * PI / teeth as f32;
+    let half_root = pitch * tooth_width_ratio.clamp(0.05, 0.95) / 2.0;
+    let half_tip = half_root * config::GEAR_TIP_TAPER;
+    let gap = pitch - 2.0 * half_root;
+    
+    // Angles des vertices du bord du corps : coins de chaque dent,
+    // puis points intermédiaires du creux qui la suit
+    let mut body_angles = Vec::new();
+    for tooth in 0..teeth {
+        let center = tooth as f32 * pitch;
+        body_angles.push(center - half_root);
+        body_angles.push(center + half_root);
+        for step in 1..config::GEAR_GAP_SUBDIVISIONS {
+            body_angles.push(center + half_root + gap * step as f32 / config::GEAR_GAP_SUBDIVISIONS as f32);
+        }
+    }
+    let body_count = body_angles.len();
+    let per_tooth = body_count / teeth;
+    
+    // === VERTICES ===
+    let mut positions = Vec::new();
+    
+    // Bord extérieur du corps (pied des dents)
+    for angle in &body_angles {
+        positions.push([root_radius * angle.cos(), root_radius * angle.sin(), 0.0]);
+    }
+    
+    // Bord intérieur du corps (trou central)
+    for angle in &body_angles {
+        positions.push([inner_radius * angle.cos(), inner_radius * angle.sin(), 0.0]);
+    }
+    
+    // Sommets des dents (2 par dent)
+    for tooth in 0..teeth {
+        let center = tooth as f32 * pitch;
+        for angle in [center - half_tip, center + half_tip] {
+            positions.push([tip_radius * angle.cos(), tip_radius * angle.sin(), 0.0]);
+        }
+    }
+    
+    // === TRIANGLES DU CORPS ===
+    // Même découpage que create_circle_mesh : 2 triangles par segment,
+    // sommets dans le sens antihoraire
+    let mut indices = Vec::new();
+    for i in 0..body_count {
+        let next = (i + 1) % body_count;
+        indices.extend([i, next, body_count + i]);
+        indices.extend([next, body_count + next, body_count + i]);
+    }
+    
+    // === TRIANGLES DES DENTS ===
+    // Trapèze : coins du pied (partagés avec le corps) et sommet
+    for tooth in 0..teeth {
+        let root_left = tooth * per_tooth;
+        let root_right = root_left + 1;
+        let tip_left = 2 * body_count + 2 * tooth;
+        let tip_right = tip_left + 1;
+        indices.extend([root_left, tip_right, root_right]);
+        indices.extend([root_left, tip_left, tip_right]);
+    }
+    
     Mesh::new(
         bevy::render::render_resource::PrimitiveTopology::TriangleList,
         bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
     )
     .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(
+        indices.into_iter().map(|index| index as u32).collect()
+    ))
 }
 
-/// Crée un polygone complexe à partir d'une liste de points
+/// Crée un mesh d'étoile pleine à `points` branches
 /// 
-/// Utilise une triangulation en éventail (fan triangulation) :
-/// - Tous les triangles partagent le premier vertex
-/// - Chaque triangle relie le premier vertex à deux vertices consécutifs
-/// 
-/// # Limitations
-/// Cette méthode fonctionne bien pour les polygones convexes.
-/// Pour les polygones concaves complexes, une triangulation plus
-/// sophistiquée (comme l'algorithme ear-clipping) serait nécessaire.
+/// Le contour alterne entre les pointes (`outer_radius`) et les creux
+/// (`inner_radius`), puis est triangulé en éventail autour du centre,
+/// comme `create_filled_circle_mesh`.
 /// 
 /// # Arguments
-/// * `points` - Slice de Vec2 représentant les sommets du polygone
+/// * `points` - Nombre de branches (minimum 3)
+/// * `outer_radius` - Rayon des pointes
+/// * `inner_radius` - Rayon des creux entre deux branches
+/// 
+/// # Orientation
+/// La première pointe est sur l'axe +X : une rotation d'angle θ la
+/// dirige dans la direction θ.
+/// 
+/// # Détails techniques
+/// - Vertices : 2 × points + 1 (centre + contour)
+/// - Triangles : 2 × points
 /// 
 /// # Panic
-/// Panic si moins de 3 points sont fournis
-pub fn create_polygon_from_points(points: &[Vec2]) -> Mesh {
-    if points.len() < 3 {
-        panic!("Un polygone doit avoir au moins 3 points");
+/// Panic si moins de 3 branches sont demandées
+pub fn create_star_mesh(points: usize, outer_radius: f32, inner_radius: f32) -> Mesh {
+    if points < 3 {
+        panic!("Une étoile doit avoir au moins 3 branches");
     }
     
-    // === CONVERSION DES POINTS EN POSITIONS 3D ===
-    let positions: Vec<[f32; 3]> = points
-        .iter()
-        .map(|p| [p.x, p.y, 0.0])
-        .collect();
+    // === VERTICES : CENTRE PUIS CONTOUR (POINTE, CREUX, ...) ===
+    let corners = 2 * points;
+    let mut positions = vec![[0.0, 0.0, 0.0]];
+    for i in 0..corners {
+        let angle = PI * i as f32 / points as f32;
+        let radius = if i % 2 == 0 { outer_radius } else { inner_radius };
+        positions.push([radius * angle.cos(), radius * angle.sin(), 0.0]);
+    }
     
-    // === TRIANGULATION EN ÉVENTAIL ===
-    // Pour N points : créer N-2 triangles
-    // Triangle i relie les vertices 0, i, i+1
-    let mut indices = Vec::new();
-    for i in 1..(points.len() - 1) {
-        indices.push(0u32);           // Premier vertex (pivot)
-        indices.push(i as u32);       // Vertex actuel
-        indices.push((i + 1) as u32); // Vertex suivant
+    // === TRIANGLES EN ÉVENTAIL ===
+    let mut indices = Vec::with_capacity(corners * 3);
+    for i in 0..corners {
+        let current = (i + 1) as u32;
+        let next = ((i + 1) % corners + 1) as u32;
+        indices.extend_from_slice(&[0, current, next]);
     }
     
     Mesh::new(
@@ -248,184 +465,1617 @@ pub fn create_polygon_from_points(points: &[Vec2]) -> Mesh {
     .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
 }
 
-// ═══════════════════════════════════════════════════════════════════════════
-//            SECTION 3 : CALCULS DE POSITIONS DES TRIANGLES
-// ═══════════════════════════════════════════════════════════════════════════
-
-/// Calcule les coordonnées d'un triangle extérieur
+/// Calcule le contour d'un cœur à partir de son équation paramétrique
 /// 
-/// Les triangles extérieurs sont positionnés de manière à ce qu'un
-/// de leurs côtés (la base) touche le cercle principal.
+/// Équation classique, pour t de 0 à 2π :
+/// - x(t) = 16 sin³(t)
+/// - y(t) = 13 cos(t) - 5 cos(2t) - 2 cos(3t) - cos(4t)
 /// 
-/// # Stratégie de positionnement
-/// 1. Calculer deux points sur le cercle (extrémités de la base)
-/// 2. Calculer le troisième point à l'extérieur du cercle
+/// La boîte englobante du contour est centrée sur l'origine, puis le
+/// contour est mis à l'échelle pour que son plus grand côté mesure `size`.
 /// 
 /// # Arguments
-/// * `base_angle` - Angle central du triangle en radians
-/// * `circle_radius` - Rayon du cercle de référence
-/// * `triangle_side` - Longueur d'un côté du triangle équilatéral
+/// * `size` - Taille du cœur (plus grand côté de la boîte englobante)
+/// * `segments` - Nombre de points du contour (minimum 3)
 /// 
-/// # Géométrie
-/// Pour un triangle équilatéral de côté L :
-/// - Hauteur : h = L × √3 / 2
-/// - Les deux points de base sont espacés angulairement de L/R radians
-///   où R est le rayon du cercle
-pub fn calculate_exterior_triangle_points(
-    base_angle: f32,
-    circle_radius: f32,
-    triangle_side: f32,
-) -> (Vec2, Vec2, Vec2) {
-    let half_side = triangle_side / 2.0;
-    let height = triangle_side * (3.0_f32.sqrt() / 2.0);
-    
-    // === CALCUL DES DEUX POINTS DE BASE SUR LE CERCLE ===
-    // L'écart angulaire est approximé par half_side / circle_radius
-    let angle1 = base_angle - (half_side / circle_radius);
-    let angle2 = base_angle + (half_side / circle_radius);
-    
-    // Point 1 : sur le cercle, à gauche du centre
-    let p1 = Vec2::new(
-        circle_radius * angle1.cos(),
-        circle_radius * angle1.sin(),
-    );
+/// # Détails techniques
+/// Le contour est fermé implicitement : le dernier point est relié au
+/// premier (t = 2π n'est pas répété). Il commence au creux du haut
+/// (t = 0) et tourne dans le sens horaire.
+pub fn heart_outline(size: f32, segments: usize) -> Vec<Vec2> {
+    let segments = segments.max(config::MIN_FILLED_CIRCLE_SEGMENTS);
     
-    // Point 2 : sur le cercle, à droite du centre
-    let p2 = Vec2::new(
-        circle_radius * angle2.cos(),
-        circle_radius * angle2.sin(),
-    );
+    // === ÉCHANTILLONNAGE DE LA COURBE ===
+    let raw: Vec<Vec2> = (0..segments)
+        .map(|i| {
+            let t = 2.0 * PI * i as f32 / segments as f32;
+            Vec2::new(
+                16.0 * t.sin().powi(3),
+                13.0 * t.cos() - 5.0 * (2.0 * t).cos() - 2.0 * (3.0 * t).cos() - (4.0 * t).cos(),
+            )
+        })
+        .collect();
     
-    // === CALCUL DU SOMMET EXTÉRIEUR ===
-    // Positionné à une distance de (rayon + hauteur) du centre
-    let p3 = Vec2::new(
-        (circle_radius + height) * base_angle.cos(),
-        (circle_radius + height) * base_angle.sin(),
-    );
+    // === CENTRAGE ET MISE À L'ÉCHELLE ===
+    let (min, max) = bounding_box(&raw);
+    let center = (min + max) / 2.0;
+    let scale = size / (max - min).max_element().max(f32::EPSILON);
     
-    (p1, p2, p3)
+    raw.into_iter().map(|p| (p - center) * scale).collect()
 }
 
-/// Calcule les coordonnées d'un triangle intérieur
+/// Crée un mesh de cœur plein
 /// 
-/// Similaire à calculate_exterior_triangle_points mais :
-/// - La base touche le cercle intérieur
-/// - Le sommet pointe vers le centre (intérieur)
+/// Le contour de `heart_outline` est triangulé en éventail autour du
+/// centre de sa boîte englobante (l'origine), comme `create_filled_circle_mesh`.
+/// Chaque rayon issu de ce centre ne coupe le contour qu'une fois :
+/// l'éventail couvre le cœur sans recouvrement.
 /// 
 /// # Arguments
-/// * `base_angle` - Angle central du triangle en radians
-/// * `inner_radius` - Rayon du cercle intérieur
-/// * `triangle_side` - Longueur d'un côté du triangle
-pub fn calculate_interior_triangle_points(
-    base_angle: f32,
-    inner_radius: f32,
-    triangle_side: f32,
-) -> (Vec2, Vec2, Vec2) {
-    let half_side = triangle_side / 2.0;
-    let height = triangle_side * (3.0_f32.sqrt() / 2.0);
-    
-    // === POINTS DE BASE SUR LE CERCLE INTÉRIEUR ===
-    let angle1 = base_angle - (half_side / inner_radius);
-    let angle2 = base_angle + (half_side / inner_radius);
+/// * `size` - Taille du cœur (plus grand côté de la boîte englobante)
+/// * `segments` - Nombre de points du contour (minimum 3)
+/// 
+/// # Détails techniques
+/// - Vertices : segments + 1 (centre + contour)
+/// - Triangles : segments
+pub fn create_heart_mesh(size: f32, segments: usize) -> Mesh {
+    let outline = heart_outline(size, segments);
+    let count = outline.len();
     
-    let p1 = Vec2::new(
-        inner_radius * angle1.cos(),
-        inner_radius * angle1.sin(),
-    );
-    let p2 = Vec2::new(
-        inner_radius * angle2.cos(),
-        inner_radius * angle2.sin(),
-    );
+    // === VERTICES : CENTRE PUIS CONTOUR ===
+    let mut positions = vec![[0.0, 0.0, 0.0]];
+    positions.extend(outline.iter().map(|p| [p.x, p.y, 0.0]));
     
-    // === SOMMET VERS L'INTÉRIEUR ===
-    // Distance : rayon - hauteur (pour pointer vers le centre)
-    let p3 = Vec2::new(
-        (inner_radius - height) * base_angle.cos(),
-        (inner_radius - height) * base_angle.sin(),
-    );
+    // === TRIANGLES EN ÉVENTAIL ===
+    // Contour horaire : (centre, suivant, actuel) garde l'ordre antihoraire
+    let mut indices = Vec::with_capacity(count * 3);
+    for i in 0..count {
+        let current = (i + 1) as u32;
+        let next = ((i + 1) % count + 1) as u32;
+        indices.extend_from_slice(&[0, next, current]);
+    }
     
-    (p1, p2, p3)
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
 }
 
-/// Calcule le centroïde (centre de gravité) d'un triangle
+/// Crée un mesh de cercle plein avec un dégradé radial (couleurs par vertex)
 /// 
-/// Le centroïde est le point d'intersection des médianes du triangle.
-/// Pour un triangle, c'est simplement la moyenne des coordonnées
-/// de ses trois sommets.
+/// Même géométrie que `create_filled_circle_mesh`, mais chaque vertex
+/// reçoit une couleur via `Mesh::ATTRIBUTE_COLOR` :
+/// - le vertex central prend `center_color`
+/// - les vertices du contour prennent `rim_color`
 /// 
-/// # Formule
-/// Centroïde = ((x1+x2+x3)/3, (y1+y2+y3)/3)
+/// Le GPU interpole ensuite les couleurs entre le centre et le bord,
+/// ce qui produit un dégradé radial sans texture.
 /// 
 /// # Arguments
-/// * `p1`, `p2`, `p3` - Les trois sommets du triangle
+/// * `radius` - Rayon du cercle
+/// * `segments` - Nombre de segments du contour
+/// * `center_color` - Couleur au centre du disque
+/// * `rim_color` - Couleur sur le contour
 /// 
-/// # Propriété
-/// Le centroïde est équidistant des trois côtés du triangle
-pub fn calculate_triangle_centroid(p1: Vec2, p2: Vec2, p3: Vec2) -> Vec2 {
-    Vec2::new(
-        (p1.x + p2.x + p3.x) / 3.0,
-        (p1.y + p2.y + p3.y) / 3.0,
-    )
-}
+/// # Utilisation
+/// Le `ColorMaterial` multiplie sa couleur par celle des vertices :
+/// utiliser un matériau blanc (`Color::WHITE`) pour conserver le dégradé tel quel.
+pub fn create_filled_circle_mesh_with_colors(
+    radius: f32,
+    segments: usize,
+    center_color: Color,
+    rim_color: Color,
+) -> Mesh {
+    // Nombre de vertices lu sur le mesh : `segments` a pu être relevé au minimum
+    let mesh = create_filled_circle_mesh(radius, segments);
+    let rim_vertex_count = mesh.count_vertices() - 1;
 
-// ═══════════════════════════════════════════════════════════════════════════
-//                    SECTION 4 : DÉFINITION DU LOGO "R"
-// ═══════════════════════════════════════════════════════════════════════════
+    // === COULEURS PAR VERTEX ===
+    // Même ordre que les positions : centre d'abord, puis le contour
+    let mut colors = vec![LinearRgba::from(center_color).to_f32_array()];
+    colors.extend(std::iter::repeat_n(
+        LinearRgba::from(rim_color).to_f32_array(),
+        rim_vertex_count,
+    ));
 
-/// Structure représentant une partie du logo "R"
+    mesh.with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+}
+
+/// Crée un mesh d'anneau avec un dégradé entre le bord intérieur et extérieur
 /// 
-/// Chaque partie du logo est définie par :
-/// - Un nom descriptif pour l'identification
-/// - Une liste de points formant le polygone
-/// - Un ordre de profondeur Z pour le rendu en couches
-#[derive(Clone)]
-pub struct RPartDefinition {
-    /// Nom descriptif de la partie (pour debug et logs)
-    pub name: &'static str,
-    
-    /// Liste ordonnée des sommets du polygone
-    /// Les points doivent former un contour dans l'ordre
-    pub points: Vec<Vec2>,
-    
-    /// Ordre de rendu sur l'axe Z (plus élevé = devant)
-    /// Recommandation : utiliser des valeurs entre 0.40 et 0.50
-    /// pour être devant les triangles intérieurs (Z=0.3)
-    pub z_order: f32,
+/// Variante de `create_circle_mesh` qui ajoute `Mesh::ATTRIBUTE_COLOR` :
+/// les N premiers vertices (cercle extérieur) reçoivent `outer_color`,
+/// les N suivants (cercle intérieur) reçoivent `inner_color`.
+/// 
+/// # Arguments
+/// * `outer_radius` - Rayon du cercle extérieur
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `segments` - Nombre de segments (qualité du cercle)
+/// * `inner_color` - Couleur du bord intérieur
+/// * `outer_color` - Couleur du bord extérieur
+pub fn create_circle_mesh_with_colors(
+    outer_radius: f32,
+    inner_radius: f32,
+    segments: usize,
+    inner_color: Color,
+    outer_color: Color,
+) -> Mesh {
+    // Même ordre que create_circle_mesh : extérieur puis intérieur
+    let mut colors = vec![LinearRgba::from(outer_color).to_f32_array(); segments];
+    colors.extend(std::iter::repeat_n(
+        LinearRgba::from(inner_color).to_f32_array(),
+        segments,
+    ));
+
+    create_circle_mesh(outer_radius, inner_radius, segments)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
 }
 
-/// Retourne toutes les parties composant le logo "R"
+/// Crée un mesh d'anneau avec des coordonnées de texture radiales
 /// 
-/// Le logo "R" est décomposé en 8 parties géométriques distinctes :
-/// 1. Haut : barre horizontale supérieure
-/// 2. Gauche : barre verticale principale
-/// 3. Arrondi : courbe de la partie droite supérieure
-/// 4. Centre : petite barre de séparation
-/// 5. Pied gauche : base élargie à gauche
-/// 6. Milieu : zone de connexion centrale
-/// 7. Jambe droite : diagonale partant vers la droite
-/// 8. Pied droit : base élargie à droite
+/// Les UV suivent la forme de l'anneau, pour qu'une texture à rayures
+/// s'enroule proprement autour du cercle :
+/// - U va de 0 à 1 en faisant le tour (angle 0 → 2π)
+/// - V va de 0 (bord intérieur) à 1 (bord extérieur)
 /// 
-/// # Organisation
-/// Chaque partie a un z_order incrémental (0.40 à 0.47) pour
-/// s'assurer qu'elles se superposent correctement sans conflit visuel.
+/// # Raccord
+/// À l'angle 0, le dernier segment devrait relier U = 1 au premier vertex
+/// (U = 0) : le GPU interpolerait alors toute la texture sur ce segment.
+/// Les vertices du raccord sont donc dupliqués : le contour a N + 1
+/// vertices par cercle, le premier avec U = 0 et le dernier, à la même
+/// position, avec U = 1.
 /// 
-/// # Coordonnées
-/// Les coordonnées sont définies dans un système où :
-/// - (0, 0) est au centre de l'écran
-/// - X positif va vers la droite
-/// - Y positif va vers le haut
+/// Mêmes triangles antihoraires que `create_circle_mesh`.
 /// 
-/// # Modification
-/// Pour ajuster le logo :
-/// 1. Modifier les Vec2::new(x, y) de chaque partie
-/// 2. Pour ajouter une partie : ajouter un nouveau RPartDefinition
-/// 3. Pour supprimer une partie : commenter ou retirer le bloc
-pub fn get_all_r_parts() -> Vec<RPartDefinition> {
-    vec![
-        // === PARTIE 1 : BARRE HORIZONTALE SUPÉRIEURE ===
+/// # Arguments
+/// * `outer_radius` - Rayon du cercle extérieur
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `segments` - Nombre de segments (qualité du cercle)
+/// 
+/// # Détails techniques
+/// Pour N segments : 2(N + 1) vertices (N + 1 extérieurs puis N + 1
+/// intérieurs), 2N triangles.
+pub fn create_circle_mesh_with_radial_uvs(outer_radius: f32, inner_radius: f32, segments: usize) -> Mesh {
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+    
+    // === VERTICES : EXTÉRIEUR (V = 1) PUIS INTÉRIEUR (V = 0) ===
+    // i = segments est le doublon de i = 0, avec U = 1
+    for (radius, v) in [(outer_radius, 1.0), (inner_radius, 0.0)] {
+        for i in 0..=segments {
+            let u = i as f32 / segments as f32;
+            let angle = 2.0 * PI * u;
+            positions.push([radius * angle.cos(), radius * angle.sin(), 0.0]);
+            uvs.push([u, v]);
+        }
+    }
+    
+    // === TRIANGLES ===
+    // Pas de retour au début : le segment i relie les colonnes i et i + 1
+    let inner_start = (segments + 1) as u32;
+    for i in 0..segments as u32 {
+        let next = i + 1;
+        
+        // Extérieur_i, extérieur_next, intérieur_i
+        indices.extend([i, next, inner_start + i]);
+        
+        // Extérieur_next, intérieur_next, intérieur_i
+        indices.extend([next, inner_start + next, inner_start + i]);
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Crée un anneau dont la couleur parcourt tout le cercle chromatique
+/// 
+/// Variante continue des triangles extérieurs arc-en-ciel : chaque paire
+/// de vertices (extérieur et intérieur) reçoit la teinte de son angle,
+/// et le GPU interpole les couleurs entre deux segments.
+/// Équivaut à `create_oriented_rainbow_ring_mesh(.., 0.0, false)`.
+/// 
+/// # Arguments
+/// * `outer_radius` - Rayon du cercle extérieur
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `segments` - Nombre de segments (finesse du dégradé)
+pub fn create_rainbow_ring_mesh(outer_radius: f32, inner_radius: f32, segments: usize) -> Mesh {
+    create_oriented_rainbow_ring_mesh(outer_radius, inner_radius, segments, 0.0, false)
+}
+
+/// Crée un anneau arc-en-ciel avec teinte de départ et sens configurables
+/// 
+/// Le vertex d'angle θ (en degrés, 0° = axe X positif) reçoit la teinte
+/// `start_hue ± θ` : le dégradé suit les mêmes couleurs que les triangles
+/// extérieurs (`materials::rainbow_hue`), mais sans discontinuité.
+/// 
+/// # Arguments
+/// * `outer_radius` - Rayon du cercle extérieur
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `segments` - Nombre de segments (finesse du dégradé)
+/// * `start_hue` - Teinte à l'angle 0°, en degrés
+/// * `clockwise` - Si vrai, la teinte décroît avec l'angle
+/// 
+/// # Utilisation
+/// Comme pour tous les meshes à couleurs par vertex, utiliser un
+/// matériau blanc pour conserver les couleurs telles quelles.
+pub fn create_oriented_rainbow_ring_mesh(
+    outer_radius: f32,
+    inner_radius: f32,
+    segments: usize,
+    start_hue: f32,
+    clockwise: bool,
+) -> Mesh {
+    let direction = if clockwise { -1.0 } else { 1.0 };
+    
+    // === TEINTE DE CHAQUE ANGLE ===
+    // Saturation et valeur proches du HSL (0.8, 0.6) des triangles
+    let ring_colors: Vec<[f32; 4]> = (0..segments)
+        .map(|i| {
+            let angle_degrees = 360.0 * i as f32 / segments as f32;
+            let color = crate::materials::color_from_hsv(start_hue + direction * angle_degrees, 0.7, 0.92);
+            LinearRgba::from(color).to_f32_array()
+        })
+        .collect();
+    
+    // Même ordre que create_circle_mesh : extérieur puis intérieur
+    let mut colors = ring_colors.clone();
+    colors.extend(ring_colors);
+    
+    create_circle_mesh(outer_radius, inner_radius, segments)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+}
+
+/// Nombre maximal de lignes de la grille de chaque côté de l'axe central
+pub const MAX_GRID_LINES_PER_SIDE: i32 = 10_000;
+
+/// Crée un mesh de grille (lignes horizontales et verticales)
+/// 
+/// Le mesh utilise la topologie `LineList` : chaque paire de vertices
+/// forme un segment de 1 pixel d'épaisseur, sans remplissage.
+/// Une ligne verticale et une ligne horizontale passent par l'origine,
+/// ce qui permet de repérer facilement le point (0, 0).
+/// 
+/// # Arguments
+/// * `width` - Largeur totale couverte par la grille
+/// * `height` - Hauteur totale couverte par la grille
+/// * `cell_size` - Écart entre deux lignes
+/// 
+/// # Détails techniques
+/// - Colonnes : 2 × ⌊width / (2 × cell_size)⌋ + 1 lignes verticales
+/// - Rangées : 2 × ⌊height / (2 × cell_size)⌋ + 1 lignes horizontales
+/// - 2 vertices par ligne
+/// 
+/// # Cas particuliers
+/// - `cell_size` nul, négatif ou non fini : mesh vide (le nombre de lignes
+///   serait infini)
+/// - Au plus `MAX_GRID_LINES_PER_SIDE` lignes de chaque côté de l'axe
+///   central, pour qu'une case minuscule n'épuise pas la mémoire
+pub fn create_grid_mesh(width: f32, height: f32, cell_size: f32) -> Mesh {
+    let half_width = width / 2.0;
+    let half_height = height / 2.0;
+    
+    let mut positions = Vec::new();
+    
+    // Nombre de lignes de chaque côté de l'axe central (-1 : aucune ligne)
+    let half_lines = |half_extent: f32| {
+        if cell_size > 0.0 && cell_size.is_finite() {
+            ((half_extent / cell_size).floor() as i32).min(MAX_GRID_LINES_PER_SIDE)
+        } else {
+            -1
+        }
+    };
+    let half_columns = half_lines(half_width);
+    let half_rows = half_lines(half_height);
+    
+    // === LIGNES VERTICALES ===
+    for k in -half_columns..=half_columns {
+        let x = k as f32 * cell_size;
+        positions.push([x, -half_height, 0.0]);
+        positions.push([x, half_height, 0.0]);
+    }
+    
+    // === LIGNES HORIZONTALES ===
+    for k in -half_rows..=half_rows {
+        let y = k as f32 * cell_size;
+        positions.push([-half_width, y, 0.0]);
+        positions.push([half_width, y, 0.0]);
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::LineList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+}
+
+/// Orientation des sommets d'un triangle créé par `create_triangle_with_winding`
+/// 
+/// # Convention du projet
+/// Les meshes du logo sont en sens antihoraire (Y vers le haut), la face
+/// avant de wgpu (`FrontFace::Ccw`). Le pipeline 2D de Bevy ne supprime
+/// aucune face (`cull_mode: None`) : l'orientation ne change pas l'affichage
+/// avec `ColorMaterial`, mais compte pour un matériau personnalisé qui
+/// active le culling et pour les calculs d'aire signée ou de normales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Winding {
+    /// Ordre des points conservé tel que fourni
+    #[default]
+    Preserve,
+    
+    /// Ordre inversé si nécessaire pour obtenir le sens antihoraire
+    AutoCcw,
+}
+
+/// Remet un contour dans le sens antihoraire (Y vers le haut)
+/// 
+/// Inverse l'ordre des points si leur aire signée (`polygon_signed_area`)
+/// est négative. Un contour dégénéré (aire nulle) est laissé tel quel.
+pub fn ensure_ccw(points: &mut [Vec2]) {
+    if polygon_signed_area(points) < 0.0 {
+        points.reverse();
+    }
+}
+
+/// Crée un triangle à partir de trois points 2D
+/// 
+/// Fonction simple qui convertit 3 points Vec2 en un mesh triangulaire.
+/// 
+/// # Arguments
+/// * `p1`, `p2`, `p3` - Les trois sommets du triangle
+/// 
+/// # Note
+/// L'ordre des points est conservé (voir `Winding::Preserve`).
+/// Pour imposer le sens antihoraire, utiliser `create_triangle_with_winding`.
+pub fn create_triangle_from_points(p1: Vec2, p2: Vec2, p3: Vec2) -> Mesh {
+    create_triangle_with_winding(p1, p2, p3, Winding::Preserve)
+}
+
+/// Crée un triangle à partir de trois points 2D, avec contrôle de l'orientation
+/// 
+/// # Arguments
+/// * `p1`, `p2`, `p3` - Les trois sommets du triangle
+/// * `winding` - `AutoCcw` pour toujours obtenir le sens antihoraire,
+///   quel que soit l'ordre des points fournis
+pub fn create_triangle_with_winding(p1: Vec2, p2: Vec2, p3: Vec2, winding: Winding) -> Mesh {
+    let mut points = [p1, p2, p3];
+    if winding == Winding::AutoCcw {
+        ensure_ccw(&mut points);
+    }
+    let [p1, p2, p3] = points;
+    
+    // Conversion des points 2D en positions 3D (Z=0)
+    let positions = vec![
+        [p1.x, p1.y, 0.0],
+        [p2.x, p2.y, 0.0],
+        [p3.x, p3.y, 0.0],
+    ];
+
+    // Indices des 3 sommets dans l'ordre
+    let indices = vec![0u32, 1, 2];
+
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Crée un polygone complexe à partir d'une liste de points
+/// 
+/// Triangulation par découpage d'oreilles (`triangulate_polygon`) :
+/// correcte pour les polygones convexes comme concaves. Pour un polygone
+/// convexe, elle donne l'éventail habituel (triangles 0, i, i+1).
+/// 
+/// # Arguments
+/// * `points` - Slice de Vec2 représentant les sommets du polygone
+/// 
+/// # Panic
+/// Panic si moins de 3 points sont fournis
+/// (voir `try_create_polygon_from_points` pour une version sans panic)
+pub fn create_polygon_from_points(points: &[Vec2]) -> Mesh {
+    try_create_polygon_from_points(points)
+        .unwrap_or_else(|_| panic!("Un polygone doit avoir au moins 3 points"))
+}
+
+/// Triangule un polygone simple par découpage d'oreilles (ear clipping)
+/// 
+/// Une oreille est un sommet convexe (même sens que le contour) dont le
+/// triangle formé avec ses deux voisins ne contient aucun autre sommet.
+/// Elle est retirée avec son triangle, jusqu'à ce qu'il ne reste que 3
+/// sommets. La recherche repart toujours du deuxième sommet restant :
+/// un polygone convexe donne l'éventail 0, i, i+1.
+/// 
+/// # Retour
+/// Indices des N-2 triangles, dans le sens du contour. Vide pour moins
+/// de 3 points. Un contour d'aire nulle ou qui se croise (sans oreille
+/// valide) est terminé en éventail, comme avant le découpage.
+pub fn triangulate_polygon(points: &[Vec2]) -> Vec<u32> {
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    let orientation = polygon_signed_area(points).signum();
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut indices = Vec::with_capacity(3 * (points.len() - 2));
+    
+    // === RETRAIT DES OREILLES ===
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (1..=count).map(|k| k % count).find(|&k| {
+            let (prev, cur, next) = (remaining[(k + count - 1) % count], remaining[k], remaining[(k + 1) % count]);
+            let (a, b, c) = (points[prev], points[cur], points[next]);
+            
+            // Sommet convexe, dans le sens du contour
+            if orientation == 0.0 || (b - a).perp_dot(c - b) * orientation <= 0.0 {
+                return false;
+            }
+            // Aucun autre sommet restant dans le triangle (bords compris)
+            remaining
+                .iter()
+                .filter(|&&other| other != prev && other != cur && other != next)
+                .all(|&other| !point_in_triangle(points[other], a, b, c))
+        });
+        
+        let Some(k) = ear else {
+            // Contour dégénéré : éventail sur les sommets restants
+            for i in 1..count - 1 {
+                indices.extend([remaining[0], remaining[i], remaining[i + 1]].map(|index| index as u32));
+            }
+            return indices;
+        };
+        indices.extend([remaining[(k + count - 1) % count], remaining[k], remaining[(k + 1) % count]].map(|index| index as u32));
+        remaining.remove(k);
+    }
+    
+    indices.extend(remaining.iter().map(|&index| index as u32));
+    indices
+}
+
+/// Vrai si `point` est dans le triangle (a, b, c) ou sur un de ses bords
+/// 
+/// Le point est du même côté des trois arêtes, quel que soit le sens
+/// du triangle.
+fn point_in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    let d1 = (b - a).perp_dot(point - a);
+    let d2 = (c - b).perp_dot(point - b);
+    let d3 = (a - c).perp_dot(point - c);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+/// Crée un polygone dont le contour peut être fermé explicitement ou non
+/// 
+/// Les contours importés répètent parfois le premier point à la fin. Ce
+/// point de fermeture (dernier point à moins de `POLYGON_CLOSE_EPSILON`
+/// du premier) est retiré avant la triangulation : sans cela, un
+/// triangle plat apparaîtrait à la jointure. Les deux conventions
+/// donnent donc le même mesh.
+/// 
+/// # Panic
+/// Panic s'il reste moins de 3 points (voir `create_polygon_from_points`)
+pub fn create_polygon_auto_close(points: &[Vec2]) -> Mesh {
+    let points = match points {
+        [first, .., last] if first.distance(*last) <= config::POLYGON_CLOSE_EPSILON => &points[..points.len() - 1],
+        _ => points,
+    };
+    
+    create_polygon_from_points(points)
+}
+
+/// Crée un polygone, ou une erreur si le contour est trop court
+/// 
+/// Même triangulation que `create_polygon_from_points`.
+/// 
+/// # Erreurs
+/// `LogoError::TooFewPoints` si moins de 3 points sont fournis
+pub fn try_create_polygon_from_points(points: &[Vec2]) -> Result<Mesh, LogoError> {
+    if points.len() < 3 {
+        return Err(LogoError::TooFewPoints { count: points.len(), min: 3 });
+    }
+    
+    // === CONVERSION DES POINTS EN POSITIONS 3D ===
+    let positions: Vec<[f32; 3]> = points
+        .iter()
+        .map(|p| [p.x, p.y, 0.0])
+        .collect();
+    
+    // === TRIANGULATION PAR OREILLES ===
+    // Pour N points : N-2 triangles
+    let indices = triangulate_polygon(points);
+    
+    Ok(Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices)))
+}
+
+/// Exporte un mesh au format Wavefront OBJ
+/// 
+/// Une ligne `v x y z` par vertex puis une ligne `f a b c` par triangle
+/// (indices OBJ numérotés à partir de 1). Un mesh sans indices est lu
+/// comme une liste de triangles consécutifs.
+/// 
+/// # Erreurs
+/// `LogoError::TooFewPoints` si le mesh a moins de 3 positions
+/// (ou aucun attribut de position)
+pub fn to_obj(mesh: &Mesh) -> Result<String, LogoError> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions.as_slice(),
+        _ => &[],
+    };
+    if positions.len() < 3 {
+        return Err(LogoError::TooFewPoints { count: positions.len(), min: 3 });
+    }
+    
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    
+    // L'écriture dans une String ne peut pas échouer
+    let mut obj = String::new();
+    for [x, y, z] in positions {
+        let _ = writeln!(obj, "v {} {} {}", x, y, z);
+    }
+    for triangle in indices.chunks_exact(3) {
+        let _ = writeln!(obj, "f {} {} {}", triangle[0] + 1, triangle[1] + 1, triangle[2] + 1);
+    }
+    Ok(obj)
+}
+
+/// Fusionne plusieurs meshes en un seul
+/// 
+/// Chaque mesh est d'abord déplacé par son `Transform` (positions
+/// transformées), puis ses indices sont décalés du nombre de vertices
+/// déjà ajoutés. Les triangles sont conservés dans l'ordre des meshes :
+/// à profondeur égale, un mesh suivant est dessiné par-dessus le précédent.
+/// 
+/// # Arguments
+/// * `meshes` - Meshes à fusionner, avec leur placement dans le mesh final
+/// 
+/// # Limitations
+/// Seules les positions sont conservées (pas de normales, UV ni couleurs
+/// de vertices) : le mesh fusionné s'utilise avec un matériau unique.
+/// Un mesh sans indices est lu comme une liste de triangles consécutifs.
+pub fn merge_meshes(meshes: &[(Mesh, Transform)]) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    
+    for (mesh, transform) in meshes {
+        let Some(VertexAttributeValues::Float32x3(mesh_positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            continue;
+        };
+        let offset = positions.len() as u32;
+        
+        // === POSITIONS DÉPLACÉES ===
+        positions.extend(
+            mesh_positions
+                .iter()
+                .map(|&position| transform.transform_point(Vec3::from(position)).to_array()),
+        );
+        
+        // === INDICES DÉCALÉS ===
+        match mesh.indices() {
+            Some(mesh_indices) => indices.extend(mesh_indices.iter().map(|index| offset + index as u32)),
+            None => indices.extend((0..mesh_positions.len() as u32).map(|index| offset + index)),
+        }
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Remplit un polygone qui se recoupe, selon la règle du nombre d'enroulement non nul
+/// 
+/// Un point est rempli si le contour tourne au moins une fois autour de
+/// lui (règle "nonzero" du SVG) : le centre d'un pentagramme, entouré deux
+/// fois, est donc plein. L'éventail de `create_polygon_from_points`
+/// remplit mal ces contours.
+/// 
+/// # Méthode (balayage par bandes horizontales)
+/// 1. Découpage du plan en bandes aux ordonnées des sommets et des
+///    croisements d'arêtes : dans une bande, les arêtes ne se croisent pas
+/// 2. Dans chaque bande, arêtes triées par abscisse au milieu de la bande,
+///    parcourues de gauche à droite avec un compteur d'enroulement
+///    (+1 arête montante, -1 descendante)
+/// 3. Chaque intervalle où le compteur est non nul devient un trapèze
+///    (2 triangles, sens antihoraire)
+/// 
+/// # Performances
+/// Recherche des croisements en O(n²), puis O(n log n) par bande : bien
+/// plus coûteux que l'éventail (O(n)), et davantage de triangles. À
+/// réserver aux contours qui se recoupent ; les polygones convexes
+/// gardent `create_polygon_from_points`.
+/// 
+/// # Arguments
+/// * `points` - Sommets du contour (fermé implicitement)
+/// 
+/// # Cas dégénérés
+/// Moins de 3 points, ou contour d'aire nulle : mesh sans triangle.
+pub fn create_polygon_nonzero_fill(points: &[Vec2]) -> Mesh {
+    // === ARÊTES NON HORIZONTALES ===
+    // Les arêtes horizontales ne changent pas l'enroulement
+    let edges: Vec<(Vec2, Vec2)> = (0..points.len())
+        .map(|i| (points[i], points[(i + 1) % points.len()]))
+        .filter(|(a, b)| a.y != b.y)
+        .collect();
+    
+    // === ORDONNÉES DES BANDES ===
+    let mut ys: Vec<f32> = points.iter().map(|p| p.y).collect();
+    for i in 0..edges.len() {
+        for j in (i + 1)..edges.len() {
+            if let Some(crossing) = segment_crossing_point(edges[i].0, edges[i].1, edges[j].0, edges[j].1) {
+                ys.push(crossing.y);
+            }
+        }
+    }
+    ys.sort_by(f32::total_cmp);
+    ys.dedup_by(|a, b| (*a - *b).abs() < 1e-5);
+    
+    // Abscisse d'une arête à l'ordonnée y
+    let x_at = |(a, b): (Vec2, Vec2), y: f32| a.x + (b.x - a.x) * (y - a.y) / (b.y - a.y);
+    
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    
+    for slab in ys.windows(2) {
+        let (y0, y1) = (slab[0], slab[1]);
+        let y_mid = (y0 + y1) / 2.0;
+        
+        // === ARÊTES TRAVERSANT LA BANDE, DE GAUCHE À DROITE ===
+        let mut crossing: Vec<((Vec2, Vec2), i32)> = edges
+            .iter()
+            .filter(|(a, b)| a.y.min(b.y) < y_mid && a.y.max(b.y) > y_mid)
+            .map(|&(a, b)| ((a, b), if b.y > a.y { 1 } else { -1 }))
+            .collect();
+        crossing.sort_by(|e, f| x_at(e.0, y_mid).total_cmp(&x_at(f.0, y_mid)));
+        
+        // === INTERVALLES D'ENROULEMENT NON NUL ===
+        let mut winding = 0;
+        let mut span_start = None;
+        for (edge, direction) in crossing {
+            let was_inside = winding != 0;
+            winding += direction;
+            match (was_inside, winding != 0) {
+                (false, true) => span_start = Some(edge),
+                (true, false) => {
+                    let Some(left) = span_start.take() else {
+                        continue;
+                    };
+                    // Trapèze : bas gauche, bas droit, haut droit, haut gauche
+                    let base = positions.len() as u32;
+                    positions.extend([
+                        [x_at(left, y0), y0, 0.0],
+                        [x_at(edge, y0), y0, 0.0],
+                        [x_at(edge, y1), y1, 0.0],
+                        [x_at(left, y1), y1, 0.0],
+                    ]);
+                    indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+                }
+                _ => {}
+            }
+        }
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Point de croisement strict des segments [a, b] et [c, d]
+/// 
+/// `None` si les segments sont parallèles ou ne se coupent qu'en dehors
+/// de leurs intérieurs (extrémités communes comprises).
+fn segment_crossing_point(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> Option<Vec2> {
+    let r = b - a;
+    let s = d - c;
+    let denominator = r.perp_dot(s);
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+    
+    // a + t·r = c + u·s
+    let t = (c - a).perp_dot(s) / denominator;
+    let u = (c - a).perp_dot(r) / denominator;
+    (t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0).then(|| a + r * t)
+}
+
+/// Crée le mesh d'un segment épais (rectangle orienté)
+/// 
+/// Le rectangle va de `start` à `end`, avec une largeur `thickness`
+/// centrée sur le segment. Les extrémités sont coupées droit.
+/// 
+/// # Arguments
+/// * `start` - Début du segment
+/// * `end` - Fin du segment
+/// * `thickness` - Épaisseur du trait
+/// 
+/// # Détails techniques
+/// 4 vertices, 2 triangles (via `create_polygon_from_points`)
+pub fn create_thick_line_mesh(start: Vec2, end: Vec2, thickness: f32) -> Mesh {
+    // Normale au segment, de longueur égale à la demi-épaisseur
+    let normal = (end - start).normalize_or(Vec2::X).perp() * (thickness / 2.0);
+    
+    create_polygon_from_points(&[
+        start + normal,
+        end + normal,
+        end - normal,
+        start - normal,
+    ])
+}
+
+/// Crée le mesh d'un trait épais en pointillés
+/// 
+/// Le segment est découpé en tirets (un quadrilatère chacun) séparés
+/// par des espaces vides. Le trait commence toujours par un tiret ;
+/// le dernier tiret est raccourci pour s'arrêter exactement sur `end`.
+/// 
+/// # Arguments
+/// * `start` - Point de départ du trait
+/// * `end` - Point d'arrivée du trait
+/// * `thickness` - Épaisseur du trait
+/// * `dash_length` - Longueur d'un tiret
+/// * `gap_length` - Longueur d'un espace entre deux tirets
+/// 
+/// # Cas particuliers
+/// Si `dash_length` est nul ou négatif, ou si `gap_length` est nul ou
+/// négatif, le trait est plein (`create_thick_line_mesh`).
+pub fn create_dashed_line_mesh(
+    start: Vec2,
+    end: Vec2,
+    thickness: f32,
+    dash_length: f32,
+    gap_length: f32,
+) -> Mesh {
+    if dash_length <= 0.0 || gap_length <= 0.0 {
+        return create_thick_line_mesh(start, end, thickness);
+    }
+    
+    let length = start.distance(end);
+    let direction = (end - start).normalize_or(Vec2::X);
+    let period = dash_length + gap_length;
+    
+    // === PREMIER TIRET ===
+    let mut dashes = create_thick_line_mesh(
+        start,
+        start + direction * dash_length.min(length),
+        thickness
+    );
+    
+    // === TIRETS SUIVANTS ===
+    // Le dernier tiret est tronqué à la fin du segment
+    let mut dash_start = period;
+    while length - dash_start > f32::EPSILON {
+        let dash_end = (dash_start + dash_length).min(length);
+        let dash = create_thick_line_mesh(
+            start + direction * dash_start,
+            start + direction * dash_end,
+            thickness
+        );
+        dashes
+            .merge(&dash)
+            .expect("Tous les tirets ont les mêmes attributs");
+        dash_start += period;
+    }
+    
+    dashes
+}
+
+/// Crée le mesh d'un contour fermé en pointillés, avec décalage des tirets
+/// 
+/// Le motif (tiret puis espace) est continu tout autour du contour : il
+/// ne recommence pas à chaque sommet. Augmenter `offset` au fil du temps
+/// fait défiler les tirets le long du contour ("fourmis en marche").
+/// 
+/// # Arguments
+/// * `points` - Sommets du contour (fermé implicitement)
+/// * `thickness` - Épaisseur du trait
+/// * `dash_length` - Longueur d'un tiret
+/// * `gap_length` - Longueur d'un espace entre deux tirets
+/// * `offset` - Avancée du motif le long du contour (périodique)
+/// 
+/// # Détails techniques
+/// Un quadrilatère (4 vertices, 2 triangles) par morceau de tiret :
+/// un tiret à cheval sur un sommet est coupé en deux.
+/// 
+/// # Cas particuliers
+/// Si `dash_length` ou `gap_length` est nul ou négatif, le contour est
+/// plein (`create_polyline_mesh`).
+pub fn create_dashed_outline_mesh(
+    points: &[Vec2],
+    thickness: f32,
+    dash_length: f32,
+    gap_length: f32,
+    offset: f32,
+) -> Mesh {
+    if dash_length <= 0.0 || gap_length <= 0.0 {
+        return create_polyline_mesh(points, thickness, true);
+    }
+    
+    let period = dash_length + gap_length;
+    let offset = offset.rem_euclid(period);
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    
+    // Distance parcourue le long du contour au début de l'arête courante
+    let mut travelled = 0.0;
+    
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let length = a.distance(b);
+        let direction = (b - a).normalize_or(Vec2::X);
+        let normal = direction.perp() * (thickness / 2.0);
+        
+        // === TIRETS CHEVAUCHANT L'ARÊTE ===
+        // Le tiret k couvre [k × période + offset, ... + dash_length]
+        let mut k = ((travelled - offset) / period).floor() - 1.0;
+        while k * period + offset < travelled + length {
+            let dash_start = (k * period + offset).max(travelled) - travelled;
+            let dash_end = (k * period + offset + dash_length).min(travelled + length) - travelled;
+            
+            if dash_end - dash_start > f32::EPSILON {
+                let (from, to) = (a + direction * dash_start, a + direction * dash_end);
+                let base = positions.len() as u32;
+                let corners = [from + normal, to + normal, to - normal, from - normal];
+                positions.extend(corners.map(|p| [p.x, p.y, 0.0]));
+                indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+            }
+            k += 1.0;
+        }
+        
+        travelled += length;
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Crée le mesh d'une flèche : un segment épais terminé par une pointe
+/// 
+/// # Arguments
+/// * `start` - Origine de la flèche
+/// * `end` - Extrémité de la pointe
+/// * `thickness` - Épaisseur du corps de la flèche
+/// * `head_length` - Longueur de la pointe (le long de la flèche)
+/// * `head_width` - Largeur de la base de la pointe
+/// 
+/// # Flèches courtes
+/// Si la flèche est plus courte que `head_length`, la pointe est
+/// réduite à la longueur totale et le corps disparaît : la pointe
+/// arrive donc toujours exactement sur `end`.
+pub fn create_arrow_mesh(
+    start: Vec2,
+    end: Vec2,
+    thickness: f32,
+    head_length: f32,
+    head_width: f32,
+) -> Mesh {
+    let length = start.distance(end);
+    let direction = (end - start).normalize_or(Vec2::X);
+    let normal = direction.perp();
+    
+    // === DIMENSIONS DE LA POINTE ===
+    // La pointe ne peut pas dépasser la longueur de la flèche
+    let head_length = head_length.min(length);
+    let head_base = end - direction * head_length;
+    
+    // === POINTE ===
+    let mut arrow = create_triangle_from_points(
+        head_base + normal * (head_width / 2.0),
+        end,
+        head_base - normal * (head_width / 2.0),
+    );
+    
+    // === CORPS (s'il reste de la place) ===
+    if length - head_length > f32::EPSILON {
+        let shaft = create_thick_line_mesh(start, head_base, thickness);
+        arrow
+            .merge(&shaft)
+            .expect("Le corps et la pointe ont les mêmes attributs");
+    }
+    
+    arrow
+}
+
+/// Crée le mesh d'un trait épais suivant une ligne brisée (polyligne)
+/// 
+/// Chaque point de la polyligne est dédoublé en deux vertices, de part
+/// et d'autre du trait. Deux points consécutifs forment un quadrilatère
+/// (2 triangles). Aux angles, les deux vertices sont placés sur la
+/// bissectrice (jointure en onglet, "miter") pour que le trait garde
+/// une épaisseur constante.
+/// 
+/// # Arguments
+/// * `points` - Points de la polyligne, dans l'ordre
+/// * `thickness` - Épaisseur du trait (centré sur la ligne)
+/// * `closed` - Si vrai, le dernier point est relié au premier
+/// 
+/// # Détails techniques
+/// - Les points consécutifs identiques sont ignorés (segment de longueur nulle)
+/// - L'onglet est limité à 4 × la demi-épaisseur pour les angles très aigus
+/// 
+/// # Panic
+/// Panic si moins de 2 points distincts sont fournis
+pub fn create_polyline_mesh(points: &[Vec2], thickness: f32, closed: bool) -> Mesh {
+    // === SUPPRESSION DES POINTS EN DOUBLE ===
+    let path = remove_duplicate_points(points, closed);
+    if path.len() < 2 {
+        panic!("Une polyligne doit avoir au moins 2 points distincts");
+    }
+    
+    let count = path.len();
+    let half_thickness = thickness / 2.0;
+    let mut positions = Vec::with_capacity(count * 2);
+    
+    // === VERTICES DE PART ET D'AUTRE DE CHAQUE POINT ===
+    for i in 0..count {
+        let current = path[i];
+        
+        // Voisins (inexistants aux extrémités d'une polyligne ouverte)
+        let previous = if i > 0 { Some(path[i - 1]) } else if closed { Some(path[count - 1]) } else { None };
+        let next = if i + 1 < count { Some(path[i + 1]) } else if closed { Some(path[0]) } else { None };
+        
+        // Normales des segments entrant et sortant
+        let normal_in = previous.map(|p| (current - p).normalize_or_zero().perp());
+        let normal_out = next.map(|n| (n - current).normalize_or_zero().perp());
+        let (normal_in, normal_out) = match (normal_in, normal_out) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None) => (a, a),
+            (None, Some(b)) => (b, b),
+            (None, None) => (Vec2::Y, Vec2::Y),
+        };
+        
+        // Bissectrice et longueur de l'onglet
+        let miter = (normal_in + normal_out).normalize_or(normal_in);
+        let miter_length = half_thickness / miter.dot(normal_in).max(0.25);
+        
+        positions.push((current + miter * miter_length).extend(0.0).to_array());
+        positions.push((current - miter * miter_length).extend(0.0).to_array());
+    }
+    
+    // === QUADRILATÈRES ENTRE POINTS CONSÉCUTIFS ===
+    let segment_count = if closed { count } else { count - 1 };
+    let mut indices = Vec::with_capacity(segment_count * 6);
+    for i in 0..segment_count {
+        let a = (2 * i) as u32;
+        let b = (2 * ((i + 1) % count)) as u32;
+        
+        // Deux triangles : (gauche_a, droite_a, gauche_b) et (gauche_b, droite_a, droite_b)
+        indices.extend_from_slice(&[a, a + 1, b, b, a + 1, b + 1]);
+    }
+    
+    Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+}
+
+/// Calcule la ligne centrale d'une spirale d'Archimède
+/// 
+/// Le rayon varie linéairement de `start_radius` à `end_radius` pendant
+/// que l'angle parcourt `turns` tours (sens antihoraire, départ sur l'axe X).
+/// 
+/// # Arguments
+/// * `turns` - Nombre de tours (peut être fractionnaire)
+/// * `start_radius` - Rayon au premier point
+/// * `end_radius` - Rayon au dernier point
+/// * `segments` - Nombre de segments (segments + 1 points, minimum 1)
+pub fn spiral_centerline(turns: f32, start_radius: f32, end_radius: f32, segments: usize) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    
+    (0..=segments)
+        .map(|i| {
+            let progress = i as f32 / segments as f32;
+            let angle = 2.0 * PI * turns * progress;
+            let radius = start_radius + (end_radius - start_radius) * progress;
+            Vec2::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Crée le mesh d'une spirale d'Archimède tracée d'un trait épais
+/// 
+/// La ligne centrale (`spiral_centerline`) est passée au traceur de
+/// polylignes `create_polyline_mesh` (trait ouvert).
+/// 
+/// # Arguments
+/// * `turns` - Nombre de tours
+/// * `start_radius` - Rayon au début de la spirale
+/// * `end_radius` - Rayon à la fin de la spirale
+/// * `thickness` - Épaisseur du trait
+/// * `segments` - Nombre de segments de la ligne centrale
+/// 
+/// # Cas particuliers
+/// Une spirale réduite à un point (rayons nuls, aucun segment) donne un
+/// mesh vide au lieu de faire paniquer `create_polyline_mesh`.
+pub fn create_spiral_mesh(
+    turns: f32,
+    start_radius: f32,
+    end_radius: f32,
+    thickness: f32,
+    segments: usize,
+) -> Mesh {
+    let centerline = spiral_centerline(turns, start_radius, end_radius, segments);
+    if remove_duplicate_points(&centerline, false).len() < 2 {
+        return Mesh::new(
+            bevy::render::render_resource::PrimitiveTopology::TriangleList,
+            bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+        .with_inserted_indices(bevy::render::mesh::Indices::U32(Vec::new()));
+    }
+    
+    create_polyline_mesh(&centerline, thickness, false)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//            SECTION 3 : CALCULS DE POSITIONS DES TRIANGLES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Calcule les coordonnées d'un triangle extérieur
+/// 
+/// Les triangles extérieurs sont positionnés de manière à ce qu'un
+/// de leurs côtés (la base) touche le cercle principal.
+/// 
+/// # Stratégie de positionnement
+/// 1. Calculer deux points sur le cercle (extrémités de la base)
+/// 2. Calculer le troisième point à l'extérieur du cercle
+/// 
+/// # Arguments
+/// * `base_angle` - Angle central du triangle en radians
+/// * `circle_radius` - Rayon du cercle de référence
+/// * `triangle_side` - Longueur d'un côté du triangle équilatéral
+/// 
+/// # Géométrie
+/// Pour un triangle équilatéral de côté L :
+/// - Hauteur : h = L × √3 / 2
+/// - Les deux points de base sont espacés angulairement de L/R radians
+///   où R est le rayon du cercle
+/// 
+/// # Orientation
+/// Base et sommet utilisent la même convention d'angle (0 = axe +X, sens
+/// antihoraire) : le sommet est sur la demi-droite issue du centre à
+/// `base_angle`, à la distance R + h > R. Il pointe donc toujours vers
+/// l'extérieur, quel que soit l'angle (même négatif ou au-delà de 2π).
+pub fn calculate_exterior_triangle_points(
+    base_angle: f32,
+    circle_radius: f32,
+    triangle_side: f32,
+) -> (Vec2, Vec2, Vec2) {
+    calculate_exterior_triangle_points_with_gap(
+        base_angle,
+        circle_radius,
+        triangle_side,
+        0.0,
+        config::EQUILATERAL_HEIGHT_RATIO,
+    )
+}
+
+/// Calcule les coordonnées d'un triangle extérieur dont la base est rétrécie
+/// 
+/// Identique à `calculate_exterior_triangle_points`, avec chaque extrémité
+/// de la base rapprochée du centre du triangle de `gap / 2` radians :
+/// deux triangles voisins sont alors séparés d'un angle `gap`.
+/// Un `gap` négatif élargit la base (chevauchement volontaire).
+/// Le sommet ne bouge pas.
+/// 
+/// La hauteur vaut `triangle_side × height_ratio` : avec
+/// `EQUILATERAL_HEIGHT_RATIO` le triangle est équilatéral, au-delà il est
+/// isocèle et plus pointu.
+/// 
+/// # Arguments
+/// * `base_angle` - Angle central du triangle en radians
+/// * `circle_radius` - Rayon du cercle de référence
+/// * `triangle_side` - Longueur de la base du triangle (sans écart)
+/// * `gap` - Écart angulaire entre deux triangles voisins, en radians
+/// * `height_ratio` - Rapport hauteur / côté
+/// 
+/// # Cas limite
+/// Un écart plus grand que la base la réduit à un point (triangle plat).
+pub fn calculate_exterior_triangle_points_with_gap(
+    base_angle: f32,
+    circle_radius: f32,
+    triangle_side: f32,
+    gap: f32,
+    height_ratio: f32,
+) -> (Vec2, Vec2, Vec2) {
+    let half_side = triangle_side / 2.0;
+    let height = triangle_side * height_ratio;
+    
+    // === CALCUL DES DEUX POINTS DE BASE SUR LE CERCLE ===
+    // L'écart angulaire est approximé par half_side / circle_radius,
+    // diminué de la moitié de l'espace entre triangles
+    let half_angle = (half_side / circle_radius - gap / 2.0).max(0.0);
+    let angle1 = base_angle - half_angle;
+    let angle2 = base_angle + half_angle;
+    
+    // Point 1 : sur le cercle, à gauche du centre
+    let p1 = Vec2::new(
+        circle_radius * angle1.cos(),
+        circle_radius * angle1.sin(),
+    );
+    
+    // Point 2 : sur le cercle, à droite du centre
+    let p2 = Vec2::new(
+        circle_radius * angle2.cos(),
+        circle_radius * angle2.sin(),
+    );
+    
+    // === CALCUL DU SOMMET EXTÉRIEUR ===
+    // Positionné à une distance de (rayon + hauteur) du centre
+    let p3 = Vec2::new(
+        (circle_radius + height) * base_angle.cos(),
+        (circle_radius + height) * base_angle.sin(),
+    );
+    
+    (p1, p2, p3)
+}
+
+/// Calcule les coordonnées d'un triangle intérieur
+/// 
+/// Similaire à calculate_exterior_triangle_points mais :
+/// - La base touche le cercle intérieur
+/// - Le sommet pointe vers le centre (intérieur)
+/// 
+/// # Arguments
+/// * `base_angle` - Angle central du triangle en radians
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `triangle_side` - Longueur d'un côté du triangle
+pub fn calculate_interior_triangle_points(
+    base_angle: f32,
+    inner_radius: f32,
+    triangle_side: f32,
+) -> (Vec2, Vec2, Vec2) {
+    calculate_interior_triangle_points_directed(
+        base_angle,
+        inner_radius,
+        triangle_side,
+        true,
+        config::EQUILATERAL_HEIGHT_RATIO,
+    )
+}
+
+/// Calcule les coordonnées d'un triangle intérieur, sommet vers le centre ou vers l'extérieur
+/// 
+/// Même base que `calculate_interior_triangle_points` (sur le cercle
+/// intérieur) ; seul le sommet change :
+/// - `apex_inward` vrai : à (rayon - hauteur) du centre
+/// - `apex_inward` faux : à (rayon + hauteur), effet "soleil" (le sommet
+///   chevauche l'anneau si le triangle est plus haut que son épaisseur)
+/// 
+/// La hauteur vaut `triangle_side × height_ratio`
+/// (`EQUILATERAL_HEIGHT_RATIO` pour un triangle équilatéral).
+/// 
+/// # Arguments
+/// * `base_angle` - Angle central du triangle en radians
+/// * `inner_radius` - Rayon du cercle intérieur
+/// * `triangle_side` - Longueur de la base du triangle
+/// * `apex_inward` - Sens du sommet (vers le centre ou vers l'extérieur)
+/// * `height_ratio` - Rapport hauteur / côté
+pub fn calculate_interior_triangle_points_directed(
+    base_angle: f32,
+    inner_radius: f32,
+    triangle_side: f32,
+    apex_inward: bool,
+    height_ratio: f32,
+) -> (Vec2, Vec2, Vec2) {
+    let half_side = triangle_side / 2.0;
+    let height = triangle_side * height_ratio;
+    
+    // === POINTS DE BASE SUR LE CERCLE INTÉRIEUR ===
+    let angle1 = base_angle - (half_side / inner_radius);
+    let angle2 = base_angle + (half_side / inner_radius);
+    
+    let p1 = Vec2::new(
+        inner_radius * angle1.cos(),
+        inner_radius * angle1.sin(),
+    );
+    let p2 = Vec2::new(
+        inner_radius * angle2.cos(),
+        inner_radius * angle2.sin(),
+    );
+    
+    // === SOMMET ===
+    // Distance : rayon - hauteur (vers le centre) ou rayon + hauteur (vers l'extérieur)
+    let apex_radius = if apex_inward { inner_radius - height } else { inner_radius + height };
+    let p3 = Vec2::new(
+        apex_radius * base_angle.cos(),
+        apex_radius * base_angle.sin(),
+    );
+    
+    (p1, p2, p3)
+}
+
+/// Calcule l'angle de position (en degrés) d'un triangle extérieur
+/// 
+/// Les triangles sont répartis régulièrement sur 360° :
+/// l'écart entre deux triangles vaut 360° / count.
+/// 
+/// # Arguments
+/// * `index` - Position du triangle (0 à count-1)
+/// * `count` - Nombre total de triangles extérieurs
+/// 
+/// # Exemples
+/// - count = 36 : 0°, 10°, 20°, ... (configuration par défaut)
+/// - count = 12 : 0°, 30°, 60°, ...
+pub fn exterior_triangle_angle_degrees(index: usize, count: usize) -> f32 {
+    (index as f32) * 360.0 / count as f32
+}
+
+/// Calcule l'angle de position (en degrés) d'un triangle intérieur
+/// 
+/// Les triangles sont répartis régulièrement sur 360° :
+/// l'écart entre deux triangles vaut 360° / count.
+/// Un décalage de +90° oriente le premier triangle vers le haut.
+/// 
+/// # Arguments
+/// * `index` - Position du triangle (0 à count-1)
+/// * `count` - Nombre total de triangles intérieurs
+/// 
+/// # Exemples
+/// - count = 5 : 90°, 162°, 234°, 306°, 378° (pentagone)
+/// - count = 6 : 90°, 150°, 210°, ... (hexagone, 60° d'écart)
+pub fn interior_triangle_angle_degrees(index: usize, count: usize) -> f32 {
+    let step = 360.0 / count as f32;
+    (index as f32) * step + 90.0
+}
+
+/// Calcule le centroïde (centre de gravité) d'un triangle
+/// 
+/// Le centroïde est le point d'intersection des médianes du triangle.
+/// Pour un triangle, c'est simplement la moyenne des coordonnées
+/// de ses trois sommets.
+/// 
+/// # Formule
+/// Centroïde = ((x1+x2+x3)/3, (y1+y2+y3)/3)
+/// 
+/// # Arguments
+/// * `p1`, `p2`, `p3` - Les trois sommets du triangle
+/// 
+/// # Propriété
+/// Le centroïde est équidistant des trois côtés du triangle
+pub fn calculate_triangle_centroid(p1: Vec2, p2: Vec2, p3: Vec2) -> Vec2 {
+    Vec2::new(
+        (p1.x + p2.x + p3.x) / 3.0,
+        (p1.y + p2.y + p3.y) / 3.0,
+    )
+}
+
+/// Calcule le centroïde (centre de gravité) d'un polygone
+/// 
+/// Contrairement à la simple moyenne des sommets, ce centroïde tient compte
+/// de la surface : un côté découpé en nombreux points ne "tire" pas le centre.
+/// 
+/// # Formule (polygone non croisé de N sommets)
+/// - A  = ½ × Σ (xᵢ·yᵢ₊₁ - xᵢ₊₁·yᵢ)
+/// - Cx = 1/(6A) × Σ (xᵢ + xᵢ₊₁)(xᵢ·yᵢ₊₁ - xᵢ₊₁·yᵢ)
+/// - Cy = 1/(6A) × Σ (yᵢ + yᵢ₊₁)(xᵢ·yᵢ₊₁ - xᵢ₊₁·yᵢ)
+/// 
+/// # Arguments
+/// * `points` - Sommets du polygone, dans l'ordre du contour
+/// 
+/// # Cas dégénérés
+/// Si la surface est quasi nulle (points alignés, moins de 3 points),
+/// retourne la moyenne des sommets (ou `Vec2::ZERO` si la liste est vide).
+pub fn polygon_centroid(points: &[Vec2]) -> Vec2 {
+    if points.is_empty() {
+        return Vec2::ZERO;
+    }
+    
+    let mut double_area = 0.0;
+    let mut weighted_sum = Vec2::ZERO;
+    
+    for i in 0..points.len() {
+        let current = points[i];
+        let next = points[(i + 1) % points.len()];
+        
+        // Produit vectoriel : double de l'aire signée du triangle (origine, current, next)
+        let cross = current.perp_dot(next);
+        double_area += cross;
+        weighted_sum += (current + next) * cross;
+    }
+    
+    if double_area.abs() < f32::EPSILON {
+        // Polygone dégénéré : repli sur la moyenne des sommets
+        return points.iter().copied().sum::<Vec2>() / points.len() as f32;
+    }
+    
+    weighted_sum / (3.0 * double_area)
+}
+
+/// Vérifie qu'un polygone est simple (son contour ne se croise pas)
+/// 
+/// Compare chaque paire d'arêtes non adjacentes : le polygone est simple
+/// si aucune ne se coupe ni ne se touche. Un contour qui se croise (en
+/// "nœud papillon" par exemple) est mal rendu par la triangulation en éventail.
+/// 
+/// # Arguments
+/// * `points` - Sommets du polygone, dans l'ordre du contour (fermé implicitement)
+/// 
+/// # Complexité
+/// O(n²) : suffisant pour les quelques dizaines de points d'une lettre.
+/// 
+/// # Cas dégénérés
+/// Les points consécutifs identiques (dont un point de fermeture répété)
+/// sont ignorés. Moins de 3 points distincts : retourne faux.
+pub fn is_simple_polygon(points: &[Vec2]) -> bool {
+    // Un point de fermeture répété (dernier = premier) est toléré
+    let points = remove_duplicate_points(points, true);
+    let count = points.len();
+    if count < 3 {
+        return false;
+    }
+    
+    for i in 0..count {
+        let (a, b) = (points[i], points[(i + 1) % count]);
+        for j in (i + 2)..count {
+            // La dernière arête est adjacente à la première
+            if i == 0 && j == count - 1 {
+                continue;
+            }
+            let (c, d) = (points[j], points[(j + 1) % count]);
+            if segments_intersect(a, b, c, d) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Défaut d'un contour qui empêche un rendu correct (voir `polygon_defect`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolygonDefect {
+    /// Moins de 3 points (nombre de points reçus)
+    TooFewPoints(usize),
+    
+    /// Aire nulle : points confondus ou alignés
+    ZeroArea,
+    
+    /// Le contour se croise lui-même (voir `is_simple_polygon`)
+    SelfIntersecting,
+}
+
+impl fmt::Display for PolygonDefect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonDefect::TooFewPoints(count) => write!(f, "{} point(s), au moins 3 nécessaires", count),
+            PolygonDefect::ZeroArea => write!(f, "aire nulle (points confondus ou alignés)"),
+            PolygonDefect::SelfIntersecting => write!(f, "le contour se croise lui-même"),
+        }
+    }
+}
+
+/// Cherche le premier défaut d'un contour de polygone
+/// 
+/// Vérifie dans l'ordre : au moins 3 points, aire non nulle
+/// (`polygon_signed_area`), contour simple (`is_simple_polygon`).
+/// 
+/// # Retour
+/// `None` si le polygone peut être rendu correctement
+pub fn polygon_defect(points: &[Vec2]) -> Option<PolygonDefect> {
+    if points.len() < 3 {
+        return Some(PolygonDefect::TooFewPoints(points.len()));
+    }
+    if polygon_signed_area(points).abs() <= f32::EPSILON {
+        return Some(PolygonDefect::ZeroArea);
+    }
+    if !is_simple_polygon(points) {
+        return Some(PolygonDefect::SelfIntersecting);
+    }
+    None
+}
+
+/// Vrai si les segments [a, b] et [c, d] se coupent ou se touchent
+/// 
+/// Test d'orientation : chaque segment doit avoir les extrémités de
+/// l'autre de part et d'autre (ou dessus, pour les cas alignés).
+fn segments_intersect(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    // Signe de l'aire du triangle (p, q, r) : côté de r par rapport à (p, q)
+    let orientation = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    // r (aligné avec p, q) est-il dans le rectangle englobant de [p, q] ?
+    let on_segment = |p: Vec2, q: Vec2, r: Vec2| r.cmpge(p.min(q)).all() && r.cmple(p.max(q)).all();
+    
+    let d1 = orientation(c, d, a);
+    let d2 = orientation(c, d, b);
+    let d3 = orientation(a, b, c);
+    let d4 = orientation(a, b, d);
+    
+    // Cas général : croisement strict
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    
+    // Cas alignés : une extrémité posée sur l'autre segment
+    (d1 == 0.0 && on_segment(c, d, a))
+        || (d2 == 0.0 && on_segment(c, d, b))
+        || (d3 == 0.0 && on_segment(a, b, c))
+        || (d4 == 0.0 && on_segment(a, b, d))
+}
+
+/// Calcule l'aire signée d'un polygone (formule du lacet)
+/// 
+/// # Formule
+/// A = ½ × Σ (xᵢ·yᵢ₊₁ - xᵢ₊₁·yᵢ)
+/// 
+/// # Signe
+/// - positive : sommets dans le sens antihoraire (Y vers le haut)
+/// - négative : sommets dans le sens horaire
+/// - nulle : polygone dégénéré (points alignés, moins de 3 points)
+pub fn polygon_signed_area(points: &[Vec2]) -> f32 {
+    let mut double_area = 0.0;
+    for i in 0..points.len() {
+        double_area += points[i].perp_dot(points[(i + 1) % points.len()]);
+    }
+    double_area / 2.0
+}
+
+/// Indique si un point est à l'intérieur d'un polygone
+/// 
+/// Règle pair-impair : une demi-droite horizontale partant du point
+/// coupe le contour un nombre impair de fois si le point est dedans.
+/// Le contour est fermé implicitement (dernier point relié au premier).
+/// 
+/// # Retour
+/// `false` pour moins de 3 points. Un point exactement sur le contour
+/// peut être classé d'un côté ou de l'autre.
+pub fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &current in polygon {
+        // L'arête traverse-t-elle la ligne horizontale du point, à sa droite ?
+        if (current.y > point.y) != (previous.y > point.y) {
+            let crossing_x = current.x + (point.y - current.y) / (previous.y - current.y) * (previous.x - current.x);
+            if point.x < crossing_x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Calcule la boîte englobante (min, max) d'un ensemble de points
+/// 
+/// La boîte englobante est le plus petit rectangle aligné sur les axes
+/// contenant tous les points.
+/// 
+/// # Arguments
+/// * `points` - Les points à englober
+/// 
+/// # Retour
+/// `(min, max)` : coin inférieur gauche et coin supérieur droit.
+/// Pour une liste vide, retourne `(Vec2::ZERO, Vec2::ZERO)`.
+pub fn bounding_box(points: &[Vec2]) -> (Vec2, Vec2) {
+    if points.is_empty() {
+        return (Vec2::ZERO, Vec2::ZERO);
+    }
+    
+    points.iter().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    )
+}
+
+/// Calcule la boîte englobante de plusieurs parties du logo "R"
+/// 
+/// Réunit les points de toutes les parties puis appelle `bounding_box`.
+/// 
+/// # Arguments
+/// * `parts` - Les parties à englober (ex : `get_letter_parts('R')`)
+pub fn bounding_box_of_parts(parts: &[RPartDefinition]) -> (Vec2, Vec2) {
+    let all_points: Vec<Vec2> = parts
+        .iter()
+        .flat_map(|part| part.points.iter().copied())
+        .collect();
+    
+    bounding_box(&all_points)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//                    SECTION 4 : DÉFINITION DU LOGO "R"
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Structure représentant une partie du logo "R"
+/// 
+/// Chaque partie du logo est définie par :
+/// - Un nom descriptif pour l'identification
+/// - Une liste de points formant le polygone
+/// - Un ordre de profondeur Z pour le rendu en couches
+/// 
+/// Le nom est un `Cow` : littéral (`"Haut du R".into()`) pour les lettres
+/// intégrées, `String` pour les parties créées à l'exécution (données
+/// chargées, édition).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RPartDefinition {
+    /// Nom descriptif de la partie (pour debug et logs)
+    pub name: Cow<'static, str>,
+    
+    /// Liste ordonnée des sommets du polygone
+    /// Les points doivent former un contour dans l'ordre
+    pub points: Vec<Vec2>,
+    
+    /// Ordre de rendu sur l'axe Z (plus élevé = devant)
+    /// Attribué automatiquement par `assign_z_orders` selon la position
+    /// de la partie : inutile de le renseigner à la main
+    pub z_order: f32,
+}
+
+impl RPartDefinition {
+    /// Crée une partie à partir de son nom, de ses sommets et de sa profondeur
+    /// 
+    /// # Exemple
+    /// ```ignore
+    /// let part = RPartDefinition::new(format!("Partie {}", i), points, 0.0);
+    /// ```
+    pub fn new(name: impl Into<Cow<'static, str>>, points: Vec<Vec2>, z_order: f32) -> Self {
+        Self { name: name.into(), points, z_order }
+    }
+}
+
+/// Retourne toutes les parties composant le logo "R"
+/// 
+/// Le logo "R" est décomposé en 8 parties géométriques distinctes :
+/// 1. Haut : barre horizontale supérieure
+/// 2. Gauche : barre verticale principale
+/// 3. Arrondi : courbe de la partie droite supérieure
+/// 4. Centre : petite barre de séparation
+/// 5. Pied gauche : base élargie à gauche
+/// 6. Milieu : zone de connexion centrale
+/// 7. Jambe droite : diagonale partant vers la droite
+/// 8. Pied droit : base élargie à droite
+/// 
+/// # Organisation
+/// Chaque partie reçoit un z_order incrémental (0.40 à 0.47 par défaut)
+/// via `assign_z_orders`, pour qu'elles se superposent correctement
+/// sans conflit visuel.
+/// 
+/// # Coordonnées
+/// Les coordonnées sont définies dans un système où :
+/// - (0, 0) est au centre de l'écran
+/// - X positif va vers la droite
+/// - Y positif va vers le haut
+/// 
+/// # Modification
+/// Pour ajuster le logo :
+/// 1. Modifier les Vec2::new(x, y) de chaque partie
+/// 2. Pour ajouter une partie : ajouter un nouveau RPartDefinition
+///    (le z_order est recalculé, l'ordre de la liste fait foi)
+/// 3. Pour supprimer une partie : commenter ou retirer le bloc
+pub fn get_all_r_parts() -> Vec<RPartDefinition> {
+    let mut parts = vec![
+        // === PARTIE 1 : BARRE HORIZONTALE SUPÉRIEURE ===
         RPartDefinition {
-            name: "Haut du R",
-            z_order: 0.40,
+            name: "Haut du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(-140.0, 90.0),  // Coin supérieur gauche
                 Vec2::new(60.0, 90.0),    // Coin supérieur droit
@@ -436,8 +2086,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         
         // === PARTIE 2 : BARRE VERTICALE PRINCIPALE GAUCHE ===
         RPartDefinition {
-            name: "Gauche du R",
-            z_order: 0.41,
+            name: "Gauche du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(-80.0, 50.0),   // Haut de la barre
                 Vec2::new(-30.0, 50.0),   // Haut droit
@@ -449,8 +2099,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         // === PARTIE 3 : COURBE ARRONDIE DROITE ===
         // Points formant l'arrondi caractéristique du "R"
         RPartDefinition {
-            name: "Arrondi du R",
-            z_order: 0.42,
+            name: "Arrondi du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(60.0, 90.0),    // Départ en haut
                 Vec2::new(85.0, 60.0),    // Premier point de courbe
@@ -463,20 +2113,20 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         // === PARTIE 4 : SÉPARATEUR CENTRAL ===
         // Petite barre horizontale au milieu
         RPartDefinition {
-            name: "Centre du R",
-            z_order: 0.43,
+            name: "Centre du R".into(),
+            z_order: 0.0,
             points: vec![
-                Vec2::new(60.0, 50.0),    // Haut gauche
-                Vec2::new(40.0, 50.0),    // Haut droit
-                Vec2::new(60.0, 10.0),    // Bas gauche
-                Vec2::new(40.0, 10.0),    // Bas droit
+                Vec2::new(40.0, 50.0),    // Haut gauche
+                Vec2::new(60.0, 50.0),    // Haut droit
+                Vec2::new(60.0, 10.0),    // Bas droit
+                Vec2::new(40.0, 10.0),    // Bas gauche
             ],
         },
         
         // === PARTIE 5 : PIED GAUCHE ÉLARGI ===
         RPartDefinition {
-            name: "Pied gauche du R",
-            z_order: 0.44,
+            name: "Pied gauche du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(-80.0, -50.0),   // Connexion avec barre verticale
                 Vec2::new(-10.0, -50.0),   // Vers le centre
@@ -489,8 +2139,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         
         // === PARTIE 6 : ZONE DE CONNEXION CENTRALE ===
         RPartDefinition {
-            name: "Milieu du R",
-            z_order: 0.45,
+            name: "Milieu du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(60.0, -30.0),   // Connexion avec arrondi
                 Vec2::new(60.0, 10.0),    // Montée
@@ -501,8 +2151,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         
         // === PARTIE 7 : JAMBE DIAGONALE DROITE ===
         RPartDefinition {
-            name: "Jambe droite du R",
-            z_order: 0.46,
+            name: "Jambe droite du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(60.0, -30.0),   // Départ sous l'arrondi
                 Vec2::new(20.0, -30.0),   // Vers l'intérieur
@@ -513,8 +2163,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         
         // === PARTIE 8 : PIED DROIT ÉLARGI ===
         RPartDefinition {
-            name: "Pied droit du R",
-            z_order: 0.47,
+            name: "Pied droit du R".into(),
+            z_order: 0.0,
             points: vec![
                 Vec2::new(160.0, -50.0),  // Extrémité droite
                 Vec2::new(30.0, -50.0),   // Vers le centre
@@ -522,5 +2172,701 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
                 Vec2::new(120.0, -80.0),  // Largeur du pied
             ],
         },
+    ];
+    
+    // === ATTRIBUTION DES PROFONDEURS ===
+    // 0.40, 0.41, ... dans l'ordre de la liste
+    assign_z_orders(&mut parts, config::R_BASE_Z, config::R_Z_STEP);
+    parts
+}
+
+/// Retourne la boîte englobante (min, max) du logo "R"
+/// 
+/// Emprise des points de toutes les parties de `get_all_r_parts`, sans
+/// mise à l'échelle ni création d'entité : utile aux outils de mise en
+/// page (placement, calcul de marges).
+pub fn r_logo_bounds() -> (Vec2, Vec2) {
+    bounding_box_of_parts(&get_all_r_parts())
+}
+
+/// Lettres disponibles pour `get_letter_parts`
+pub const SUPPORTED_LETTERS: [char; 4] = ['R', 'U', 'S', 'T'];
+
+/// Retourne les parties composant une lettre du logo
+/// 
+/// Permet d'afficher une autre lettre que le "R" avec le même
+/// pipeline de rendu (mesh polygonal, couleur, contour, éclatement).
+/// Toutes les lettres sont dessinées dans la même zone que le "R"
+/// (environ -160..160 en X, -80..90 en Y) pour tenir dans l'anneau.
+/// 
+/// # Arguments
+/// * `letter` - La lettre souhaitée (majuscule ou minuscule)
+/// 
+/// # Retour
+/// - `Some(parties)` pour une lettre de `SUPPORTED_LETTERS`
+/// - `None` si la lettre n'est pas définie
+pub fn get_letter_parts(letter: char) -> Option<Vec<RPartDefinition>> {
+    let mut parts = match letter.to_ascii_uppercase() {
+        'R' => return Some(get_all_r_parts()),
+        'U' => get_u_parts(),
+        'S' => get_s_parts(),
+        'T' => get_t_parts(),
+        _ => return None,
+    };
+    
+    assign_z_orders(&mut parts, config::R_BASE_Z, config::R_Z_STEP);
+    Some(parts)
+}
+
+/// Partie telle qu'écrite dans un fichier JSON (voir `load_r_parts`)
+#[derive(Deserialize)]
+struct RPartFileEntry {
+    name: String,
+    points: Vec<[f32; 2]>,
+    #[serde(default)]
+    z_order: f32,
+}
+
+/// Charge des parties de lettre depuis un fichier JSON
+/// 
+/// Le fichier contient une liste de parties :
+/// ```json
+/// [{ "name": "Haut du R", "points": [[-110, 90], [10, 90], [10, 40]], "z_order": 0.3 }]
+/// ```
+/// `z_order` est facultatif (0 par défaut, voir `assign_z_orders`).
+/// 
+/// # Erreurs
+/// - `LogoError::Io` si le fichier ne peut pas être lu
+/// - `LogoError::Parse` si le JSON ne correspond pas au format
+/// - `LogoError::TooFewPoints` si une partie a moins de 3 points
+pub fn load_r_parts(path: impl AsRef<Path>) -> Result<Vec<RPartDefinition>, LogoError> {
+    let text = std::fs::read_to_string(path)?;
+    let entries: Vec<RPartFileEntry> = serde_json::from_str(&text)?;
+    
+    entries
+        .into_iter()
+        .map(|entry| {
+            if entry.points.len() < 3 {
+                return Err(LogoError::TooFewPoints { count: entry.points.len(), min: 3 });
+            }
+            let points = entry.points.into_iter().map(Vec2::from_array).collect();
+            Ok(RPartDefinition::new(entry.name, points, entry.z_order))
+        })
+        .collect()
+}
+
+/// Parties de la lettre "U" : deux montants reliés par une base arrondie
+fn get_u_parts() -> Vec<RPartDefinition> {
+    vec![
+        // === MONTANT GAUCHE ===
+        RPartDefinition {
+            name: "Gauche du U".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, 90.0),
+                Vec2::new(-60.0, 90.0),
+                Vec2::new(-60.0, -40.0),
+                Vec2::new(-110.0, -40.0),
+            ],
+        },
+        
+        // === MONTANT DROIT ===
+        RPartDefinition {
+            name: "Droite du U".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(60.0, 90.0),
+                Vec2::new(110.0, 90.0),
+                Vec2::new(110.0, -40.0),
+                Vec2::new(60.0, -40.0),
+            ],
+        },
+        
+        // === BASE (coins biseautés) ===
+        RPartDefinition {
+            name: "Base du U".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, -40.0),
+                Vec2::new(110.0, -40.0),
+                Vec2::new(80.0, -80.0),
+                Vec2::new(-80.0, -80.0),
+            ],
+        },
+    ]
+}
+
+/// Parties de la lettre "S" : trois barres reliées par deux montants
+fn get_s_parts() -> Vec<RPartDefinition> {
+    vec![
+        // === BARRE SUPÉRIEURE ===
+        RPartDefinition {
+            name: "Haut du S".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, 90.0),
+                Vec2::new(110.0, 90.0),
+                Vec2::new(110.0, 50.0),
+                Vec2::new(-110.0, 50.0),
+            ],
+        },
+        
+        // === MONTANT SUPÉRIEUR GAUCHE ===
+        RPartDefinition {
+            name: "Gauche du S".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, 50.0),
+                Vec2::new(-60.0, 50.0),
+                Vec2::new(-60.0, 25.0),
+                Vec2::new(-110.0, 25.0),
+            ],
+        },
+        
+        // === BARRE CENTRALE ===
+        RPartDefinition {
+            name: "Milieu du S".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, 25.0),
+                Vec2::new(110.0, 25.0),
+                Vec2::new(110.0, -15.0),
+                Vec2::new(-110.0, -15.0),
+            ],
+        },
+        
+        // === MONTANT INFÉRIEUR DROIT ===
+        RPartDefinition {
+            name: "Droite du S".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(60.0, -15.0),
+                Vec2::new(110.0, -15.0),
+                Vec2::new(110.0, -40.0),
+                Vec2::new(60.0, -40.0),
+            ],
+        },
+        
+        // === BARRE INFÉRIEURE ===
+        RPartDefinition {
+            name: "Bas du S".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-110.0, -40.0),
+                Vec2::new(110.0, -40.0),
+                Vec2::new(110.0, -80.0),
+                Vec2::new(-110.0, -80.0),
+            ],
+        },
+    ]
+}
+
+/// Parties de la lettre "T" : une barre horizontale sur une jambe centrale
+fn get_t_parts() -> Vec<RPartDefinition> {
+    vec![
+        // === BARRE HORIZONTALE ===
+        RPartDefinition {
+            name: "Barre du T".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-130.0, 90.0),
+                Vec2::new(130.0, 90.0),
+                Vec2::new(130.0, 50.0),
+                Vec2::new(-130.0, 50.0),
+            ],
+        },
+        
+        // === JAMBE CENTRALE ===
+        RPartDefinition {
+            name: "Jambe du T".into(),
+            z_order: 0.0,
+            points: vec![
+                Vec2::new(-25.0, 50.0),
+                Vec2::new(25.0, 50.0),
+                Vec2::new(25.0, -80.0),
+                Vec2::new(-25.0, -80.0),
+            ],
+        },
     ]
 }
+
+/// Attribue un z_order croissant à chaque partie
+/// 
+/// La partie d'index i reçoit `base_z + i × step` : plus besoin
+/// de tenir à jour les profondeurs à la main, et deux parties
+/// ne peuvent pas partager la même valeur (si step > 0).
+/// 
+/// # Arguments
+/// * `parts` - Les parties à modifier (dans l'ordre de rendu souhaité)
+/// * `base_z` - Profondeur de la première partie
+/// * `step` - Écart entre deux parties consécutives
+pub fn assign_z_orders(parts: &mut [RPartDefinition], base_z: f32, step: f32) {
+    for (index, part) in parts.iter_mut().enumerate() {
+        part.z_order = base_z + index as f32 * step;
+    }
+}
+
+/// Met à l'échelle les points de chaque partie, autour de l'origine
+/// 
+/// # Arguments
+/// * `parts` - Les parties à modifier
+/// * `factor` - Facteur d'échelle (1.0 = inchangé)
+pub fn scale_parts(parts: &mut [RPartDefinition], factor: f32) {
+    for part in parts.iter_mut() {
+        for point in part.points.iter_mut() {
+            *point *= factor;
+        }
+    }
+}
+
+/// Recherche les profondeurs Z partagées par plusieurs couches
+/// 
+/// Deux éléments au même Z se superposent dans un ordre imprévisible
+/// (scintillement, "z-fighting"). Deux valeurs sont considérées égales
+/// si leur écart est inférieur à 1e-6.
+/// 
+/// # Arguments
+/// * `z_values` - Profondeurs de toutes les couches créées
+/// 
+/// # Retour
+/// Les paires d'index `(i, j)` avec i < j dont les Z sont confondus
+pub fn find_z_collisions(z_values: &[f32]) -> Vec<(usize, usize)> {
+    let mut collisions = Vec::new();
+    for i in 0..z_values.len() {
+        for j in (i + 1)..z_values.len() {
+            if (z_values[i] - z_values[j]).abs() < 1e-6 {
+                collisions.push((i, j));
+            }
+        }
+    }
+    collisions
+}
+
+/// Paire de parties dont les boîtes englobantes se chevauchent
+/// 
+/// Résultat de `find_part_overlaps`, indices dans la liste des parties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartOverlap {
+    /// Index de la première partie (toujours inférieur à `second`)
+    pub first: usize,
+    
+    /// Index de la seconde partie
+    pub second: usize,
+    
+    /// Vrai si au moins une arête de chaque contour se coupe ou se touche
+    /// 
+    /// Faux : les boîtes se chevauchent mais pas les contours (parties
+    /// voisines séparées par un espace, ou contour entièrement inclus).
+    pub edges_intersect: bool,
+}
+
+/// Recherche les parties d'une lettre qui se chevauchent ou se touchent
+/// 
+/// Aide à l'assemblage d'une lettre : deux parties prévues pour être
+/// jointives doivent apparaître avec `edges_intersect` vrai ; une paire
+/// absente du résultat signale au contraire un espace entre elles.
+/// 
+/// # Méthode
+/// 1. Filtre rapide : boîtes englobantes (`bounding_box`), bords inclus
+/// 2. Test exact : intersection de chaque arête de la première partie
+///    avec chaque arête de la seconde (même test que `is_simple_polygon`)
+/// 
+/// # Arguments
+/// * `parts` - Parties de la lettre (ex : `get_letter_parts('R')`)
+/// 
+/// # Retour
+/// Les paires dont les boîtes se chevauchent, dans l'ordre (i, j) avec i < j
+pub fn find_part_overlaps(parts: &[RPartDefinition]) -> Vec<PartOverlap> {
+    let boxes: Vec<(Vec2, Vec2)> = parts.iter().map(|part| bounding_box(&part.points)).collect();
+    let mut overlaps = Vec::new();
+    
+    for i in 0..parts.len() {
+        for j in (i + 1)..parts.len() {
+            // === FILTRE : BOÎTES ENGLOBANTES ===
+            let ((min_a, max_a), (min_b, max_b)) = (boxes[i], boxes[j]);
+            if min_a.cmpgt(max_b).any() || min_b.cmpgt(max_a).any() {
+                continue;
+            }
+            
+            // === TEST EXACT : ARÊTES ===
+            overlaps.push(PartOverlap {
+                first: i,
+                second: j,
+                edges_intersect: polygon_edges_intersect(&parts[i].points, &parts[j].points),
+            });
+        }
+    }
+    overlaps
+}
+
+/// Vrai si une arête du contour `a` coupe ou touche une arête du contour `b`
+fn polygon_edges_intersect(a: &[Vec2], b: &[Vec2]) -> bool {
+    let edges = |points: &[Vec2]| {
+        let points = points.to_vec();
+        (0..points.len()).map(move |i| (points[i], points[(i + 1) % points.len()]))
+    };
+    
+    edges(a).any(|(p, q)| edges(b).any(|(r, s)| segments_intersect(p, q, r, s)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//              SECTION 5 : TRAITEMENT DES CHEMINS DE POINTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Lisse un chemin de points par l'algorithme de Chaikin ("corner cutting")
+/// 
+/// À chaque itération, chaque segment [A, B] est remplacé par deux points :
+/// - Q = ¾A + ¼B
+/// - R = ¼A + ¾B
+/// 
+/// Les angles sont ainsi "rabotés" ; après quelques itérations le chemin
+/// tend vers une courbe lisse (B-spline quadratique).
+/// Chaque itération double environ le nombre de points.
+/// 
+/// # Arguments
+/// * `points` - Chemin d'origine (ex : contour numérisé à la main)
+/// * `iterations` - Nombre de passes (0 = chemin inchangé)
+/// * `closed` - Si vrai, le dernier point est relié au premier ;
+///   sinon les extrémités sont conservées telles quelles
+/// 
+/// # Utilisation
+/// Le résultat peut être passé à `create_polyline_mesh` ou
+/// `create_polygon_from_points`.
+pub fn chaikin_smooth(points: &[Vec2], iterations: usize, closed: bool) -> Vec<Vec2> {
+    let mut path = points.to_vec();
+    
+    for _ in 0..iterations {
+        if path.len() < 3 {
+            break;
+        }
+        
+        let segment_count = if closed { path.len() } else { path.len() - 1 };
+        let mut smoothed = Vec::with_capacity(segment_count * 2 + 2);
+        
+        // Extrémité de départ conservée pour un chemin ouvert
+        if !closed {
+            smoothed.push(path[0]);
+        }
+        
+        // === DÉCOUPE DE CHAQUE SEGMENT ===
+        for i in 0..segment_count {
+            let a = path[i];
+            let b = path[(i + 1) % path.len()];
+            smoothed.push(a * 0.75 + b * 0.25);
+            smoothed.push(a * 0.25 + b * 0.75);
+        }
+        
+        // Extrémité d'arrivée conservée pour un chemin ouvert
+        if !closed {
+            smoothed.push(path[path.len() - 1]);
+        }
+        
+        path = smoothed;
+    }
+    
+    path
+}
+
+/// Simplifie une polyligne par l'algorithme de Ramer-Douglas-Peucker
+/// 
+/// Supprime les points qui s'écartent de moins de `epsilon` de la ligne
+/// reliant les points conservés. Les deux extrémités sont toujours gardées.
+/// 
+/// # Algorithme (récursif)
+/// 1. Trouver le point le plus éloigné du segment [premier, dernier]
+/// 2. S'il est à moins de `epsilon` : ne garder que les extrémités
+/// 3. Sinon : le conserver et recommencer sur les deux moitiés
+/// 
+/// # Arguments
+/// * `points` - Polyligne d'origine
+/// * `epsilon` - Écart toléré en pixels (plus grand = moins de points)
+/// 
+/// # Cas particuliers
+/// `epsilon <= 0` ou moins de 3 points : la polyligne est retournée inchangée.
+pub fn simplify_polyline(points: &[Vec2], epsilon: f32) -> Vec<Vec2> {
+    if epsilon <= 0.0 || points.len() < 3 {
+        return points.to_vec();
+    }
+    
+    // === MARQUAGE DES POINTS À CONSERVER ===
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    simplify_range(points, 0, points.len() - 1, epsilon, &mut keep);
+    
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, kept)| kept.then_some(*p))
+        .collect()
+}
+
+/// Étape récursive de `simplify_polyline` sur les points [first, last]
+fn simplify_range(points: &[Vec2], first: usize, last: usize, epsilon: f32, keep: &mut [bool]) {
+    if last <= first + 1 {
+        return;
+    }
+    
+    // Point le plus éloigné du segment [first, last]
+    let (farthest, distance) = (first + 1..last)
+        .map(|i| (i, distance_point_to_segment(points[i], points[first], points[last])))
+        .fold((first, 0.0), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+    
+    if distance > epsilon {
+        keep[farthest] = true;
+        simplify_range(points, first, farthest, epsilon, keep);
+        simplify_range(points, farthest, last, epsilon, keep);
+    }
+}
+
+/// Distance d'un point `p` au segment [a, b]
+/// 
+/// Le point est projeté sur la droite (a, b) ; la projection est
+/// ramenée sur le segment si elle tombe au-delà d'une extrémité.
+/// 
+/// # Utilisation
+/// Sélection à la souris d'un trait fin (polyligne, contour) : le trait
+/// est touché si le curseur est à moins de N pixels de l'un de ses segments.
+/// 
+/// # Cas dégénérés
+/// Si a et b sont confondus, retourne la distance de `p` à `a`.
+pub fn distance_point_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared <= f32::EPSILON {
+        return p.distance(a);
+    }
+    
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
+/// Rééchantillonne une polyligne avec des points régulièrement espacés
+/// 
+/// Parcourt la polyligne et place un point tous les `spacing` pixels
+/// (distance mesurée le long du chemin), par interpolation linéaire
+/// sur les segments. Le premier et le dernier point sont conservés.
+/// 
+/// # Arguments
+/// * `points` - Polyligne d'origine
+/// * `spacing` - Distance entre deux points consécutifs du résultat
+/// 
+/// # Utilisation
+/// - avant `create_polyline_mesh` pour un trait régulier
+/// - pour déplacer un repère à vitesse constante le long du chemin
+/// 
+/// # Cas particuliers
+/// `spacing <= 0` ou moins de 2 points : la polyligne est retournée inchangée.
+pub fn resample_uniform(points: &[Vec2], spacing: f32) -> Vec<Vec2> {
+    if spacing <= 0.0 || points.len() < 2 {
+        return points.to_vec();
+    }
+    
+    let mut resampled = vec![points[0]];
+    
+    // Distance parcourue au début du segment courant, et prochaine distance cible
+    let mut travelled = 0.0;
+    let mut next_target = spacing;
+    
+    // === PARCOURS DES SEGMENTS ===
+    for segment in points.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        let length = a.distance(b);
+        
+        // Tous les points cibles situés sur ce segment
+        while next_target <= travelled + length + 1e-4 && length > 0.0 {
+            let t = ((next_target - travelled) / length).min(1.0);
+            resampled.push(a.lerp(b, t));
+            next_target += spacing;
+        }
+        travelled += length;
+    }
+    
+    // === DERNIER POINT ===
+    // Ajouté s'il ne tombe pas exactement sur un multiple de l'espacement
+    let last = points[points.len() - 1];
+    if resampled.last().is_some_and(|p| p.distance(last) > 1e-4) {
+        resampled.push(last);
+    }
+    
+    resampled
+}
+
+/// Génère une courbe de Catmull-Rom passant par tous les points de contrôle
+/// 
+/// Contrairement à une courbe de Bézier, la courbe de Catmull-Rom passe
+/// exactement par chaque point de contrôle : pratique pour transformer
+/// des points numérisés du "R" en contours lisses.
+/// 
+/// # Formule (segment de P1 à P2, voisins P0 et P3, t de 0 à 1)
+/// P(t) = ½ × [2P1 + (P2 - P0)t + (2P0 - 5P1 + 4P2 - P3)t² + (3P1 - P0 - 3P2 + P3)t³]
+/// 
+/// # Arguments
+/// * `points` - Points de contrôle
+/// * `samples_per_segment` - Nombre de points générés entre deux points de contrôle
+/// * `closed` - Si vrai, la courbe revient au premier point
+/// 
+/// # Extrémités (courbe ouverte)
+/// Le premier et le dernier point sont dupliqués pour servir de voisins
+/// fictifs : la tangente aux extrémités suit alors le premier/dernier segment.
+/// 
+/// # Cas particuliers
+/// Moins de 2 points ou `samples_per_segment == 0` : les points sont retournés inchangés.
+/// 
+/// # Utilisation
+/// Le résultat peut être passé directement à `create_polygon_from_points`.
+pub fn catmull_rom_spline(points: &[Vec2], samples_per_segment: usize, closed: bool) -> Vec<Vec2> {
+    let count = points.len();
+    if count < 2 || samples_per_segment == 0 {
+        return points.to_vec();
+    }
+    
+    // Accès aux points avec bouclage (fermé) ou duplication des extrémités (ouvert)
+    let point_at = |index: isize| -> Vec2 {
+        if closed {
+            points[index.rem_euclid(count as isize) as usize]
+        } else {
+            points[index.clamp(0, count as isize - 1) as usize]
+        }
+    };
+    
+    let segment_count = if closed { count } else { count - 1 };
+    let mut curve = Vec::with_capacity(segment_count * samples_per_segment + 1);
+    
+    // === ÉCHANTILLONNAGE DE CHAQUE SEGMENT ===
+    for i in 0..segment_count as isize {
+        let (p0, p1, p2, p3) = (point_at(i - 1), point_at(i), point_at(i + 1), point_at(i + 2));
+        
+        for sample in 0..samples_per_segment {
+            let t = sample as f32 / samples_per_segment as f32;
+            let t2 = t * t;
+            let t3 = t2 * t;
+            
+            curve.push(0.5 * (
+                2.0 * p1
+                    + (p2 - p0) * t
+                    + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                    + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3
+            ));
+        }
+    }
+    
+    // === DERNIER POINT (COURBE OUVERTE) ===
+    if !closed {
+        curve.push(points[count - 1]);
+    }
+    
+    curve
+}
+
+/// Retire les points consécutifs confondus d'un chemin
+/// 
+/// Pour un chemin fermé, retire aussi le dernier point s'il répète le premier
+/// (certains contours du "R" sont fermés explicitement).
+fn remove_duplicate_points(points: &[Vec2], closed: bool) -> Vec<Vec2> {
+    let mut path: Vec<Vec2> = Vec::with_capacity(points.len());
+    for p in points {
+        if path.last().is_none_or(|last| last.distance(*p) > f32::EPSILON) {
+            path.push(*p);
+        }
+    }
+    if closed && path.len() > 2 && path[0].distance(path[path.len() - 1]) <= f32::EPSILON {
+        path.pop();
+    }
+    path
+}
+
+/// Décale les côtés d'un polygone vers l'extérieur ou l'intérieur
+/// 
+/// Chaque côté est déplacé de `distance` le long de sa normale, puis les
+/// nouveaux sommets sont placés à l'intersection des côtés décalés voisins.
+/// Le sens de parcours (horaire ou antihoraire) est détecté automatiquement
+/// via `polygon_signed_area`.
+/// 
+/// # Arguments
+/// * `points` - Sommets du polygone (fermeture implicite)
+/// * `distance` - Décalage : positif = agrandir, négatif = rétrécir
+/// 
+/// # Limitations
+/// Prévu pour des polygones simples, plutôt convexes (comme les parties du "R").
+/// Aux angles très aigus, le déplacement d'un sommet est limité à
+/// 4 × |distance| pour éviter des pointes démesurées ; un rétrécissement
+/// plus grand que le polygone peut tout de même le retourner.
+/// 
+/// # Utilisation
+/// Base d'un contour plus propre qu'un simple trait : remplir
+/// `offset_polygon(points, largeur)` derrière la forme d'origine.
+pub fn offset_polygon(points: &[Vec2], distance: f32) -> Vec<Vec2> {
+    let path = remove_duplicate_points(points, true);
+    let count = path.len();
+    if count < 3 || distance == 0.0 {
+        return path;
+    }
+    
+    // === ORIENTATION ===
+    // Pour un polygone antihoraire, la normale extérieure est à droite du côté
+    let outward_sign = if polygon_signed_area(&path) >= 0.0 { -1.0 } else { 1.0 };
+    let outward_normal = |a: Vec2, b: Vec2| (b - a).normalize_or_zero().perp() * outward_sign;
+    
+    // === DÉPLACEMENT DE CHAQUE SOMMET ===
+    (0..count)
+        .map(|i| {
+            let previous = path[(i + count - 1) % count];
+            let current = path[i];
+            let next = path[(i + 1) % count];
+            
+            let normal_in = outward_normal(previous, current);
+            let normal_out = outward_normal(current, next);
+            
+            // Intersection des deux côtés décalés : le long de la bissectrice,
+            // à distance / cos(demi-angle), bornée à 4 × distance
+            let miter = (normal_in + normal_out).normalize_or(normal_in);
+            current + miter * (distance / miter.dot(normal_in).max(0.25))
+        })
+        .collect()
+}
+
+/// Axe de symétrie pour `mirror_points`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Axe horizontal (y = 0) : les y changent de signe
+    X,
+    
+    /// Axe vertical (x = 0) : les x changent de signe
+    Y,
+}
+
+/// Crée le symétrique d'un ensemble de points par rapport à un axe
+/// 
+/// La symétrie passe par l'origine. Comme une symétrie inverse le sens
+/// de parcours (horaire ↔ antihoraire), l'ordre des points est aussi
+/// inversé : le polygone obtenu garde la même orientation que l'original.
+/// 
+/// # Arguments
+/// * `points` - Points d'origine
+/// * `axis` - Axe de symétrie
+/// 
+/// # Exemple
+/// Combiné à `transform_points`, permet de placer deux "R" dos à dos.
+pub fn mirror_points(points: &[Vec2], axis: Axis) -> Vec<Vec2> {
+    let factor = match axis {
+        Axis::X => Vec2::new(1.0, -1.0),
+        Axis::Y => Vec2::new(-1.0, 1.0),
+    };
+    
+    points.iter().rev().map(|p| *p * factor).collect()
+}
+
+/// Applique un `Transform` (échelle, rotation, translation) à des points 2D
+/// 
+/// La composante Z du transform est ignorée.
+/// 
+/// # Arguments
+/// * `points` - Points d'origine
+/// * `transform` - Transformation à appliquer
+pub fn transform_points(points: &[Vec2], transform: &Transform) -> Vec<Vec2> {
+    points
+        .iter()
+        .map(|p| transform.transform_point(p.extend(0.0)).truncate())
+        .collect()
+}
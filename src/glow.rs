@@ -0,0 +1,81 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: src/glow.rs                             ║
+// ║  Matériau lumineux pour la variante "néon"                               ║
+// ║  Rôle : Faire briller certains éléments (bloom)                          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module glow
+//! 
+//! `ColorMaterial` ne peut pas dépasser une intensité de 1.0 : il ne
+//! déclenche jamais le bloom. `GlowMaterial` multiplie sa couleur par
+//! `emissive_strength` ; avec une caméra HDR et le composant `Bloom`,
+//! les éléments concernés rayonnent.
+//! 
+//! Utilisé uniquement si `LogoConfig::glow` est actif (voir
+//! `systems::glow`) : sinon, rien ne change.
+
+use bevy::asset::{load_internal_asset, weak_handle};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{AlphaMode2d, Material2d, Material2dPlugin};
+
+/// Shader du matériau, intégré au binaire (aucun dossier assets requis)
+pub const GLOW_SHADER_HANDLE: Handle<Shader> = weak_handle!("6d1f3c2a-8b4e-4f7a-9c55-2e1d0b7a4c90");
+
+/// Matériau 2D émissif
+/// 
+/// La couleur finale vaut `color × emissive_strength` (les couleurs par
+/// vertex du mesh sont aussi appliquées).
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+pub struct GlowMaterial {
+    /// Couleur de base (linéaire)
+    #[uniform(0)]
+    pub color: LinearRgba,
+    
+    /// Multiplicateur d'intensité (1.0 = aucun rayonnement)
+    #[uniform(0)]
+    pub emissive_strength: f32,
+    
+    /// Mode de rendu (opaque ou transparent)
+    pub alpha_mode: AlphaMode2d,
+}
+
+impl GlowMaterial {
+    /// Crée un matériau émissif à partir d'une couleur
+    /// 
+    /// Le mode de rendu suit la règle de `ColorMaterial` :
+    /// transparent si l'alpha est inférieur à 1.0.
+    pub fn new(color: Color, emissive_strength: f32) -> Self {
+        Self {
+            color: color.to_linear(),
+            emissive_strength,
+            alpha_mode: if color.alpha() < 1.0 {
+                AlphaMode2d::Blend
+            } else {
+                AlphaMode2d::Opaque
+            },
+        }
+    }
+}
+
+impl Material2d for GlowMaterial {
+    fn fragment_shader() -> ShaderRef {
+        GLOW_SHADER_HANDLE.into()
+    }
+    
+    fn alpha_mode(&self) -> AlphaMode2d {
+        self.alpha_mode
+    }
+}
+
+/// Plugin d'enregistrement du matériau lumineux
+/// 
+/// Charge le shader intégré et le pipeline de `GlowMaterial`.
+pub struct GlowPlugin;
+
+impl Plugin for GlowPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, GLOW_SHADER_HANDLE, "shaders/glow.wgsl", Shader::from_wgsl);
+        app.add_plugins(Material2dPlugin::<GlowMaterial>::default());
+    }
+}
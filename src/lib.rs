@@ -5,7 +5,22 @@
 // ║  Rôle : Déclarer tous les modules et configurer l'application Bevy      ║
 // ╚══════════════════════════════════════════════════════════════════════════╝
 
+// Le nom du crate (LogoRust_Bevy_20250929) vient du Cargo.toml et n'est pas en snake_case
+#![allow(non_snake_case)]
+
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bevy::app::ScheduleRunnerPlugin;
 use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::renderer::RenderDevice;
+use bevy::render::settings::{RenderCreation, WgpuFeatures, WgpuSettings};
+use bevy::sprite::Wireframe2dPlugin;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
 
 // === DÉCLARATION DES MODULES ===
 // Chaque module est défini dans un fichier séparé pour une meilleure organisation
@@ -19,20 +34,373 @@ pub mod materials;
 /// Module de géométrie - Fonctions de création de formes et calculs mathématiques
 pub mod geometry;
 
+/// Module de construction - Calcul des éléments du logo (meshes, couleurs, positions) sans ECS
+pub mod build;
+
+/// Module de description - Liste des éléments de la scène, sérialisable en JSON
+pub mod describe;
+
+/// Module de dessin - Formes simples (cercle, polygone, trait) créées à la demande
+pub mod draw;
+
+/// Module de rendu sur le processeur - Image du logo sans GPU (vignettes, tests)
+pub mod raster;
+
+/// Module du matériau lumineux - Matériau émissif pour la variante "néon"
+pub mod glow;
+
+/// Module des erreurs - Type d'erreur commun des fonctions faillibles
+pub mod error;
+
+/// Module des composants - Marqueurs attachés à chaque élément créé
+pub mod components;
+
 /// Module des systèmes - Contient la logique de setup et autres systèmes Bevy
 pub mod systems;
 
 // Import du système de setup pour l'utiliser dans la configuration
 use systems::setup::setup_system;
+use systems::regenerate::{
+    adjust_exterior_count_system, config_morph_system, regenerate_logo_system, regenerate_on_key_system,
+};
+use systems::layout::fit_logo_to_window_system;
+use systems::shadow::spawn_drop_shadows_system;
+use systems::animation::{
+    animation_controls_system, explode_r_system, global_opacity_system, intro_fade_system, manual_rotation_system,
+    ring_hue_cycle_system, toggle_explode_system,
+};
+use systems::camera::{
+    camera_drag_system, camera_fit_animation_system, drag_rotation_system, camera_inertia_system, camera_zoom_system,
+    clamp_camera_system, fit_camera_on_key_system, load_camera_state_system, save_camera_state_system,
+};
+use systems::stats::update_logo_stats_system;
+use systems::glow::apply_glow_system;
+use systems::theme::{cycle_theme_system, reapply_theme_system, recolor_system};
+use systems::background::{
+    background_load_failed_system, fit_background_to_view_system, spawn_background_image_system,
+};
+use systems::selection::{
+    clear_selection_system, despawn_selection_outline_system, marching_ants_system, select_part_on_click_system,
+    spawn_selection_outline_system,
+};
+use glow::GlowPlugin;
+use systems::record::{record_frame_system, start_recording_system, FrameRecorder};
+use systems::headless::{capture_headless_frame_system, prepare_headless_target_system, HeadlessCapture};
+use systems::debug::{
+    debug_gizmos_system, inspect_part_system, ruler_click_system, ruler_gizmos_system, ruler_label_system,
+    sync_wireframe_system, toggle_centroid_markers_system, toggle_debug_gizmos_system, toggle_ruler_system,
+    toggle_wireframe_system,
+};
+
+// Ressource de configuration, réexportée pour les utilisateurs de la bibliothèque
+pub use config::{ConfigError, LogoConfig, LogoConfigBuilder, Preset, RainbowStyle, RecordSpec};
+
+// Erreur commune des fonctions faillibles
+pub use error::LogoError;
+
+// Formes simples créées à la demande
+pub use draw::{spawn_circle, spawn_line, spawn_polygon};
+
+// Rendu sur le processeur
+pub use raster::rasterize;
+
+// Description de la scène sans fenêtre
+pub use describe::{describe_scene, ElementDescription, SceneDescription};
+
+// Composants marqueurs, réexportés pour écrire des requêtes sur les éléments du logo
+pub use components::{
+    BackgroundImage, BaseAlpha, CenterDot, CenterHub, CenterStar, CentroidMarker, DropShadow, ExteriorTriangle, GridBackground, InteriorTriangle,
+    IntroFade, LogoElementFilter, LogoRoot, LogoTile, MainCamera, MainCircle, MergedLetter, ROutline, RPart, RPartHome,
+    RainbowRing, RulerLabel, Selected, SelectionOutline, SpiralBackground,
+};
+
+// Ressources d'état des animations
+pub use systems::animation::{AnimationState, ExplodeState, GlobalOpacity};
+
+// État du déplacement de la caméra
+pub use systems::camera::{CameraFit, CameraState, DragState, RotateDragState};
+
+// Thème de couleurs courant
+pub use materials::Theme;
+pub use systems::theme::{CurrentTheme, RecolorEvent};
+
+// Partie du "R" inspectée (flèches Droite/Gauche/Bas)
+pub use systems::debug::{InspectedPart, Ruler, WireframeSupport};
+
+// Coût de rendu du logo
+pub use systems::stats::LogoStats;
+
+// Événement de reconstruction du logo
+pub use systems::regenerate::{ConfigMorph, RegenerateLogo};
+
+/// Plugin Bevy du logo, à ajouter à une application existante
+/// 
+/// Cœur du logo, sans aucune entrée clavier ou souris : configuration,
+/// création et régénération du logo (événement `RegenerateLogo`,
+/// transition `ConfigMorph`), ajustement à la fenêtre, ombres, variante
+/// lumineuse, animations (`AnimationState`, `ExplodeState`), recoloriage
+/// (`RecolorEvent`), contour des parties `Selected`, image de fond et
+/// enregistrement. L'application hôte fournit `DefaultPlugins` (ou
+/// l'équivalent) et garde la main sur sa boucle, ses entrées et sa caméra.
+/// 
+/// Les commandes clavier et souris sont dans `LogoInteractionPlugin`, les
+/// outils de débogage dans `LogoDebugPlugin` : deux plugins optionnels,
+/// ajoutés tous les deux par `run()`.
+/// 
+/// # Cohabitation avec l'application hôte
+/// - Caméra : le setup ne crée sa caméra que si aucune `MainCamera`
+///   n'existe ; une application qui a sa propre caméra lui ajoute ce
+///   marqueur (dans un système exécuté avant `setup_system`)
+/// - `GlowPlugin` n'est ajouté que s'il ne l'est pas déjà
+/// 
+/// # Panic
+/// Panic avec le message de `ConfigError` si `LogoConfig::validate`
+/// refuse la configuration (y compris celle de `run_with_config`).
+/// 
+/// # Exemple
+/// ```ignore
+/// App::new()
+///     .add_plugins((DefaultPlugins, LogoPlugin::new(LogoConfig::default())))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogoPlugin {
+    /// Configuration du logo, insérée comme ressource au démarrage
+    pub config: LogoConfig,
+}
+
+impl LogoPlugin {
+    /// Crée le plugin avec une configuration donnée
+    pub fn new(config: LogoConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Plugin for LogoPlugin {
+    fn build(&self, app: &mut App) {
+        // === VALIDATION ===
+        // Une configuration incohérente ferait paniquer (ou épuiser la
+        // mémoire de) la création du logo : mieux vaut un message clair
+        if let Err(error) = self.config.validate() {
+            panic!("LogoPlugin : configuration invalide : {}", error);
+        }
+        
+        // === PLUGINS DE RENDU ===
+        if !app.is_plugin_added::<GlowPlugin>() {
+            app.add_plugins(GlowPlugin);
+        }
+        
+        app
+            // === RESSOURCES ===
+            // Configuration du logo, lue par les systèmes de création
+            .insert_resource(self.config.clone())
+            .init_resource::<ExplodeState>()
+            .init_resource::<AnimationState>()
+            .init_resource::<LogoStats>()
+            .init_resource::<CurrentTheme>()
+            // Lue dans LogoConfig : doit être initialisée après elle
+            .init_resource::<GlobalOpacity>()
+            
+            // === ÉVÉNEMENTS ===
+            .add_event::<RegenerateLogo>()
+            .add_event::<RecolorEvent>()
+            
+            // === SYSTÈMES DE DÉMARRAGE ===
+            // Startup : systèmes exécutés une seule fois au lancement
+            // Notre système setup_system crée tous les éléments visuels
+            .add_systems(Startup, (setup_system, spawn_background_image_system, start_recording_system))
+            
+            // === SYSTÈMES DE MISE À JOUR ===
+            // Update : systèmes exécutés à chaque frame
+            // L'envoi de l'événement est ordonné avant sa lecture
+            .add_systems(Update, (
+                config_morph_system.run_if(resource_exists::<ConfigMorph>),
+                regenerate_logo_system,
+            ).chain())
+            .add_systems(Update, fit_logo_to_window_system.after(regenerate_logo_system))
+            .add_systems(Update, spawn_drop_shadows_system.after(regenerate_logo_system))
+            .add_systems(Update, update_logo_stats_system.after(spawn_drop_shadows_system))
+            .add_systems(Update, apply_glow_system.after(regenerate_logo_system))
+            .add_systems(Update, reapply_theme_system.after(regenerate_logo_system))
+            .add_systems(Update, (
+                global_opacity_system,
+                (intro_fade_system, explode_r_system, ring_hue_cycle_system),
+            ).chain())
+            .add_systems(Update, recolor_system)
+            .add_systems(Update, background_load_failed_system)
+            // Après tous les déplacements de la caméra de la frame
+            .add_systems(PostUpdate, fit_background_to_view_system.before(TransformSystem::TransformPropagate))
+            .add_systems(Update, (
+                despawn_selection_outline_system,
+                spawn_selection_outline_system,
+                marching_ants_system.run_if(any_with_component::<Selected>),
+            ).chain())
+            .add_systems(Update, record_frame_system.run_if(resource_exists::<FrameRecorder>));
+    }
+}
+
+/// Commandes clavier et souris du logo (optionnel, ajouté par `run()`)
+/// 
+/// À ajouter après `LogoPlugin`. Regroupe tout ce qui lit les entrées ou
+/// déplace la caméra, et peut donc entrer en conflit avec l'application hôte :
+/// - G : régénération ; +/- : nombre de triangles extérieurs
+/// - Espace, [ et ] : pause et vitesse des animations ; X : éclatement
+/// - Q/E ou glisser avec le bouton droit : rotation du logo
+/// - T : thème de couleurs suivant
+/// - clic gauche sur une partie du "R" : sélection ; Échap : désélection
+/// - caméra `MainCamera` : glisser avec le bouton gauche (avec inertie),
+///   molette, F pour recadrer, limitation à la zone du logo, Ctrl+S pour
+///   l'enregistrer (`CameraState`) et restauration au lancement
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogoInteractionPlugin;
+
+impl Plugin for LogoInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // === RESSOURCES ===
+            .init_resource::<DragState>()
+            .init_resource::<CameraFit>()
+            .init_resource::<RotateDragState>()
+            
+            // === SYSTÈMES DE DÉMARRAGE ===
+            .add_systems(Startup, load_camera_state_system.after(setup_system))
+            
+            // === SYSTÈMES DE MISE À JOUR ===
+            .add_systems(Update, (
+                regenerate_on_key_system,
+                adjust_exterior_count_system,
+            ).before(regenerate_logo_system))
+            .add_systems(Update, (animation_controls_system, toggle_explode_system).before(global_opacity_system))
+            .add_systems(Update, (manual_rotation_system, drag_rotation_system))
+            .add_systems(Update, cycle_theme_system)
+            .add_systems(Update, (select_part_on_click_system, clear_selection_system).before(despawn_selection_outline_system))
+            .add_systems(Update, (
+                camera_zoom_system,
+                camera_drag_system,
+                camera_inertia_system,
+                fit_camera_on_key_system,
+                camera_fit_animation_system,
+                clamp_camera_system,
+            ).chain())
+            .add_systems(Update, save_camera_state_system);
+    }
+}
+
+/// Outils de débogage du logo (optionnel, ajouté par `run()`)
+/// 
+/// À ajouter après `LogoPlugin` :
+/// - W : mode fil de fer, si le périphérique de rendu offre
+///   `POLYGON_MODE_LINE` (voir `WireframeSupport`) ; la fonctionnalité
+///   n'est jamais exigée au démarrage
+/// - F4 : gizmos de débogage ; F5 : repères de centroïdes
+/// - flèches : inspection des parties du "R" (`InspectedPart`)
+/// - M puis deux clics : règle de mesure (`Ruler`)
+/// 
+/// `Wireframe2dPlugin` n'est ajouté que s'il ne l'est pas déjà.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogoDebugPlugin;
+
+impl Plugin for LogoDebugPlugin {
+    fn build(&self, app: &mut App) {
+        // === PLUGINS DE RENDU ===
+        if !app.is_plugin_added::<Wireframe2dPlugin>() {
+            app.add_plugins(Wireframe2dPlugin::default());
+        }
+        
+        app
+            // === RESSOURCES ===
+            .init_resource::<InspectedPart>()
+            .init_resource::<Ruler>()
+            .init_resource::<WireframeSupport>()
+            
+            // === SYSTÈMES DE MISE À JOUR ===
+            .add_systems(Update, toggle_centroid_markers_system.before(regenerate_logo_system))
+            .add_systems(Update, (
+                toggle_wireframe_system,
+                sync_wireframe_system.run_if(resource_changed::<LogoConfig>),
+            ).chain())
+            .add_systems(Update, (toggle_debug_gizmos_system, debug_gizmos_system).chain())
+            .add_systems(Update, inspect_part_system)
+            .add_systems(Update, (
+                toggle_ruler_system,
+                ruler_click_system,
+                ruler_label_system.run_if(resource_changed::<Ruler>),
+                ruler_gizmos_system,
+            ).chain());
+    }
+    
+    fn finish(&self, app: &mut App) {
+        // Le périphérique de rendu existe une fois RenderPlugin terminé
+        let available = app
+            .world()
+            .get_resource::<RenderDevice>()
+            .is_some_and(|device| device.features().contains(WgpuFeatures::POLYGON_MODE_LINE));
+        app.insert_resource(WireframeSupport { available });
+    }
+}
 
 /// Fonction principale qui configure et lance l'application Bevy
 /// 
 /// Cette fonction crée une nouvelle application Bevy avec :
 /// - Les plugins par défaut (fenêtre, rendu, input, etc.)
+/// - Le plugin de rendu fil de fer 2D (désactivé tant que `wireframe` est
+///   faux, ou si l'adaptateur graphique n'offre pas `POLYGON_MODE_LINE`)
+/// - La ressource `LogoConfig` (valeurs par défaut du module config),
+///   dont `msaa_samples` règle l'anticrénelage de la caméra
 /// - Le système de setup qui s'exécute au démarrage
+/// - Les systèmes de régénération (touche G ou événement `RegenerateLogo`,
+///   touches +/- pour le nombre de triangles extérieurs, transition
+///   `ConfigMorph` entre deux configurations)
+/// - L'ajustement du logo à la taille de la fenêtre
+/// - Les ombres portées (si `drop_shadow` est actif)
+/// - La variante lumineuse (si `glow` est actif, voir `GlowPlugin`)
+/// - Le calcul du coût de rendu (`LogoStats`), affiché dans la console
+/// - Les animations (fondu d'introduction si `intro_animation` est actif,
+///   éclatement du "R" avec la touche X), contrôlées par `AnimationState`
+///   (Espace : pause, [ et ] : vitesse)
+/// - La rotation manuelle du logo (Q : antihoraire, E : horaire, ou
+///   glisser avec le bouton droit, voir `RotateDragState`)
+/// - La rotation de teinte de l'anneau (si `ring_hue_cycle_speed` > 0)
+/// - Le changement de thème de couleurs (touche T, voir `Theme`) et le
+///   recoloriage d'une couche par l'événement `RecolorEvent`
+/// - L'image de fond optionnelle (`background_image`)
+/// - La sélection des parties du "R" (clic gauche, Échap pour tout
+///   désélectionner) et leur contour animé (composant `Selected`)
+/// - L'inspection des parties du "R" une par une (flèches, voir `InspectedPart`)
+/// - Les repères de centroïdes des triangles (touche F5, `show_centroids`)
+/// - La règle de mesure (touche M puis deux clics, voir `Ruler`)
+/// - L'opacité globale du logo (`GlobalOpacity`, initialisée depuis
+///   `LogoConfig::global_opacity`)
+/// - Le déplacement de la caméra (glisser avec le bouton gauche, avec inertie,
+///   zoom à la molette, recadrage sur le logo avec F), limité à la zone du logo
+/// - L'enregistrement de la caméra (Ctrl+S, `CameraState`), restaurée au
+///   lancement suivant
 /// 
 /// L'application tourne en boucle jusqu'à ce que l'utilisateur ferme la fenêtre.
 pub fn run() {
+    run_with_config(LogoConfig::default());
+}
+
+/// Lance l'application avec une configuration personnalisée
+/// 
+/// Identique à `run()`, avec `config` à la place des valeurs par défaut.
+/// Le logo est ajouté par `LogoPlugin`, `LogoInteractionPlugin` et
+/// `LogoDebugPlugin`, après les plugins par défaut.
+/// Si `config.record_frames` est défini, l'animation est enregistrée en
+/// séquence d'images PNG (voir `RecordSpec`) puis l'application se ferme.
+/// 
+/// # Exemple
+/// ```ignore
+/// run_with_config(LogoConfig {
+///     record_frames: Some(RecordSpec {
+///         fps: 30,
+///         duration: 2.0,
+///         output_dir: "frames".into(),
+///     }),
+///     ..Default::default()
+/// });
+/// ```
+pub fn run_with_config(config: LogoConfig) {
     App::new()
         // === PLUGINS BEVY ===
         // DefaultPlugins inclut tous les systèmes essentiels :
@@ -41,12 +409,21 @@ pub fn run() {
         // - InputPlugin : clavier, souris, gamepad
         // - AssetPlugin : chargement des assets
         // - et bien d'autres...
-        .add_plugins(DefaultPlugins)
-        
-        // === SYSTÈMES DE DÉMARRAGE ===
-        // Startup : systèmes exécutés une seule fois au lancement
-        // Notre système setup_system crée tous les éléments visuels
-        .add_systems(Startup, setup_system)
+        // Les fonctionnalités offertes par l'adaptateur (dont POLYGON_MODE_LINE,
+        // pour le fil de fer) sont activées d'office ; POLYGON_MODE_LINE n'est
+        // exigée que si le fil de fer est demandé dès le lancement
+        .add_plugins((
+            DefaultPlugins.set(RenderPlugin {
+                render_creation: RenderCreation::Automatic(WgpuSettings {
+                    features: required_features(&config),
+                    ..default()
+                }),
+                ..default()
+            }),
+            LogoPlugin::new(config),
+            LogoInteractionPlugin,
+            LogoDebugPlugin,
+        ))
         
         // === LANCEMENT ===
         // Démarre la boucle de jeu (game loop)
@@ -54,4 +431,111 @@ pub fn run() {
         .run();
 }
 
+/// Fonctionnalités GPU indispensables à `config`
+/// 
+/// `POLYGON_MODE_LINE` si `wireframe` est actif au lancement, aucune sinon :
+/// le logo s'affiche alors sur tout adaptateur (WebGL2, mobile, rendu logiciel).
+pub fn required_features(config: &LogoConfig) -> WgpuFeatures {
+    if config.wireframe {
+        WgpuFeatures::POLYGON_MODE_LINE
+    } else {
+        WgpuFeatures::empty()
+    }
+}
+
+/// Affiche la description de la scène en JSON, sans ouvrir de fenêtre
+/// 
+/// Utilise la configuration par défaut (voir `describe_scene`). La sortie
+/// standard ne contient que le JSON : elle peut être redirigée vers un
+/// fichier pour être comparée entre deux versions.
+/// 
+/// # Utilisation
+/// ```text
+/// cargo run -- describe > scene.json
+/// ```
+pub fn describe() {
+    let description = describe_scene(&LogoConfig::default());
+    let json = serde_json::to_string_pretty(&description).expect("La description est sérialisable");
+    println!("{json}");
+}
+
+/// Génère le logo dans une image PNG, sans ouvrir de fenêtre
+/// 
+/// Destinée à l'intégration continue (aucun serveur d'affichage requis) et
+/// aux comparaisons d'images de non-régression. Le déroulement est :
+/// 1. `setup_system` crée la scène, exactement comme dans `run()`
+/// 2. la caméra est redirigée vers une image hors écran de
+///    `HEADLESS_IMAGE_SIZE` pixels de côté, le logo y est ajusté
+/// 3. après `HEADLESS_WARMUP_FRAMES` frames, l'image est capturée
+///    et écrite dans `output_path`, puis l'application se termine
+/// 
+/// # Arguments
+/// * `config` - Configuration du logo à générer
+/// * `output_path` - Chemin du fichier PNG à écrire
+/// 
+/// # Fonctionnalités Bevy requises
+/// - `bevy_render`, `bevy_core_pipeline`, `bevy_sprite` : rendu 2D
+/// - `png` : encodage de l'image
+/// - un adaptateur GPU (un rendu logiciel type lavapipe/llvmpipe suffit)
+/// 
+/// Le plugin `WinitPlugin` est désactivé et aucune fenêtre n'est créée ;
+/// la boucle est cadencée par `ScheduleRunnerPlugin` (60 frames/s).
+/// L'animation d'introduction est désactivée pour capturer le logo complet.
+/// Avec `LogoConfig::transparent_export`, le fond du PNG est transparent.
+/// 
+/// # Erreurs
+/// - `io::ErrorKind::InvalidInput` si `LogoConfig::validate` refuse la
+///   configuration (comme `LogoPlugin`, avant toute création)
+/// - une erreur si l'image n'a pas pu être capturée ou écrite
+pub fn run_headless(config: LogoConfig, output_path: impl AsRef<Path>) -> io::Result<()> {
+    // === VALIDATION ===
+    if let Err(error) = config.validate() {
+        println!("⚠️ Rendu sans fenêtre : configuration invalide : {}", error);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("configuration invalide : {}", error)));
+    }
+    
+    let outcome = Arc::new(Mutex::new(None));
+    let capture = HeadlessCapture::new(output_path.as_ref().to_path_buf(), outcome.clone());
+    
+    App::new()
+        // === PLUGINS BEVY SANS FENÊTRE ===
+        .add_plugins((
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .disable::<WinitPlugin>(),
+            ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0)),
+            GlowPlugin,
+        ))
+        
+        // === RESSOURCES ===
+        .insert_resource(LogoConfig {
+            intro_animation: false,
+            ..config
+        })
+        .insert_resource(capture)
+        .init_resource::<GlobalOpacity>()
+        
+        // === SYSTÈMES ===
+        // La racine du logo doit exister avant l'ajustement à l'image
+        .add_systems(Startup, (setup_system, prepare_headless_target_system).chain())
+        .add_systems(Update, (
+            spawn_drop_shadows_system,
+            global_opacity_system,
+            apply_glow_system,
+            capture_headless_frame_system,
+        ))
+        .run();
+    
+    // === RÉSULTAT ===
+    outcome
+        .lock()
+        .unwrap()
+        .take()
+        .unwrap_or_else(|| Err(io::Error::other("application terminée sans capture")))
+}
+
 
@@ -5,15 +5,24 @@
 // ║  Rôle : Lancer l'application Bevy configurée dans lib.rs                ║
 // ╚══════════════════════════════════════════════════════════════════════════╝
 
-// Importe la fonction run() depuis le module library
-use LogoRust_Bevy_20250929::run;
+// Importe les points d'entrée depuis le module library
+use LogoRust_Bevy_20250929::{describe, run};
 
 /// Point d'entrée principal de l'application
 /// 
 /// Cette fonction est appelée automatiquement au lancement du programme.
 /// Elle délègue toute la logique à la fonction run() définie dans lib.rs
 /// pour maintenir une séparation claire entre le point d'entrée et la logique.
+/// 
+/// # Commandes
+/// - sans argument : ouvre la fenêtre du logo
+/// - `describe` : affiche la scène en JSON puis quitte (voir `describe()`)
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("describe") {
+        describe();
+        return;
+    }
+    
     // Lancement de l'application Bevy
     run();
 }
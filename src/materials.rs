@@ -13,6 +13,8 @@
 //! - Réutilisation des couleurs
 
 use bevy::prelude::*;
+use bevy::sprite::AlphaMode2d;
+use crate::config::LogoConfig;
 
 /// Retourne le matériau pour le cercle principal (anneau épais)
 /// 
@@ -24,7 +26,38 @@ use bevy::prelude::*;
 /// - Bleu : (0.2, 0.4, 0.8)
 /// - Vert : (0.2, 0.8, 0.3)
 pub fn get_main_circle_color() -> ColorMaterial {
-    ColorMaterial::from(Color::srgb(0.8, 0.2, 0.1))
+    get_main_circle_color_with_alpha(1.0)
+}
+
+/// Retourne le matériau du cercle principal avec une opacité donnée
+/// 
+/// Même couleur que `get_main_circle_color`, avec `alpha` (borné à [0, 1]).
+/// En dessous de 1.0, le matériau est en mode `Blend` : le fond se voit
+/// à travers l'anneau.
+/// 
+/// # Ordre de dessin
+/// Bevy trie les éléments transparents 2D de l'arrière vers l'avant selon
+/// leur Z. L'anneau (Z = 0.0) est derrière tous les autres éléments du
+/// logo : il est dessiné en premier et les triangles se mélangent par-dessus.
+/// De même, les parties de la lettre (alpha 0.8) ont chacune un Z distinct
+/// (`r_base_z` + i × `r_z_step`, collisions signalées au démarrage) :
+/// leurs chevauchements sont mélangés dans l'ordre de la liste des parties.
+pub fn get_main_circle_color_with_alpha(alpha: f32) -> ColorMaterial {
+    ColorMaterial::from(Color::srgba(0.8, 0.2, 0.1, alpha.clamp(0.0, 1.0)))
+}
+
+/// Modifie l'opacité d'un matériau en gardant un mode de rendu cohérent
+/// 
+/// Bevy choisit le mode `Opaque` pour un matériau créé avec alpha = 1.0 :
+/// son alpha est alors ignoré. Passer en mode `Blend` dès que l'alpha
+/// descend sous 1.0 rend la transparence visible (fondus, opacité globale).
+pub fn set_material_alpha(material: &mut ColorMaterial, alpha: f32) {
+    material.color.set_alpha(alpha);
+    material.alpha_mode = if alpha < 1.0 {
+        AlphaMode2d::Blend
+    } else {
+        AlphaMode2d::Opaque
+    };
 }
 
 /// Génère une couleur arc-en-ciel basée sur l'index du triangle
@@ -37,17 +70,49 @@ pub fn get_main_circle_color() -> ColorMaterial {
 /// # Arguments
 /// * `index` - Position du triangle (0 à EXTERIOR_TRIANGLES_COUNT-1)
 /// 
-/// # Exemples de couleurs générées
+/// # Exemples de couleurs générées (36 triangles par défaut)
 /// - Index 0 (0°) : Rouge
 /// - Index 9 (90°) : Jaune-vert
 /// - Index 18 (180°) : Cyan
 /// - Index 27 (270°) : Violet
 pub fn get_rainbow_color(index: usize) -> ColorMaterial {
-    // Calcul de l'angle de teinte : chaque triangle décale de 10°
-    let hue_fraction = (index as f32 * 10.0) / 360.0;
+    get_oriented_rainbow_color(index, crate::config::EXTERIOR_TRIANGLES_COUNT, 0.0, false)
+}
+
+/// Calcule la teinte (0-360°) d'un triangle arc-en-ciel
+/// 
+/// Les triangles se partagent le cercle chromatique complet, quel que soit
+/// leur nombre : avec 12 triangles comme avec 36, l'arc-en-ciel fait un tour.
+/// 
+/// # Arguments
+/// * `index` - Position du triangle (0 à count-1)
+/// * `count` - Nombre de triangles de l'anneau (0 est traité comme 1)
+/// * `start_hue` - Teinte du triangle d'index 0, en degrés
+/// * `clockwise` - Si vrai, la teinte décroît avec l'index (sens horaire à l'écran)
+/// 
+/// # Formule
+/// teinte = start_hue ± index / count × 360°, ramenée dans [0, 360)
+pub fn rainbow_hue(index: usize, count: usize, start_hue: f32, clockwise: bool) -> f32 {
+    // Écart de teinte constant entre deux triangles voisins
+    let offset = index as f32 / count.max(1) as f32 * 360.0;
+    let direction = if clockwise { -1.0 } else { 1.0 };
     
-    // Conversion en angle complet (0-360°)
-    let hue_degrees = hue_fraction * 360.0;
+    (start_hue + direction * offset).rem_euclid(360.0)
+}
+
+/// Génère une couleur arc-en-ciel avec teinte de départ et sens configurables
+/// 
+/// Même principe que `get_rainbow_color` (S = 0.8, L = 0.6), mais la teinte
+/// est calculée par `rainbow_hue` : utile pour aligner l'arc-en-ciel sur
+/// une couleur de marque ou inverser le sens de parcours.
+/// 
+/// # Arguments
+/// * `index` - Position du triangle
+/// * `count` - Nombre de triangles de l'anneau
+/// * `start_hue` - Teinte du premier triangle (degrés)
+/// * `clockwise` - Sens de parcours de l'arc-en-ciel
+pub fn get_oriented_rainbow_color(index: usize, count: usize, start_hue: f32, clockwise: bool) -> ColorMaterial {
+    let hue_degrees = rainbow_hue(index, count, start_hue, clockwise);
     
     // Création de la couleur HSL
     let color = Color::hsl(
@@ -59,10 +124,55 @@ pub fn get_rainbow_color(index: usize) -> ColorMaterial {
     ColorMaterial::from(color)
 }
 
-/// Retourne la couleur d'un triangle intérieur selon son index
+/// Crée une couleur à partir de ses composantes HSV
 /// 
-/// Palette de 5 couleurs distinctes qui se répètent si nécessaire.
-/// Chaque couleur est choisie pour être bien distincte des autres.
+/// HSV (Teinte, Saturation, Valeur) est plus pratique que HSL pour
+/// les dégradés : à valeur fixe, toutes les teintes ont la même intensité.
+/// 
+/// # Arguments
+/// * `hue` - Teinte en degrés (ramenée dans [0, 360))
+/// * `saturation` - Saturation de 0.0 (gris) à 1.0 (couleur pure)
+/// * `value` - Valeur de 0.0 (noir) à 1.0 (pleine intensité)
+pub fn color_from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+    Color::hsv(hue.rem_euclid(360.0), saturation, value)
+}
+
+/// Décale la teinte d'une couleur dans l'espace HSV
+/// 
+/// Saturation, valeur et opacité sont conservées ; la nouvelle couleur
+/// est reconstruite avec `color_from_hsv`.
+/// 
+/// # Arguments
+/// * `color` - Couleur de départ
+/// * `degrees` - Décalage de teinte en degrés (positif ou négatif)
+fn shift_hue(color: Color, degrees: f32) -> Color {
+    let hsva = Hsva::from(color);
+    color_from_hsv(hsva.hue + degrees, hsva.saturation, hsva.value).with_alpha(hsva.alpha)
+}
+
+/// Retourne la couleur complémentaire (teinte opposée, +180°)
+/// 
+/// Exemple : le rouge-orangé du cercle principal donne un bleu-cyan,
+/// utilisable comme couleur d'accent pour la lettre.
+pub fn complementary(color: Color) -> Color {
+    shift_hue(color, 180.0)
+}
+
+/// Retourne les deux couleurs analogues (voisines sur le cercle chromatique)
+/// 
+/// # Arguments
+/// * `color` - Couleur de départ
+/// * `spread_degrees` - Écart de teinte (30° est un choix classique)
+/// 
+/// # Retour
+/// (teinte - écart, teinte + écart)
+pub fn analogous(color: Color, spread_degrees: f32) -> (Color, Color) {
+    (shift_hue(color, -spread_degrees), shift_hue(color, spread_degrees))
+}
+
+/// Palette des triangles intérieurs
+/// 
+/// 5 couleurs distinctes, choisies pour être bien différentes les unes des autres.
 /// 
 /// # Palette actuelle
 /// 0. Bleu azur : pour le calme et la confiance
@@ -70,22 +180,110 @@ pub fn get_rainbow_color(index: usize) -> ColorMaterial {
 /// 2. Vert émeraude : pour la nature et l'harmonie
 /// 3. Rose fuchsia : pour l'originalité
 /// 4. Violet : pour la sophistication
+pub const INTERIOR_PALETTE: [Color; 5] = [
+    Color::srgb(0.2, 0.6, 0.9), // Bleu azur
+    Color::srgb(0.9, 0.6, 0.2), // Orange
+    Color::srgb(0.2, 0.9, 0.6), // Vert émeraude
+    Color::srgb(0.9, 0.2, 0.6), // Rose fuchsia
+    Color::srgb(0.6, 0.2, 0.9), // Violet
+];
+
+/// Retourne la couleur d'un triangle intérieur selon son index
+/// 
+/// Les couleurs de `INTERIOR_PALETTE` se répètent si nécessaire.
 /// 
 /// # Arguments
 /// * `index` - Position du triangle (0 à INTERIOR_TRIANGLES_COUNT-1)
 pub fn get_interior_triangle_color(index: usize) -> ColorMaterial {
-    // Définition de la palette de couleurs
-    let colors = [
-        Color::srgb(0.2, 0.6, 0.9), // Bleu azur
-        Color::srgb(0.9, 0.6, 0.2), // Orange
-        Color::srgb(0.2, 0.9, 0.6), // Vert émeraude
-        Color::srgb(0.9, 0.2, 0.6), // Rose fuchsia
-        Color::srgb(0.6, 0.2, 0.9), // Violet
-    ];
-    
     // Utilisation du modulo pour gérer les index supérieurs à 5
     // Exemple : index 7 → 7 % 5 = 2 → couleur verte
-    ColorMaterial::from(colors[index % colors.len()])
+    ColorMaterial::from(INTERIOR_PALETTE[index % INTERIOR_PALETTE.len()])
+}
+
+/// Retourne les couleurs de `count` triangles intérieurs
+/// 
+/// La palette s'adapte à n'importe quel nombre de triangles :
+/// - count ≤ 5 : les premières couleurs de la palette
+/// - count > 5 : la palette se répète (index 5 → bleu azur, etc.)
+/// 
+/// # Arguments
+/// * `count` - Nombre de triangles intérieurs
+pub fn interior_colors(count: usize) -> Vec<Color> {
+    (0..count)
+        .map(|i| INTERIOR_PALETTE[i % INTERIOR_PALETTE.len()])
+        .collect()
+}
+
+/// Retourne les couleurs de `count` triangles intérieurs en dégradé circulaire
+/// 
+/// Le triangle d'index i reçoit la teinte `start_hue + i / count × 360°`
+/// (`color_from_hsv`, saturation 0.7, valeur 0.9) : deux triangles voisins
+/// ont des teintes voisines et le dernier rejoint le premier.
+/// 
+/// # Arguments
+/// * `count` - Nombre de triangles intérieurs
+/// * `start_hue` - Teinte du triangle d'index 0, en degrés
+pub fn interior_gradient_colors(count: usize, start_hue: f32) -> Vec<Color> {
+    (0..count)
+        .map(|i| color_from_hsv(start_hue + i as f32 / count as f32 * 360.0, 0.7, 0.9))
+        .collect()
+}
+
+/// Angle d'or en degrés : 360° × (1 - 1/φ)
+/// 
+/// Des teintes espacées de cet angle ne se chevauchent jamais et restent
+/// bien réparties sur le cercle chromatique, quel que soit leur nombre.
+const GOLDEN_ANGLE_DEGREES: f32 = 137.507_77;
+
+/// Générateur pseudo-aléatoire SplitMix64
+/// 
+/// Minuscule et déterministe : une même graine donne toujours la même
+/// suite de nombres, sur toutes les plateformes.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Nombre suivant de la suite
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    
+    /// Nombre flottant uniforme dans [0, 1)
+    fn next_f32(&mut self) -> f32 {
+        // 24 bits de poids fort : la précision d'un f32
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+/// Génère une palette aléatoire reproductible de `count` couleurs
+/// 
+/// Plutôt que des composantes RVB tirées au hasard (souvent ternes ou
+/// trop proches), les teintes avancent de l'angle d'or à partir d'une
+/// teinte de départ aléatoire. Saturation et valeur varient légèrement
+/// pour que les couleurs restent vives.
+/// 
+/// # Arguments
+/// * `count` - Nombre de couleurs
+/// * `seed` - Graine du générateur : la même graine donne la même palette
+/// 
+/// # Plages
+/// - Saturation : 0.55 à 0.85
+/// - Valeur : 0.75 à 0.95
+pub fn random_palette(count: usize, seed: u64) -> Vec<Color> {
+    let mut rng = SplitMix64(seed);
+    let start_hue = rng.next_f32() * 360.0;
+    
+    (0..count)
+        .map(|i| {
+            let hue = start_hue + i as f32 * GOLDEN_ANGLE_DEGREES;
+            let saturation = 0.55 + 0.3 * rng.next_f32();
+            let value = 0.75 + 0.2 * rng.next_f32();
+            color_from_hsv(hue, saturation, value)
+        })
+        .collect()
 }
 
 /// Retourne le matériau pour les petits cercles centraux
@@ -102,3 +300,115 @@ pub fn get_small_circle_color() -> ColorMaterial {
     ColorMaterial::from(Color::srgba(1.0, 1.0, 1.0, 0.7))
 }
 
+
+// ═══════════════════════════════════════════════════════════════════════════
+//                              THÈMES DE COULEURS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Thème de couleurs du logo (anneau, triangles intérieurs, lettre)
+/// 
+/// `Classic` reproduit exactement les couleurs d'origine
+/// (`get_main_circle_color`, `get_interior_triangle_color`, `LETTER_COLOR`).
+/// Les triangles extérieurs gardent toujours l'arc-en-ciel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Theme {
+    /// Couleurs d'origine : anneau rouge-orangé, palette vive, lettre orange
+    #[default]
+    Classic,
+    
+    /// Bleus et turquoises
+    Ocean,
+    
+    /// Orangés, roses et violets
+    Sunset,
+    
+    /// Niveaux de gris
+    Monochrome,
+}
+
+/// Palette des triangles intérieurs du thème `Ocean`
+const OCEAN_PALETTE: [Color; 5] = [
+    Color::srgb(0.0, 0.45, 0.7),  // Bleu profond
+    Color::srgb(0.0, 0.7, 0.75),  // Turquoise
+    Color::srgb(0.3, 0.85, 0.9),  // Cyan clair
+    Color::srgb(0.1, 0.3, 0.55),  // Bleu nuit
+    Color::srgb(0.5, 0.8, 0.7),   // Vert d'eau
+];
+
+/// Palette des triangles intérieurs du thème `Sunset`
+const SUNSET_PALETTE: [Color; 5] = [
+    Color::srgb(1.0, 0.75, 0.2),  // Or
+    Color::srgb(1.0, 0.45, 0.35), // Corail
+    Color::srgb(0.9, 0.25, 0.5),  // Framboise
+    Color::srgb(0.55, 0.2, 0.6),  // Prune
+    Color::srgb(1.0, 0.55, 0.1),  // Mandarine
+];
+
+/// Palette des triangles intérieurs du thème `Monochrome`
+const MONOCHROME_PALETTE: [Color; 5] = [
+    Color::srgb(0.85, 0.85, 0.85),
+    Color::srgb(0.65, 0.65, 0.65),
+    Color::srgb(0.5, 0.5, 0.5),
+    Color::srgb(0.35, 0.35, 0.35),
+    Color::srgb(0.75, 0.75, 0.75),
+];
+
+impl Theme {
+    /// Tous les thèmes, dans l'ordre de `next`
+    pub const ALL: [Theme; 4] = [Theme::Classic, Theme::Ocean, Theme::Sunset, Theme::Monochrome];
+    
+    /// Thème suivant (le dernier revient au premier)
+    pub fn next(self) -> Theme {
+        let index = Theme::ALL.iter().position(|theme| *theme == self).unwrap_or(0);
+        Theme::ALL[(index + 1) % Theme::ALL.len()]
+    }
+    
+    /// Nom du thème, pour les messages de la console
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Classic => "Classique",
+            Theme::Ocean => "Océan",
+            Theme::Sunset => "Coucher de soleil",
+            Theme::Monochrome => "Monochrome",
+        }
+    }
+    
+    /// Couleur de l'anneau principal (opaque)
+    pub fn ring_color(self) -> Color {
+        match self {
+            Theme::Classic => get_main_circle_color().color,
+            Theme::Ocean => Color::srgb(0.05, 0.3, 0.6),
+            Theme::Sunset => Color::srgb(0.85, 0.3, 0.2),
+            Theme::Monochrome => Color::srgb(0.25, 0.25, 0.25),
+        }
+    }
+    
+    /// Couleur du triangle intérieur d'index `index` (palette répétée)
+    /// 
+    /// `Classic` reprend les couleurs du setup pour `config`
+    /// (`build::interior_triangle_colors` : dégradé, palette aléatoire).
+    pub fn interior_color(self, config: &LogoConfig, index: usize) -> Color {
+        let palette = match self {
+            Theme::Classic => {
+                return crate::build::interior_triangle_colors(config)
+                    .get(index)
+                    .copied()
+                    .unwrap_or_else(|| get_interior_triangle_color(index).color);
+            }
+            Theme::Ocean => &OCEAN_PALETTE,
+            Theme::Sunset => &SUNSET_PALETTE,
+            Theme::Monochrome => &MONOCHROME_PALETTE,
+        };
+        palette[index % palette.len()]
+    }
+    
+    /// Couleur de remplissage de la lettre (alpha 0.8, comme `LETTER_COLOR`)
+    pub fn letter_color(self) -> Color {
+        match self {
+            Theme::Classic => crate::build::LETTER_COLOR,
+            Theme::Ocean => Color::srgba(0.85, 0.95, 1.0, 0.8),
+            Theme::Sunset => Color::srgba(1.0, 0.9, 0.4, 0.8),
+            Theme::Monochrome => Color::srgba(0.95, 0.95, 0.95, 0.8),
+        }
+    }
+}
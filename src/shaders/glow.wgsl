@@ -0,0 +1,28 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                     FICHIER: src/shaders/glow.wgsl                       ║
+// ║  Shader du matériau lumineux (GlowMaterial)                              ║
+// ║  Rôle : Émettre une couleur au-delà de 1.0 pour déclencher le bloom     ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+// Même disposition que les champs #[uniform(0)] de GlowMaterial
+struct GlowMaterial {
+    color: vec4<f32>,
+    emissive_strength: f32,
+};
+
+@group(2) @binding(0) var<uniform> material: GlowMaterial;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    var color = material.color;
+
+    // Couleurs par vertex (anneau arc-en-ciel en dégradé)
+#ifdef VERTEX_COLORS
+    color = color * mesh.color;
+#endif
+
+    // Intensité multipliée : en HDR, les valeurs > 1.0 "débordent" (bloom)
+    return vec4<f32>(color.rgb * material.emissive_strength, color.a);
+}
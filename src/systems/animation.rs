@@ -0,0 +1,376 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: src/systems/animation.rs                       ║
+// ║  Systèmes d'animation du logo                                            ║
+// ║  Rôle : Faire évoluer l'apparence des éléments au fil du temps          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module animation
+//! 
+//! Toutes les animations utilisent la ressource `Time` de Bevy :
+//! elles progressent selon le temps réel écoulé et non selon le nombre
+//! de frames, ce qui les rend indépendantes de la fréquence d'affichage.
+//! 
+//! La ressource `AnimationState` les contrôle toutes à la fois :
+//! pause (Espace) et vitesse globale ([ pour ralentir, ] pour accélérer).
+//! 
+//! La ressource `GlobalOpacity` règle la transparence du logo entier,
+//! indépendamment du fondu d'introduction.
+//! 
+//! Les touches Q et E font tourner le logo à la main (hors `AnimationState`).
+//! 
+//! L'anneau principal peut changer lentement de teinte (`ring_hue_cycle_speed`).
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use crate::components::{BaseAlpha, DropShadow, IntroFade, LogoElementFilter, LogoRoot, MainCircle, RPartHome};
+use crate::config::{self, LogoConfig};
+use crate::{geometry, materials};
+
+/// Contrôle global de toutes les animations
+/// 
+/// - `paused` : les animations sont figées (aucune progression)
+/// - `speed` : multiplicateur appliqué au temps écoulé (1.0 = normal)
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AnimationState {
+    /// Vrai si les animations sont en pause
+    pub paused: bool,
+    
+    /// Multiplicateur de vitesse (0.5 = deux fois plus lent)
+    pub speed: f32,
+}
+
+impl Default for AnimationState {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            speed: 1.0,
+        }
+    }
+}
+
+impl AnimationState {
+    /// Temps d'animation écoulé depuis la frame précédente, en secondes
+    /// 
+    /// Vaut `Time::delta_secs() × speed`, ou 0 en pause.
+    pub fn delta_secs(&self, time: &Time) -> f32 {
+        if self.paused {
+            0.0
+        } else {
+            time.delta_secs() * self.speed
+        }
+    }
+}
+
+/// Pause et vitesse des animations au clavier
+/// 
+/// - Espace : pause / reprise
+/// - [ : vitesse divisée par `ANIMATION_SPEED_FACTOR`
+/// - ] : vitesse multipliée par `ANIMATION_SPEED_FACTOR`
+/// 
+/// La vitesse est bornée à [ANIMATION_SPEED_MIN, ANIMATION_SPEED_MAX].
+pub fn animation_controls_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<AnimationState>,
+) {
+    // === PAUSE ===
+    if keys.just_pressed(KeyCode::Space) {
+        state.paused = !state.paused;
+        if state.paused {
+            println!("⏸️ Animations en pause");
+        } else {
+            println!("▶️ Animations reprises");
+        }
+    }
+    
+    // === VITESSE ===
+    let factor = if keys.just_pressed(KeyCode::BracketRight) {
+        config::ANIMATION_SPEED_FACTOR
+    } else if keys.just_pressed(KeyCode::BracketLeft) {
+        1.0 / config::ANIMATION_SPEED_FACTOR
+    } else {
+        return;
+    };
+    state.speed = (state.speed * factor).clamp(config::ANIMATION_SPEED_MIN, config::ANIMATION_SPEED_MAX);
+    println!("⏩ Vitesse des animations : ×{}", state.speed);
+}
+
+/// Éléments dont l'alpha d'origine n'est pas encore mémorisé
+type NewOpacityElement<'a> = (Entity, &'a MeshMaterial2d<ColorMaterial>, Has<IntroFade>);
+
+/// Éléments soumis à l'opacité globale : ceux du logo et leurs ombres
+/// 
+/// Les autres `Mesh2d` (formes de `draw`, outils de débogage, entités de
+/// l'application hôte) gardent leur alpha.
+type OpacityElementFilter = Or<(LogoElementFilter, With<DropShadow>)>;
+
+/// Éléments dont l'alpha d'origine est mémorisé
+type OpacityElement<'a> = (&'a MeshMaterial2d<ColorMaterial>, &'a BaseAlpha);
+
+/// Opacité globale du logo, de 0.0 (invisible) à 1.0 (opaque)
+/// 
+/// Multiplie l'alpha d'origine de chaque élément (voir `BaseAlpha`).
+/// Initialisée depuis `LogoConfig::global_opacity`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GlobalOpacity(pub f32);
+
+impl FromWorld for GlobalOpacity {
+    fn from_world(world: &mut World) -> Self {
+        let opacity = world
+            .get_resource::<LogoConfig>()
+            .map_or(1.0, |config| config.global_opacity);
+        Self(opacity)
+    }
+}
+
+impl GlobalOpacity {
+    /// Opacité bornée à [0, 1]
+    pub fn value(&self) -> f32 {
+        self.0.clamp(0.0, 1.0)
+    }
+}
+
+/// Applique l'opacité globale aux matériaux des éléments
+/// 
+/// 1. Nouveaux éléments : l'alpha d'origine du matériau est mémorisé
+///    dans `BaseAlpha`, puis l'opacité leur est appliquée
+/// 2. Si `GlobalOpacity` a changé : alpha = alpha_origine × opacité
+///    pour tous les éléments
+/// 
+/// Les éléments en cours de fondu d'introduction ne sont pas modifiés :
+/// `intro_fade_system` applique lui-même l'opacité. Ce système doit donc
+/// s'exécuter avant lui, pour lire l'alpha d'origine avant le fondu.
+/// 
+/// Seuls les éléments du logo et leurs ombres sont concernés
+/// (`OpacityElementFilter`). Les ombres partagent un même matériau :
+/// l'alpha d'origine est lu une seule fois par matériau.
+pub fn global_opacity_system(
+    mut commands: Commands,
+    opacity: Res<GlobalOpacity>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    new_elements: Query<NewOpacityElement, (Without<BaseAlpha>, OpacityElementFilter)>,
+    elements: Query<OpacityElement, (Without<IntroFade>, OpacityElementFilter)>,
+) {
+    let value = opacity.value();
+    
+    // === NOUVEAUX ÉLÉMENTS ===
+    let mut recorded = HashMap::new();
+    for (entity, material_handle, fading) in &new_elements {
+        let Some(material) = materials.get_mut(&material_handle.0) else {
+            continue;
+        };
+        let base_alpha = *recorded
+            .entry(material_handle.0.id())
+            .or_insert(material.color.alpha());
+        commands.entity(entity).insert(BaseAlpha(base_alpha));
+        
+        if !fading {
+            materials::set_material_alpha(material, base_alpha * value);
+        }
+    }
+    
+    // === CHANGEMENT D'OPACITÉ ===
+    if !opacity.is_changed() {
+        return;
+    }
+    for (material_handle, base_alpha) in &elements {
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            materials::set_material_alpha(material, base_alpha.0 * value);
+        }
+    }
+}
+
+/// Fait apparaître progressivement les éléments marqués `IntroFade`
+/// 
+/// Pour chaque élément :
+/// 1. Accumuler le temps écoulé
+/// 2. Calculer la progression t = (écoulé - délai) / durée, bornée à [0, 1]
+/// 3. Appliquer alpha = alpha_final × t × opacité globale au matériau
+/// 4. Retirer le composant une fois le fondu terminé
+/// 
+/// Les copies d'un logo pavé (`LogoConfig::tile`) partagent leurs
+/// matériaux : l'alpha final est mémorisé une seule fois par matériau,
+/// sinon les copies suivantes liraient un alpha déjà en cours de fondu.
+/// Le temps suit `AnimationState` (pause et vitesse).
+pub fn intro_fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    animation: Res<AnimationState>,
+    opacity: Res<GlobalOpacity>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut fades: Query<(Entity, &mut IntroFade, &MeshMaterial2d<ColorMaterial>)>,
+) {
+    if animation.paused {
+        return;
+    }
+    
+    // === ALPHAS FINAUX DÉJÀ MÉMORISÉS ===
+    // Un par matériau, partagé par les éléments qui l'utilisent
+    let mut recorded: HashMap<AssetId<ColorMaterial>, f32> = fades
+        .iter()
+        .filter_map(|(_, fade, material_handle)| Some((material_handle.0.id(), fade.target_alpha?)))
+        .collect();
+    
+    for (entity, mut fade, material_handle) in &mut fades {
+        let Some(material) = materials.get_mut(&material_handle.0) else {
+            continue;
+        };
+        
+        // === ALPHA FINAL ===
+        // Mémorisé au premier passage, avant toute modification
+        let target_alpha = *recorded
+            .entry(material_handle.0.id())
+            .or_insert(material.color.alpha());
+        fade.target_alpha = Some(target_alpha);
+        
+        // === PROGRESSION ===
+        fade.elapsed += animation.delta_secs(&time);
+        let progress = ((fade.elapsed - fade.delay) / config::INTRO_FADE_DURATION).clamp(0.0, 1.0);
+        
+        materials::set_material_alpha(material, target_alpha * progress * opacity.value());
+        
+        // === FIN DU FONDU ===
+        if progress >= 1.0 {
+            commands.entity(entity).remove::<IntroFade>();
+        }
+    }
+}
+
+/// État de l'éclatement du logo "R"
+/// 
+/// - `exploded` : cible demandée (vrai = parties écartées)
+/// - `progress` : avancement actuel, de 0.0 (assemblé) à 1.0 (éclaté)
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ExplodeState {
+    /// Vrai si le "R" doit être éclaté
+    pub exploded: bool,
+    
+    /// Avancement de l'animation (0.0 à 1.0)
+    pub progress: f32,
+}
+
+/// Inverse la cible de l'éclatement du "R" avec la touche X
+/// 
+/// L'animation elle-même est faite par `explode_r_system`.
+pub fn toggle_explode_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<ExplodeState>,
+) {
+    if keys.just_pressed(KeyCode::KeyX) {
+        state.exploded = !state.exploded;
+    }
+}
+
+/// Éclate ou réassemble les parties du "R" selon `ExplodeState`
+/// 
+/// L'avancement progresse vers la cible (`ExplodeState::exploded`, inversée
+/// par la touche X, voir `toggle_explode_system`) en `EXPLODE_DURATION`
+/// secondes, avec un lissage (smoothstep) pour un départ et une arrivée
+/// en douceur.
+/// 
+/// Chaque partie s'éloigne du centre le long de la direction stockée
+/// dans `RPartHome`. À l'avancement 0, le `Transform` d'origine est
+/// restauré exactement. Seules les parties du "R" sont concernées.
+/// En pause (`AnimationState`), la cible change mais les parties restent figées.
+pub fn explode_r_system(
+    time: Res<Time>,
+    animation: Res<AnimationState>,
+    mut state: ResMut<ExplodeState>,
+    mut parts: Query<(&mut Transform, &RPartHome)>,
+) {
+    // === AVANCEMENT VERS LA CIBLE ===
+    let target = if state.exploded { 1.0 } else { 0.0 };
+    if state.progress == target || animation.paused {
+        return;
+    }
+    let step = animation.delta_secs(&time) / config::EXPLODE_DURATION;
+    state.progress = if target > state.progress {
+        (state.progress + step).min(target)
+    } else {
+        (state.progress - step).max(target)
+    };
+    
+    // === APPLICATION AUX PARTIES ===
+    // Smoothstep : t² × (3 - 2t)
+    let t = state.progress;
+    let eased = t * t * (3.0 - 2.0 * t);
+    for (mut transform, home) in &mut parts {
+        let offset = home.direction * config::EXPLODE_DISTANCE * eased;
+        *transform = home.transform;
+        transform.translation += offset.extend(0.0);
+    }
+}
+
+/// Fait tourner le logo entier tant que Q ou E est maintenue
+/// 
+/// - Q : sens antihoraire
+/// - E : sens horaire
+/// 
+/// Vitesse : `LogoConfig::manual_rotation_speed` degrés par seconde.
+/// La rotation est ajoutée à celle de la racine `LogoRoot` : elle se
+/// cumule avec toute autre rotation appliquée à la racine.
+/// Contrôle manuel : ni la pause ni la vitesse de `AnimationState`
+/// ne s'appliquent.
+pub fn manual_rotation_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    config: Res<LogoConfig>,
+    mut roots: Query<&mut Transform, With<LogoRoot>>,
+) {
+    // === SENS DE ROTATION ===
+    // Q et E maintenues ensemble s'annulent
+    let mut direction = 0.0;
+    if keys.pressed(KeyCode::KeyQ) {
+        direction += 1.0;
+    }
+    if keys.pressed(KeyCode::KeyE) {
+        direction -= 1.0;
+    }
+    if direction == 0.0 {
+        return;
+    }
+    
+    // === ROTATION DE LA RACINE ===
+    let angle = geometry::degrees_to_radians(direction * config.manual_rotation_speed * time.delta_secs());
+    for mut transform in &mut roots {
+        transform.rotate_z(angle);
+    }
+}
+
+/// Fait tourner la teinte de l'anneau principal au fil du temps
+/// 
+/// Actif si `LogoConfig::ring_hue_cycle_speed` (degrés par seconde) est
+/// strictement positif. La saturation, la valeur et l'opacité de la
+/// couleur sont conservées (recalcul par `color_from_hsv`).
+/// Le matériau existant est modifié sur place : aucun asset n'est créé.
+/// Suit la pause et la vitesse de `AnimationState`.
+pub fn ring_hue_cycle_system(
+    time: Res<Time>,
+    animation: Res<AnimationState>,
+    config: Res<LogoConfig>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    rings: Query<&MeshMaterial2d<ColorMaterial>, With<MainCircle>>,
+) {
+    if config.ring_hue_cycle_speed <= 0.0 {
+        return;
+    }
+    let degrees = config.ring_hue_cycle_speed * animation.delta_secs(&time);
+    if degrees == 0.0 {
+        return;
+    }
+    
+    // Les copies d'un logo pavé partagent le matériau de l'anneau :
+    // chaque matériau n'avance qu'une fois par frame
+    let mut updated = HashSet::new();
+    for material_handle in &rings {
+        if !updated.insert(material_handle.0.id()) {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            let hsva = Hsva::from(material.color);
+            material.color = materials::color_from_hsv(hsva.hue + degrees, hsva.saturation, hsva.value)
+                .with_alpha(hsva.alpha);
+        }
+    }
+}
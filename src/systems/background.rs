@@ -0,0 +1,107 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: src/systems/background.rs                      ║
+// ║  Image de fond optionnelle                                               ║
+// ║  Rôle : Afficher une image derrière le logo, à la taille de la fenêtre  ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module background
+//! 
+//! Si `LogoConfig::background_image` est défini :
+//! - l'image est chargée par l'`AssetServer` (plugin `AssetPlugin` de
+//!   `DefaultPlugins`) et affichée par un `Sprite` à `BACKGROUND_IMAGE_Z`
+//! - le sprite suit la caméra `MainCamera` : centré sur elle et à la
+//!   taille de la fenêtre en coordonnées monde (échelle de projection
+//!   comprise), il couvre toute la vue malgré les déplacements et le zoom
+//! - si le chargement échoue (fichier absent, format inconnu), une erreur
+//!   est affichée et le sprite est supprimé : la couleur de fond
+//!   (`ClearColor`) reste visible
+
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::components::{BackgroundImage, MainCamera};
+use crate::config::{self, LogoConfig};
+use crate::systems::camera::projection_scale;
+
+/// Crée le sprite de l'image de fond (Startup)
+pub fn spawn_background_image_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<LogoConfig>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Some(path) = &config.background_image else {
+        return;
+    };
+    
+    let image = asset_server.load(path.clone());
+    let custom_size = windows.single().ok().map(|window| window.size());
+    
+    commands.spawn((
+        Sprite { image, custom_size, ..default() },
+        Transform::from_xyz(0.0, 0.0, config::BACKGROUND_IMAGE_Z),
+        BackgroundImage,
+    ));
+    println!("🖼️ Image de fond : {}", path.display());
+}
+
+/// Taille de l'image de fond, en coordonnées monde, pour couvrir la vue
+/// 
+/// # Arguments
+/// * `window_size` - Taille de la fenêtre (pixels logiques)
+/// * `projection_scale` - Échelle de la projection orthographique
+///   (2.0 : la vue couvre deux fois plus de monde)
+pub fn background_size(window_size: Vec2, projection_scale: f32) -> Vec2 {
+    window_size * projection_scale
+}
+
+/// Filtre de la caméra principale (disjoint de l'image de fond)
+type CameraFilter = (With<MainCamera>, Without<BackgroundImage>);
+
+/// Garde l'image de fond sous la caméra et à la taille de la vue
+/// 
+/// Centre le sprite sur la `MainCamera` (son Z reste `BACKGROUND_IMAGE_Z`)
+/// et l'ajuste à `background_size`. Exécuté après les déplacements de la
+/// caméra, avant la propagation des transforms : le fond ne décroche
+/// jamais d'une frame. Rien n'est modifié si la vue n'a pas changé.
+pub fn fit_background_to_view_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Transform, &Projection), CameraFilter>,
+    mut backgrounds: Query<(&mut Sprite, &mut Transform), With<BackgroundImage>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, projection)) = cameras.single() else {
+        return;
+    };
+    let size = background_size(window.size(), projection_scale(projection));
+    let center = camera.translation.truncate();
+    
+    for (mut sprite, mut transform) in &mut backgrounds {
+        if sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
+        if transform.translation.truncate() != center {
+            transform.translation = center.extend(config::BACKGROUND_IMAGE_Z);
+        }
+    }
+}
+
+/// Supprime l'image de fond si son chargement a échoué
+/// 
+/// La couleur de fond (`ClearColor`) reprend alors sa place.
+pub fn background_load_failed_system(
+    mut commands: Commands,
+    mut failures: EventReader<AssetLoadFailedEvent<Image>>,
+    backgrounds: Query<(Entity, &Sprite), With<BackgroundImage>>,
+) {
+    for failure in failures.read() {
+        for (entity, sprite) in &backgrounds {
+            if sprite.image.id() == failure.id {
+                println!("⚠️ Image de fond '{}' illisible : {}", failure.path, failure.error);
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
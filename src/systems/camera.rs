@@ -0,0 +1,579 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                   FICHIER: src/systems/camera.rs                         ║
+// ║  Déplacement de la caméra à la souris                                    ║
+// ║  Rôle : Explorer le logo (glisser-déplacer avec inertie)                ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module camera
+//! 
+//! Interactions de la souris avec la caméra principale (`MainCamera`) :
+//! - Glisser avec le bouton gauche : la scène suit le curseur
+//! - Glisser avec le bouton droit : le logo tourne sur lui-même, comme un
+//!   plateau tournant (la caméra ne bouge pas)
+//! - Relâcher en plein mouvement : la caméra continue sur sa lancée
+//!   puis ralentit (friction `LogoConfig::pan_friction`)
+//! - Molette : zoom centré sur le curseur (borné par `zoom_min`/`zoom_max`)
+//! - La caméra reste dans la boîte englobante du logo, élargie de
+//!   `LogoConfig::pan_margin` : le logo ne peut pas être perdu hors écran
+//! - Touche F : recadrage en douceur sur le logo entier
+//! - Ctrl+S : enregistre la position et le zoom dans `camera.json`,
+//!   relus au lancement suivant (`CameraState`)
+//! 
+//! Les déplacements sont convertis en unités du monde en tenant compte
+//! de l'échelle de la projection orthographique (zoom).
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+use std::path::Path;
+use crate::components::{LogoElementFilter, LogoRoot, LogoTile, MainCamera};
+use crate::config::{self, LogoConfig};
+use crate::geometry;
+use crate::error::LogoError;
+use crate::systems::layout;
+
+/// État du glisser-déplacer de la caméra
+/// 
+/// - `dragging` : le bouton gauche est maintenu
+/// - `last_cursor` : position du curseur à la frame précédente (pixels fenêtre)
+/// - `velocity` : vitesse récente de la caméra, en unités du monde par seconde
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct DragState {
+    /// Vrai pendant un glisser-déplacer
+    pub dragging: bool,
+    
+    /// Dernière position connue du curseur
+    pub last_cursor: Option<Vec2>,
+    
+    /// Vitesse de la caméra, conservée après le relâchement (inertie)
+    pub velocity: Vec2,
+}
+
+/// État de la rotation du logo au glisser avec le bouton droit
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct RotateDragState {
+    /// Angle (radians) du curseur autour du centre du logo à la frame
+    /// précédente, `None` hors glisser
+    pub anchor_angle: Option<f32>,
+}
+
+/// Recadrage en cours de la caméra (touche F)
+/// 
+/// Position et échelle de projection interpolées du départ vers la cible
+/// en `CAMERA_FIT_DURATION` secondes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitTransition {
+    /// Position de la caméra au début du recadrage
+    pub start_translation: Vec2,
+    
+    /// Échelle de projection au début du recadrage
+    pub start_scale: f32,
+    
+    /// Position finale de la caméra (centre du logo)
+    pub target_translation: Vec2,
+    
+    /// Échelle de projection finale
+    pub target_scale: f32,
+    
+    /// Temps écoulé depuis le début (secondes)
+    pub elapsed: f32,
+}
+
+/// État du recadrage de la caméra sur le logo
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CameraFit {
+    /// Recadrage en cours, `None` au repos
+    pub transition: Option<FitTransition>,
+}
+
+/// Position et zoom de la caméra, sauvegardés entre deux lancements
+/// 
+/// Enregistré en JSON dans `config::CAMERA_STATE_FILE` (Ctrl+S) et relu
+/// au démarrage par `load_camera_state_system`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraState {
+    /// Position de la caméra (monde)
+    pub translation: Vec2,
+    
+    /// Échelle de la projection orthographique (1.0 = sans zoom)
+    pub scale: f32,
+}
+
+impl Default for CameraState {
+    fn default() -> Self {
+        Self { translation: Vec2::ZERO, scale: 1.0 }
+    }
+}
+
+impl CameraState {
+    /// Écrit l'état en JSON dans `path` (fichier remplacé s'il existe)
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), LogoError> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+    
+    /// Lit un état enregistré par `save`
+    /// 
+    /// # Erreurs
+    /// - `LogoError::Io` si le fichier est absent ou illisible
+    /// - `LogoError::Parse` si le JSON est invalide ou l'échelle non
+    ///   strictement positive
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LogoError> {
+        let state: Self = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if !(state.scale.is_finite() && state.scale > 0.0 && state.translation.is_finite()) {
+            return Err(LogoError::Parse(format!("état de caméra invalide : {state:?}")));
+        }
+        Ok(state)
+    }
+    
+    /// Borne l'échelle au zoom accessible avec la molette
+    /// 
+    /// Échelle = 1 / zoom : elle reste dans [1 / zoom_max, 1 / zoom_min],
+    /// même si le fichier a été enregistré avec d'autres bornes ou modifié.
+    pub fn clamp_zoom(self, config: &LogoConfig) -> Self {
+        Self {
+            scale: self.scale.clamp(1.0 / config.zoom_max, 1.0 / config.zoom_min),
+            ..self
+        }
+    }
+}
+
+/// Échelle de la projection orthographique (1.0 si autre projection)
+pub(crate) fn projection_scale(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Orthographic(orthographic) => orthographic.scale,
+        _ => 1.0,
+    }
+}
+
+/// Position de la caméra après un zoom centré sur un point
+/// 
+/// Le point du monde sous le curseur doit rester sous le curseur :
+/// monde = caméra + décalage × échelle, avant comme après le zoom.
+/// 
+/// # Arguments
+/// * `camera` - Position actuelle de la caméra (monde)
+/// * `cursor_offset` - Position du curseur par rapport au centre de la
+///   fenêtre, en pixels, axe Y vers le haut
+/// * `old_scale` - Échelle de projection avant le zoom
+/// * `new_scale` - Échelle de projection après le zoom
+/// 
+/// # Formule
+/// caméra' = caméra + décalage × (ancienne_échelle - nouvelle_échelle)
+pub fn zoom_anchor_translation(camera: Vec2, cursor_offset: Vec2, old_scale: f32, new_scale: f32) -> Vec2 {
+    camera + cursor_offset * (old_scale - new_scale)
+}
+
+/// Position et échelle de projection qui cadrent une boîte dans la vue
+/// 
+/// # Arguments
+/// * `bounds` - Boîte à cadrer (min, max), en unités du monde
+/// * `viewport` - Taille de la vue en pixels
+/// * `margin` - Marge à conserver de chaque côté, en pixels
+/// 
+/// # Formule
+/// échelle = max(largeur_boîte / (largeur - 2×marge), hauteur_boîte / (hauteur - 2×marge))
+/// position = centre de la boîte
+pub fn fit_camera_to_bounds(bounds: (Vec2, Vec2), viewport: Vec2, margin: f32) -> (Vec2, f32) {
+    let (min, max) = bounds;
+    let size = (max - min).max(Vec2::splat(f32::EPSILON));
+    let available = (viewport - Vec2::splat(2.0 * margin)).max(Vec2::ONE);
+    
+    ((min + max) / 2.0, (size / available).max_element())
+}
+
+/// Déplace la caméra en glissant avec le bouton gauche de la souris
+/// 
+/// Le point de la scène sous le curseur reste sous le curseur :
+/// la caméra se déplace de l'opposé du mouvement de la souris.
+/// L'axe Y est inversé (Y vers le bas dans la fenêtre, vers le haut dans le monde).
+/// 
+/// # Vitesse
+/// La vitesse est lissée sur les dernières frames (moyenne exponentielle) :
+/// si la souris s'arrête avant le relâchement, la vitesse retombe vers 0
+/// et la caméra ne repart pas.
+pub fn camera_drag_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+    config: Res<LogoConfig>,
+    mut drag: ResMut<DragState>,
+    mut cameras: Query<(&mut Transform, &Projection), With<MainCamera>>,
+) {
+    // === FIN DU GLISSER ===
+    if !buttons.pressed(MouseButton::Left) {
+        drag.dragging = false;
+        drag.last_cursor = None;
+        return;
+    }
+    
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    
+    // === DÉBUT DU GLISSER ===
+    // La première frame mémorise le curseur et arrête toute inertie
+    let Some(last_cursor) = drag.last_cursor.replace(cursor) else {
+        drag.dragging = true;
+        drag.velocity = Vec2::ZERO;
+        return;
+    };
+    
+    let Ok((mut transform, projection)) = cameras.single_mut() else {
+        return;
+    };
+    
+    // === DÉPLACEMENT EN UNITÉS DU MONDE ===
+    let screen_delta = cursor - last_cursor;
+    let world_delta = Vec2::new(-screen_delta.x, screen_delta.y) * projection_scale(projection);
+    transform.translation += world_delta.extend(0.0);
+    
+    // === VITESSE RÉCENTE ===
+    let delta_secs = time.delta_secs();
+    if delta_secs > 0.0 {
+        let sample = (world_delta / delta_secs).clamp_length_max(config.pan_max_velocity);
+        drag.velocity = drag.velocity.lerp(sample, config::PAN_VELOCITY_SMOOTHING);
+    }
+}
+
+/// Fait tourner le logo en glissant avec le bouton droit de la souris
+/// 
+/// L'angle balayé par le curseur autour du centre du logo (position des
+/// racines `LogoRoot`, moyenne des cases en mosaïque) entre deux frames
+/// est ajouté à la rotation de chaque racine : le point du logo saisi
+/// suit le curseur, comme sur un plateau tournant.
+/// 
+/// # Formule
+/// delta = angle(centre → curseur) - angle de la frame précédente,
+/// ramené dans [-π, π[ pour tourner par le plus court chemin
+pub fn drag_rotation_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut rotate: ResMut<RotateDragState>,
+    mut roots: Query<&mut Transform, With<LogoRoot>>,
+) {
+    // === FIN DU GLISSER ===
+    if !buttons.pressed(MouseButton::Right) {
+        rotate.anchor_angle = None;
+        return;
+    }
+    
+    // === CURSEUR EN COORDONNÉES DU MONDE ===
+    let Some(cursor) = windows.single().ok().and_then(|window| window.cursor_position()) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Ok(cursor_world) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+    
+    // === ANGLE AUTOUR DU CENTRE DU LOGO ===
+    let count = roots.iter().len();
+    if count == 0 {
+        return;
+    }
+    let center = roots.iter().map(|transform| transform.translation.truncate()).sum::<Vec2>() / count as f32;
+    let angle = geometry::angle_between_points(center, cursor_world);
+    
+    // La première frame mémorise seulement l'angle de départ
+    let Some(anchor_angle) = rotate.anchor_angle.replace(angle) else {
+        return;
+    };
+    
+    // === ROTATION DES RACINES ===
+    let delta = geometry::normalize_angle(angle - anchor_angle + PI) - PI;
+    for mut transform in &mut roots {
+        transform.rotate_z(delta);
+    }
+}
+
+/// Fait glisser la caméra sur sa lancée après un relâchement
+/// 
+/// Tant qu'aucun glisser n'est en cours, la vitesse de `DragState` est
+/// appliquée à la caméra puis diminuée : v ← v × e^(-friction × dt).
+/// Le mouvement s'arrête sous `PAN_MIN_VELOCITY` unités par seconde.
+pub fn camera_inertia_system(
+    time: Res<Time>,
+    config: Res<LogoConfig>,
+    mut drag: ResMut<DragState>,
+    mut cameras: Query<&mut Transform, With<MainCamera>>,
+) {
+    if drag.dragging || drag.velocity == Vec2::ZERO {
+        return;
+    }
+    
+    // === ARRÊT ===
+    if drag.velocity.length() < config::PAN_MIN_VELOCITY {
+        drag.velocity = Vec2::ZERO;
+        return;
+    }
+    
+    // === DÉPLACEMENT ===
+    let delta_secs = time.delta_secs();
+    for mut transform in &mut cameras {
+        transform.translation += (drag.velocity * delta_secs).extend(0.0);
+    }
+    
+    // === FRICTION ===
+    drag.velocity *= (-config.pan_friction * delta_secs).exp();
+}
+
+/// Zoome avec la molette, en gardant fixe le point sous le curseur
+/// 
+/// Chaque cran multiplie le zoom par `ZOOM_STEP`. Le zoom (inverse de
+/// l'échelle de projection : 2.0 = deux fois plus gros) est borné à
+/// [zoom_min, zoom_max]. Les défilements en pixels (pavés tactiles)
+/// sont convertis en crans (`ZOOM_PIXELS_PER_LINE` pixels par cran).
+pub fn camera_zoom_system(
+    mut wheel: EventReader<MouseWheel>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<LogoConfig>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    // === CUMUL DES CRANS DE LA FRAME ===
+    let lines: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / config::ZOOM_PIXELS_PER_LINE,
+        })
+        .sum();
+    if lines == 0.0 {
+        return;
+    }
+    
+    let Ok((mut transform, mut projection)) = cameras.single_mut() else {
+        return;
+    };
+    let Projection::Orthographic(orthographic) = projection.as_mut() else {
+        return;
+    };
+    
+    // === NOUVELLE ÉCHELLE ===
+    let old_scale = orthographic.scale;
+    let zoom = (config::ZOOM_STEP.powf(lines) / old_scale).clamp(config.zoom_min, config.zoom_max);
+    let new_scale = 1.0 / zoom;
+    orthographic.scale = new_scale;
+    
+    // === ANCRAGE SUR LE CURSEUR ===
+    // Sans curseur dans la fenêtre, le zoom est centré sur l'écran
+    let Some(cursor_offset) = windows.single().ok().and_then(|window| {
+        let cursor = window.cursor_position()?;
+        let offset = cursor - window.size() / 2.0;
+        Some(Vec2::new(offset.x, -offset.y))
+    }) else {
+        return;
+    };
+    
+    let camera = zoom_anchor_translation(transform.translation.truncate(), cursor_offset, old_scale, new_scale);
+    transform.translation = camera.extend(transform.translation.z);
+}
+
+/// Maintient la caméra dans la zone du logo
+/// 
+/// La zone autorisée est la boîte englobante du logo, ou de la mosaïque
+/// (`layout::tiled_bounds`), transformée par une racine `LogoRoot`
+/// (ajustement à la fenêtre) et élargie de `pan_margin` de chaque côté.
+/// 
+/// Si la caméra touche un bord, la composante correspondante de la
+/// vitesse d'inertie est annulée pour qu'elle ne reste pas "collée".
+pub fn clamp_camera_system(
+    config: Res<LogoConfig>,
+    roots: Query<(&GlobalTransform, Option<&LogoTile>), With<LogoRoot>>,
+    mut drag: ResMut<DragState>,
+    mut cameras: Query<&mut Transform, With<MainCamera>>,
+) {
+    // Toutes les cases partagent l'ajustement : une racine suffit
+    let Some((root, tile)) = roots.iter().next() else {
+        return;
+    };
+    
+    // === ZONE AUTORISÉE (MONDE) ===
+    // Boîte de la mosaïque, exprimée dans le repère de cette case
+    let tile_offset = tile.map_or(Vec2::ZERO, |tile| tile.offset);
+    let (min, max) = layout::tiled_bounds(&config);
+    let (min, max) = (min - tile_offset, max - tile_offset);
+    let corner_a = root.transform_point(min.extend(0.0)).truncate();
+    let corner_b = root.transform_point(max.extend(0.0)).truncate();
+    let margin = Vec2::splat(config.pan_margin);
+    let allowed_min = corner_a.min(corner_b) - margin;
+    let allowed_max = corner_a.max(corner_b) + margin;
+    
+    // === LIMITATION ===
+    for mut transform in &mut cameras {
+        let position = transform.translation.truncate();
+        let clamped = position.clamp(allowed_min, allowed_max);
+        if clamped.x != position.x {
+            drag.velocity.x = 0.0;
+        }
+        if clamped.y != position.y {
+            drag.velocity.y = 0.0;
+        }
+        transform.translation = clamped.extend(transform.translation.z);
+    }
+}
+
+/// Lance le recadrage de la caméra sur le logo entier (touche F)
+/// 
+/// La boîte du logo est recalculée à partir des entités présentes
+/// (boîtes `Aabb` des meshes, transformées par leur `GlobalTransform`) :
+/// elle suit donc les régénérations, l'éclatement et la rotation.
+/// Grille, spirale et ombres ne sont pas prises en compte.
+/// 
+/// La cible cadre le logo avec la marge `fit_margin`, quel que soit le
+/// zoom actuel (borné à [zoom_min, zoom_max]). L'inertie est arrêtée.
+pub fn fit_camera_on_key_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<LogoConfig>,
+    elements: Query<(&Aabb, &GlobalTransform), LogoElementFilter>,
+    cameras: Query<(&Transform, &Projection), With<MainCamera>>,
+    mut fit: ResMut<CameraFit>,
+    mut drag: ResMut<DragState>,
+) {
+    if !keys.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((transform, projection)) = cameras.single() else {
+        return;
+    };
+    
+    // === BOÎTE DU LOGO (MONDE) ===
+    let mut min = Vec2::splat(f32::MAX);
+    let mut max = Vec2::splat(f32::MIN);
+    for (aabb, global) in &elements {
+        let center = aabb.center.truncate();
+        let half = aabb.half_extents.truncate();
+        let corners = [
+            center - half,
+            center + half,
+            center + half * Vec2::new(1.0, -1.0),
+            center + half * Vec2::new(-1.0, 1.0),
+        ];
+        for corner in corners {
+            let world = global.transform_point(corner.extend(0.0)).truncate();
+            min = min.min(world);
+            max = max.max(world);
+        }
+    }
+    if min.x > max.x {
+        return;
+    }
+    
+    // === CIBLE ===
+    let (target_translation, target_scale) = fit_camera_to_bounds((min, max), window.size(), config.fit_margin);
+    let target_scale = target_scale.clamp(1.0 / config.zoom_max, 1.0 / config.zoom_min);
+    
+    fit.transition = Some(FitTransition {
+        start_translation: transform.translation.truncate(),
+        start_scale: projection_scale(projection),
+        target_translation,
+        target_scale,
+        elapsed: 0.0,
+    });
+    drag.velocity = Vec2::ZERO;
+    println!("🎯 Recadrage sur le logo");
+}
+
+/// Fait progresser le recadrage en cours
+/// 
+/// Interpolation lissée (smoothstep) de la position et de l'échelle.
+/// Un glisser-déplacer interrompt le recadrage.
+pub fn camera_fit_animation_system(
+    time: Res<Time>,
+    drag: Res<DragState>,
+    mut fit: ResMut<CameraFit>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    let Some(mut transition) = fit.transition else {
+        return;
+    };
+    if drag.dragging {
+        fit.transition = None;
+        return;
+    }
+    
+    // === PROGRESSION ===
+    transition.elapsed += time.delta_secs();
+    let t = (transition.elapsed / config::CAMERA_FIT_DURATION).clamp(0.0, 1.0);
+    let eased = t * t * (3.0 - 2.0 * t);
+    
+    // === APPLICATION À LA CAMÉRA ===
+    let translation = transition.start_translation.lerp(transition.target_translation, eased);
+    let scale = transition.start_scale + (transition.target_scale - transition.start_scale) * eased;
+    for (mut transform, mut projection) in &mut cameras {
+        transform.translation = translation.extend(transform.translation.z);
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = scale;
+        }
+    }
+    
+    fit.transition = (t < 1.0).then_some(transition);
+}
+
+/// Enregistre la position et le zoom de la caméra avec Ctrl+S
+/// 
+/// Écrit `config::CAMERA_STATE_FILE` dans le répertoire courant ; une
+/// erreur d'écriture est affichée sans interrompre l'application.
+pub fn save_camera_state_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    cameras: Query<(&Transform, &Projection), With<MainCamera>>,
+) {
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !(ctrl && keys.just_pressed(KeyCode::KeyS)) {
+        return;
+    }
+    let Ok((transform, projection)) = cameras.single() else {
+        return;
+    };
+    
+    let state = CameraState {
+        translation: transform.translation.truncate(),
+        scale: projection_scale(projection),
+    };
+    match state.save(config::CAMERA_STATE_FILE) {
+        Ok(()) => println!("💾 Caméra enregistrée dans {}", config::CAMERA_STATE_FILE),
+        Err(error) => println!("⚠️ Enregistrement de la caméra impossible : {error}"),
+    }
+}
+
+/// Restaure la caméra enregistrée au lancement précédent
+/// 
+/// À exécuter au démarrage, après la création de la caméra. Sans fichier,
+/// la caméra par défaut est conservée silencieusement ; un fichier
+/// corrompu est signalé puis ignoré. Le zoom restauré est borné par
+/// `zoom_min`/`zoom_max` (`CameraState::clamp_zoom`).
+pub fn load_camera_state_system(
+    config: Res<LogoConfig>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    let state = match CameraState::load(config::CAMERA_STATE_FILE) {
+        Ok(state) => state.clamp_zoom(&config),
+        Err(LogoError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => return,
+        Err(error) => {
+            println!("⚠️  {} ignoré, caméra par défaut : {error}", config::CAMERA_STATE_FILE);
+            return;
+        }
+    };
+    
+    for (mut transform, mut projection) in &mut cameras {
+        transform.translation = state.translation.extend(transform.translation.z);
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = state.scale;
+        }
+    }
+    println!("📷 Caméra restaurée depuis {}", config::CAMERA_STATE_FILE);
+}
@@ -0,0 +1,361 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                    FICHIER: src/systems/debug.rs                         ║
+// ║  Outils visuels de débogage                                              ║
+// ║  Rôle : Afficher des informations de construction par-dessus le logo   ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module debug
+//! 
+//! Aides visuelles pour vérifier la géométrie du logo :
+//! - Mode fil de fer (touche W) : affiche les arêtes de tous les triangles
+//! - Gizmos (touche F4) : centroïdes des triangles et des parties du "R",
+//!   boîte englobante du "R"
+//! - Repères de centroïdes (touche F5) : petits disques `CentroidMarker`
+//!   au centroïde de chaque triangle, recréés avec le logo
+//! - Inspection (flèches Droite/Gauche) : n'affiche qu'une partie du "R"
+//!   à la fois et affiche sa définition ; flèche Bas pour tout réafficher
+//! - Règle (touche M) : deux clics mesurent la distance entre deux points,
+//!   un troisième efface la mesure
+//! 
+//! Le mode fil de fer utilise le `Wireframe2dPlugin` de Bevy, qui nécessite
+//! la fonctionnalité GPU `POLYGON_MODE_LINE`. Elle n'est pas exigée au
+//! démarrage : sans elle (WebGL2, certains adaptateurs mobiles ou
+//! logiciels), `WireframeSupport` reste faux et la touche W est sans effet.
+
+use bevy::prelude::*;
+use bevy::sprite::Wireframe2dConfig;
+use bevy::window::PrimaryWindow;
+use crate::build;
+use crate::components::{LogoRoot, MainCamera, RPart, RulerLabel};
+use crate::config::{self, LogoConfig};
+use crate::geometry;
+use crate::systems::regenerate::RegenerateLogo;
+
+/// Disponibilité du mode fil de fer sur l'adaptateur graphique
+/// 
+/// Renseignée par `LogoPlugin` une fois le GPU initialisé : vraie si le
+/// périphérique de rendu offre `POLYGON_MODE_LINE`. Fausse par défaut
+/// (aucun rendu, par exemple dans les tests).
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WireframeSupport {
+    /// Vrai si le rendu fil de fer peut être activé
+    pub available: bool,
+}
+
+/// Bascule le mode fil de fer avec la touche W
+/// 
+/// Inverse `LogoConfig::wireframe` ; la synchronisation avec le rendu
+/// est faite par `sync_wireframe_system`. Sans `WireframeSupport`, un
+/// avertissement est affiché et rien ne change.
+pub fn toggle_wireframe_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    support: Res<WireframeSupport>,
+    mut config: ResMut<LogoConfig>,
+) {
+    if keys.just_pressed(KeyCode::KeyW) {
+        if !support.available {
+            println!("⚠️ Mode fil de fer indisponible : l'adaptateur graphique n'offre pas POLYGON_MODE_LINE");
+            return;
+        }
+        config.wireframe = !config.wireframe;
+        println!("🔲 Mode fil de fer : {}", if config.wireframe { "activé" } else { "désactivé" });
+    }
+}
+
+/// Applique `LogoConfig::wireframe` à la configuration du rendu fil de fer
+/// 
+/// Le mode global de Bevy s'applique à tous les `Mesh2d` : anneau,
+/// triangles, petits cercles et parties du "R".
+/// À exécuter quand `LogoConfig` change (y compris à sa création).
+/// Reste désactivé sans `WireframeSupport`.
+pub fn sync_wireframe_system(
+    config: Res<LogoConfig>,
+    support: Res<WireframeSupport>,
+    mut wireframe: ResMut<Wireframe2dConfig>,
+) {
+    let enabled = config.wireframe && support.available;
+    if wireframe.global != enabled {
+        wireframe.global = enabled;
+    }
+}
+
+/// Bascule l'affichage des gizmos de débogage avec la touche F4
+pub fn toggle_debug_gizmos_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<LogoConfig>,
+) {
+    if keys.just_pressed(KeyCode::F4) {
+        config.debug_gizmos = !config.debug_gizmos;
+        println!("📐 Gizmos de débogage : {}", if config.debug_gizmos { "activés" } else { "désactivés" });
+    }
+}
+
+/// Bascule les repères de centroïdes avec la touche F5
+/// 
+/// Inverse `LogoConfig::show_centroids` puis régénère le logo : les
+/// repères sont créés (ou non) avec les autres éléments.
+pub fn toggle_centroid_markers_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<LogoConfig>,
+    mut events: EventWriter<RegenerateLogo>,
+) {
+    if keys.just_pressed(KeyCode::F5) {
+        config.show_centroids = !config.show_centroids;
+        events.write(RegenerateLogo);
+        println!("🎯 Repères de centroïdes : {}", if config.show_centroids { "affichés" } else { "masqués" });
+    }
+}
+
+/// Dessine les repères de construction du logo avec les `Gizmos` de Bevy
+/// 
+/// Quand `LogoConfig::debug_gizmos` est actif, dessine à chaque frame :
+/// - une croix jaune au centroïde de chaque triangle (extérieur et intérieur)
+/// - un point cyan au centroïde de chaque partie du "R" (`polygon_centroid`)
+/// - la boîte englobante du "R" en magenta (`bounding_box_of_parts`)
+/// 
+/// Les positions sont calculées une fois depuis la configuration, dans le
+/// repère du logo, puis converties en coordonnées monde via le
+/// `GlobalTransform` de chaque racine (pour suivre le redimensionnement,
+/// les déplacements et chaque case de la mosaïque).
+pub fn debug_gizmos_system(
+    mut gizmos: Gizmos,
+    config: Res<LogoConfig>,
+    roots: Query<&GlobalTransform, With<LogoRoot>>,
+) {
+    if !config.debug_gizmos || roots.is_empty() {
+        return;
+    }
+    
+    let triangle_color = Color::srgb(1.0, 1.0, 0.0);
+    let part_color = Color::srgb(0.0, 1.0, 1.0);
+    let bounds_color = Color::srgb(1.0, 0.0, 1.0);
+    
+    // === CENTROÏDES DES TRIANGLES ===
+    // Extérieurs (mêmes sommets que create_exterior_triangles) puis
+    // intérieurs, avec la taille de leur croix
+    let exterior_centers = build::exterior_triangle_transforms(&config)
+        .into_iter()
+        .map(|(p1, p2, p3)| (geometry::calculate_triangle_centroid(p1, p2, p3), 4.0));
+    let interior_centers = (0..config.interior_triangles_count).map(|i| {
+        let (p1, p2, p3) = build::interior_triangle_points(&config, i);
+        (geometry::calculate_triangle_centroid(p1, p2, p3), 8.0)
+    });
+    let triangle_centers: Vec<(Vec2, f32)> = exterior_centers.chain(interior_centers).collect();
+    
+    // === CENTROÏDES ET BOÎTE ENGLOBANTE DU "R" ===
+    // Boîte tracée coin par coin pour rester correcte si la racine est tournée
+    let parts = config.letter_parts();
+    let part_centers: Vec<Vec2> = parts.iter().map(|part| geometry::polygon_centroid(&part.points)).collect();
+    let (min, max) = geometry::bounding_box_of_parts(&parts);
+    let bounds = [
+        Vec2::new(min.x, min.y),
+        Vec2::new(max.x, min.y),
+        Vec2::new(max.x, max.y),
+        Vec2::new(min.x, max.y),
+        Vec2::new(min.x, min.y),
+    ];
+    
+    // === TRACÉ POUR CHAQUE LOGO DE LA MOSAÏQUE ===
+    for root in &roots {
+        // Conversion repère du logo → repère monde
+        let to_world = |p: Vec2| root.transform_point(p.extend(0.0)).truncate();
+        
+        for &(center, size) in &triangle_centers {
+            gizmos.cross_2d(Isometry2d::from_translation(to_world(center)), size, triangle_color);
+        }
+        for &center in &part_centers {
+            gizmos.circle_2d(Isometry2d::from_translation(to_world(center)), 3.0, part_color);
+        }
+        gizmos.linestrip_2d(bounds.map(to_world), bounds_color);
+    }
+}
+
+/// Partie du "R" actuellement inspectée
+/// 
+/// `None` (défaut) : toutes les parties sont visibles.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InspectedPart(pub Option<usize>);
+
+/// Index de la partie à inspecter après un appui sur une flèche
+/// 
+/// L'index boucle sur le nombre de parties : après la dernière vient la
+/// première (et inversement). Sans inspection en cours, `forward` part
+/// de la première partie, sinon de la dernière.
+/// 
+/// # Arguments
+/// * `current` - Partie inspectée (`None` si aucune)
+/// * `forward` - `true` pour la partie suivante, `false` pour la précédente
+/// * `count` - Nombre de parties de la lettre
+/// 
+/// # Retour
+/// `None` si la lettre n'a aucune partie
+pub fn step_inspected_part(current: Option<usize>, forward: bool, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => count - 1,
+        (Some(index), true) => (index + 1) % count,
+        (Some(index), false) => (index + count - 1) % count,
+    })
+}
+
+/// Inspecte les parties du "R" une par une avec les flèches
+/// 
+/// - Droite / Gauche : partie suivante / précédente ; seule cette partie
+///   reste visible et sa définition (nom, points, z_order) est affichée
+/// - Bas : fin de l'inspection, toutes les parties redeviennent visibles
+/// 
+/// Les contours des parties étant leurs enfants, ils suivent leur visibilité.
+pub fn inspect_part_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<LogoConfig>,
+    mut inspected: ResMut<InspectedPart>,
+    mut parts: Query<(&RPart, &mut Visibility)>,
+) {
+    let definitions = config.letter_parts();
+    
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        inspected.0 = None;
+        println!("🔍 Inspection terminée : toutes les parties visibles");
+    } else if keys.just_pressed(KeyCode::ArrowRight) || keys.just_pressed(KeyCode::ArrowLeft) {
+        let forward = keys.just_pressed(KeyCode::ArrowRight);
+        inspected.0 = step_inspected_part(inspected.0, forward, definitions.len());
+        
+        if let Some(part) = inspected.0.and_then(|index| definitions.get(index)) {
+            println!("🔍 Partie {}/{} : '{}' (z_order {})",
+                     inspected.0.unwrap_or_default() + 1, definitions.len(), part.name, part.z_order);
+            println!("   Points : {:?}", part.points);
+        }
+    } else {
+        return;
+    }
+    
+    // === VISIBILITÉ DES PARTIES ===
+    for (part, mut visibility) in &mut parts {
+        *visibility = match inspected.0 {
+            Some(index) if index != part.index => Visibility::Hidden,
+            _ => Visibility::Inherited,
+        };
+    }
+}
+
+/// Règle de mesure (touche M)
+/// 
+/// Les clics gauches posent les extrémités de la mesure, en coordonnées
+/// du monde (pixels à l'échelle 1 de la caméra).
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct Ruler {
+    /// Vrai quand la règle est active (les clics sont des mesures)
+    pub active: bool,
+    
+    /// Extrémités posées : aucune, une, ou deux (mesure complète)
+    pub points: Vec<Vec2>,
+}
+
+impl Ruler {
+    /// Prend en compte un clic en `point`
+    /// 
+    /// Les deux premiers clics posent les extrémités ; le troisième
+    /// efface la mesure (le suivant en recommence une).
+    pub fn click(&mut self, point: Vec2) {
+        if self.points.len() >= 2 {
+            self.points.clear();
+        } else {
+            self.points.push(point);
+        }
+    }
+    
+    /// Distance entre les deux extrémités, `None` si la mesure est incomplète
+    pub fn distance(&self) -> Option<f32> {
+        match self.points[..] {
+            [start, end] => Some(start.distance(end)),
+            _ => None,
+        }
+    }
+}
+
+/// Active ou désactive la règle de mesure avec la touche M
+/// 
+/// La mesure en cours est effacée dans les deux cas.
+pub fn toggle_ruler_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut ruler: ResMut<Ruler>,
+) {
+    if keys.just_pressed(KeyCode::KeyM) {
+        ruler.active = !ruler.active;
+        ruler.points.clear();
+        println!("📏 Règle : {}", if ruler.active { "activée (cliquer deux points)" } else { "désactivée" });
+    }
+}
+
+/// Pose les extrémités de la règle aux clics gauches
+/// 
+/// La position du curseur est convertie en coordonnées du monde par la
+/// caméra principale (zoom et déplacement compris).
+pub fn ruler_click_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut ruler: ResMut<Ruler>,
+) {
+    if !ruler.active || !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(cursor) = windows.single().ok().and_then(|window| window.cursor_position()) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Ok(point) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+    
+    ruler.click(point);
+    if let Some(distance) = ruler.distance() {
+        println!("📏 Distance : {:.1} px", distance);
+    }
+}
+
+/// Dessine la règle avec les `Gizmos` : extrémités et segment
+pub fn ruler_gizmos_system(mut gizmos: Gizmos, ruler: Res<Ruler>) {
+    if !ruler.active {
+        return;
+    }
+    
+    for &point in &ruler.points {
+        gizmos.circle_2d(Isometry2d::from_translation(point), 4.0, config::RULER_COLOR);
+    }
+    if let [start, end] = ruler.points[..] {
+        gizmos.line_2d(start, end, config::RULER_COLOR);
+    }
+}
+
+/// Affiche la distance mesurée en texte, au milieu du segment
+/// 
+/// À exécuter quand `Ruler` change : l'ancien texte est supprimé et un
+/// nouveau est créé si la mesure est complète.
+pub fn ruler_label_system(
+    mut commands: Commands,
+    ruler: Res<Ruler>,
+    labels: Query<Entity, With<RulerLabel>>,
+) {
+    for label in &labels {
+        commands.entity(label).despawn();
+    }
+    
+    let (Some(distance), [start, end]) = (ruler.distance(), &ruler.points[..]) else {
+        return;
+    };
+    commands.spawn((
+        Text2d::new(format!("{:.1} px", distance)),
+        TextFont { font_size: config::RULER_FONT_SIZE, ..default() },
+        TextColor(config::RULER_COLOR),
+        Transform::from_translation(start.midpoint(*end).extend(config::RULER_LABEL_Z)),
+        RulerLabel,
+    ));
+}
@@ -0,0 +1,55 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                    FICHIER: src/systems/glow.rs                          ║
+// ║  Variante "néon" du logo                                                 ║
+// ║  Rôle : Passer l'anneau arc-en-ciel et les petits cercles en émissif    ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module glow (systèmes)
+//! 
+//! Quand `LogoConfig::glow` est actif :
+//! - la caméra est créée en HDR avec le bloom (voir `setup_system`)
+//! - les éléments lumineux nouvellement créés voient leur `ColorMaterial`
+//!   remplacé par un `GlowMaterial` de même couleur
+//! 
+//! Éléments lumineux : triangles extérieurs, anneau en dégradé et petits
+//! cercles centraux. Leur matériau n'étant plus un `ColorMaterial`, le
+//! fondu d'introduction et l'opacité globale ne s'y appliquent pas.
+
+use bevy::prelude::*;
+use crate::components::{CenterDot, ExteriorTriangle, RainbowRing};
+use crate::config::LogoConfig;
+use crate::glow::GlowMaterial;
+
+/// Éléments lumineux créés depuis le dernier passage
+type NewGlowingFilter = (
+    Added<MeshMaterial2d<ColorMaterial>>,
+    Or<(With<ExteriorTriangle>, With<RainbowRing>, With<CenterDot>)>,
+);
+
+/// Remplace le matériau des éléments lumineux par un `GlowMaterial`
+/// 
+/// La couleur est reprise du `ColorMaterial` d'origine, l'intensité
+/// vient de `LogoConfig::glow_strength`.
+pub fn apply_glow_system(
+    mut commands: Commands,
+    config: Res<LogoConfig>,
+    color_materials: Res<Assets<ColorMaterial>>,
+    mut glow_materials: ResMut<Assets<GlowMaterial>>,
+    elements: Query<(Entity, &MeshMaterial2d<ColorMaterial>), NewGlowingFilter>,
+) {
+    if !config.glow {
+        return;
+    }
+    
+    for (entity, material_handle) in &elements {
+        let Some(material) = color_materials.get(&material_handle.0) else {
+            continue;
+        };
+        let glow = glow_materials.add(GlowMaterial::new(material.color, config.glow_strength));
+        
+        commands
+            .entity(entity)
+            .remove::<MeshMaterial2d<ColorMaterial>>()
+            .insert(MeshMaterial2d(glow));
+    }
+}
@@ -0,0 +1,181 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: src/systems/headless.rs                        ║
+// ║  Rendu sans fenêtre vers une image PNG                                   ║
+// ║  Rôle : Générer le logo en intégration continue (pas d'écran requis)    ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module headless
+//! 
+//! Utilisé par `run_headless` : la caméra créée par `setup_system` est
+//! redirigée vers une image hors écran, quelques frames sont rendues
+//! (le temps que le GPU compile les pipelines), puis une capture de
+//! l'image est écrite au format PNG et l'application se termine.
+//! 
+//! Déroulement :
+//! 1. `prepare_headless_target_system` (Startup, après le setup) :
+//!    création de l'image cible, redirection de la caméra, ajustement du logo
+//! 2. `capture_headless_frame_system` (Update) : attente de
+//!    `HEADLESS_WARMUP_FRAMES` frames puis demande de capture
+//! 3. Observateur de `ScreenshotCaptured` : écriture du PNG et fin de l'application
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use crate::components::{LogoRoot, LogoTile};
+use crate::config::{self, LogoConfig};
+use crate::systems::layout;
+
+/// Résultat de la capture, partagé avec `run_headless`
+/// 
+/// Le monde Bevy est détruit à la fin de `App::run` : le résultat est
+/// donc transmis par ce pointeur partagé plutôt que par une ressource.
+pub type CaptureOutcome = Arc<Mutex<Option<io::Result<()>>>>;
+
+/// État de la capture hors écran
+#[derive(Resource)]
+pub struct HeadlessCapture {
+    /// Chemin du fichier PNG à écrire
+    pub output_path: PathBuf,
+    
+    /// Résultat de l'écriture, lu par `run_headless` après l'exécution
+    pub outcome: CaptureOutcome,
+    
+    /// Image dans laquelle la caméra dessine (créée au démarrage)
+    target: Option<Handle<Image>>,
+    
+    /// Nombre de frames rendues depuis le démarrage
+    frames: u32,
+    
+    /// Vrai une fois la capture demandée
+    requested: bool,
+}
+
+impl HeadlessCapture {
+    /// Prépare une capture vers `output_path`
+    pub fn new(output_path: PathBuf, outcome: CaptureOutcome) -> Self {
+        Self {
+            output_path,
+            outcome,
+            target: None,
+            frames: 0,
+            requested: false,
+        }
+    }
+}
+
+/// Crée l'image cible et y redirige la caméra
+/// 
+/// S'exécute après `setup_system` : la caméra et la racine du logo
+/// existent déjà. L'image est carrée (`HEADLESS_IMAGE_SIZE` pixels de côté)
+/// et le logo y est ajusté avec `layout::fit_transform`, comme dans une
+/// fenêtre de cette taille.
+/// 
+/// # Format
+/// Bgra8UnormSrgb, utilisable comme cible de rendu et copiable vers le CPU.
+/// Le canal alpha est conservé jusqu'au PNG : avec
+/// `LogoConfig::transparent_export`, la caméra efface l'image en
+/// transparent et seuls les pixels du logo sont opaques.
+pub fn prepare_headless_target_system(
+    mut images: ResMut<Assets<Image>>,
+    mut capture: ResMut<HeadlessCapture>,
+    config: Res<LogoConfig>,
+    mut cameras: Query<&mut Camera>,
+    mut roots: Query<(&mut Transform, Option<&LogoTile>), With<LogoRoot>>,
+) {
+    // === IMAGE CIBLE ===
+    let size = Extent3d {
+        width: config::HEADLESS_IMAGE_SIZE,
+        height: config::HEADLESS_IMAGE_SIZE,
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage |=
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_SRC | TextureUsages::RENDER_ATTACHMENT;
+    let handle = images.add(image);
+    
+    // === REDIRECTION DE LA CAMÉRA ===
+    for mut camera in &mut cameras {
+        camera.target = handle.clone().into();
+        if config.transparent_export {
+            camera.clear_color = ClearColorConfig::Custom(config.export_background());
+        }
+    }
+    
+    // === AJUSTEMENT DU LOGO À L'IMAGE ===
+    let viewport = Vec2::splat(config::HEADLESS_IMAGE_SIZE as f32);
+    for (mut transform, tile) in &mut roots {
+        let target = layout::tile_transform(&config, viewport, tile.map_or(Vec2::ZERO, |tile| tile.offset));
+        transform.translation = target.translation;
+        transform.scale = target.scale;
+    }
+    
+    capture.target = Some(handle);
+}
+
+/// Demande la capture de l'image après quelques frames
+/// 
+/// Les pipelines de rendu sont compilés en arrière-plan : les premières
+/// frames peuvent être vides. La capture est demandée après
+/// `HEADLESS_WARMUP_FRAMES` frames ; si elle n'est pas arrivée après
+/// `HEADLESS_TIMEOUT_FRAMES` frames, l'application se termine en erreur.
+pub fn capture_headless_frame_system(
+    mut commands: Commands,
+    mut capture: ResMut<HeadlessCapture>,
+    mut exit: EventWriter<AppExit>,
+) {
+    capture.frames += 1;
+    
+    // === ABANDON SI LA CAPTURE N'ARRIVE PAS ===
+    if capture.frames > config::HEADLESS_TIMEOUT_FRAMES {
+        println!("⚠️ Capture non reçue après {} frames", config::HEADLESS_TIMEOUT_FRAMES);
+        *capture.outcome.lock().unwrap() = Some(Err(io::Error::other("capture non reçue")));
+        exit.write(AppExit::error());
+        return;
+    }
+    
+    if capture.requested || capture.frames < config::HEADLESS_WARMUP_FRAMES {
+        return;
+    }
+    let Some(target) = capture.target.clone() else {
+        return;
+    };
+    
+    // === DEMANDE DE CAPTURE ===
+    let output_path = capture.output_path.clone();
+    let outcome = capture.outcome.clone();
+    commands.spawn(Screenshot::image(target)).observe(
+        move |trigger: Trigger<ScreenshotCaptured>, mut exit: EventWriter<AppExit>| {
+            let result = save_png(&trigger.event().0, &output_path);
+            match &result {
+                Ok(()) => println!("📸 Logo enregistré dans {}", output_path.display()),
+                Err(error) => println!("⚠️ Écriture de {} impossible : {}", output_path.display(), error),
+            }
+            *outcome.lock().unwrap() = Some(result);
+            exit.write(AppExit::Success);
+        },
+    );
+    capture.requested = true;
+}
+
+/// Écrit une image Bevy au format PNG
+/// 
+/// Utilisée aussi par l'enregistrement de séquences (`systems::record`).
+pub(crate) fn save_png(image: &Image, path: &Path) -> io::Result<()> {
+    let dynamic = image
+        .clone()
+        .try_into_dynamic()
+        .map_err(io::Error::other)?;
+    
+    dynamic.to_rgba8().save(path).map_err(io::Error::other)
+}
@@ -0,0 +1,174 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                   FICHIER: src/systems/layout.rs                         ║
+// ║  Mise en page du logo dans la fenêtre                                    ║
+// ║  Rôle : Centrer et mettre à l'échelle le logo selon la fenêtre          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module layout
+//! 
+//! Quand la fenêtre est redimensionnée, le logo garde sa taille en pixels
+//! et peut être coupé. Ce module ajuste le `Transform` de l'entité racine
+//! `LogoRoot` pour que le logo entier tienne dans la fenêtre :
+//! - mise à l'échelle uniforme (les proportions sont conservées)
+//! - recentrage sur le centre de la boîte englobante
+//! - marge configurable (`LogoConfig::fit_margin`)
+//! - décalage configurable (`LogoConfig::logo_offset`)
+//! - étirement configurable (`LogoConfig::logo_scale`)
+//! - mosaïque optionnelle (`LogoConfig::tile`) : une racine par case,
+//!   la mosaïque entière tient dans la fenêtre
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+use crate::components::{LogoRoot, LogoTile};
+use crate::config::LogoConfig;
+use crate::geometry;
+
+/// Calcule la boîte englobante (min, max) du logo complet
+/// 
+/// Réunit :
+/// - la boîte des parties de la lettre (`bounding_box_of_parts`)
+/// - le carré circonscrit à l'anneau, triangles extérieurs compris
+///   (rayon + hauteur d'un triangle extérieur)
+/// 
+/// # Arguments
+/// * `config` - Configuration du logo (rayon, taille des triangles)
+pub fn logo_bounds(config: &LogoConfig) -> (Vec2, Vec2) {
+    let letter_parts = config.letter_parts();
+    let (parts_min, parts_max) = geometry::bounding_box_of_parts(&letter_parts);
+    
+    let ring_extent = Vec2::splat(config.circle_radius + config.exterior_height());
+    
+    (parts_min.min(-ring_extent), parts_max.max(ring_extent))
+}
+
+/// Décalages des logos de la mosaïque, dans le repère du logo
+/// 
+/// Cases parcourues ligne par ligne, de haut en bas et de gauche à droite.
+/// Le pas entre deux cases est la taille de `logo_bounds` plus
+/// l'espacement ; la mosaïque est centrée sur l'origine.
+/// 
+/// Une mosaïque de 0 rangée ou 0 colonne compte 1 rangée ou 1 colonne :
+/// il y a toujours au moins un logo (la racine principale).
+/// 
+/// # Retour
+/// `[Vec2::ZERO]` sans mosaïque (`LogoConfig::tile` à `None`)
+pub fn tile_offsets(config: &LogoConfig) -> Vec<Vec2> {
+    let Some((rows, cols, spacing)) = config.tile else {
+        return vec![Vec2::ZERO];
+    };
+    let (rows, cols) = (rows.max(1), cols.max(1));
+    
+    let (min, max) = logo_bounds(config);
+    let pitch = max - min + Vec2::splat(spacing);
+    let center = Vec2::new(cols as f32 - 1.0, rows as f32 - 1.0) / 2.0;
+    
+    (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .map(|(row, col)| Vec2::new(col as f32 - center.x, center.y - row as f32) * pitch)
+        .collect()
+}
+
+/// Boîte englobante de la mosaïque complète (un seul logo sans mosaïque)
+pub fn tiled_bounds(config: &LogoConfig) -> (Vec2, Vec2) {
+    let (min, max) = logo_bounds(config);
+    tile_offsets(config)
+        .into_iter()
+        .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(low, high), offset| {
+            (low.min(min + offset), high.max(max + offset))
+        })
+}
+
+/// Calcule le `Transform` de la racine pour que le logo tienne dans une zone
+/// 
+/// # Arguments
+/// * `bounds` - Boîte englobante du logo (min, max)
+/// * `viewport` - Taille de la zone visible (largeur, hauteur) en pixels
+/// * `margin` - Marge à conserver de chaque côté, en pixels
+/// 
+/// # Formule
+/// échelle = min((largeur - 2×marge) / largeur_logo, (hauteur - 2×marge) / hauteur_logo)
+/// translation = -centre_logo × échelle
+pub fn fit_transform(bounds: (Vec2, Vec2), viewport: Vec2, margin: f32) -> Transform {
+    let (min, max) = bounds;
+    let size = (max - min).max(Vec2::splat(f32::EPSILON));
+    let available = (viewport - Vec2::splat(2.0 * margin)).max(Vec2::ONE);
+    
+    // Échelle uniforme : le côté le plus contraint l'emporte
+    let scale = (available / size).min_element();
+    
+    // Recentrage : le centre de la boîte est ramené à l'origine
+    let center = (min + max) / 2.0;
+    
+    Transform::from_translation((-center * scale).extend(0.0))
+        .with_scale(Vec3::new(scale, scale, 1.0))
+}
+
+/// Calcule le `Transform` de la racine du logo pour une zone visible
+/// 
+/// Ajustement de `fit_transform` appliqué à `tiled_bounds` (le logo seul
+/// sans mosaïque) étirée par `LogoConfig::logo_scale`, puis décalage de
+/// `LogoConfig::logo_offset` (en pixels, non mis à l'échelle).
+/// L'échelle obtenue est celle de l'ajustement multipliée par l'étirement.
+/// 
+/// # Arguments
+/// * `config` - Configuration du logo (dimensions, marge, décalage, étirement)
+/// * `viewport` - Taille de la zone visible (largeur, hauteur) en pixels
+pub fn root_transform(config: &LogoConfig, viewport: Vec2) -> Transform {
+    let (min, max) = tiled_bounds(config);
+    let stretched = (min * config.logo_scale, max * config.logo_scale);
+    
+    let mut transform = fit_transform(stretched, viewport, config.fit_margin);
+    transform.scale *= config.logo_scale.extend(1.0);
+    transform.translation += config.logo_offset.extend(0.0);
+    transform
+}
+
+/// Calcule le `Transform` de la racine d'une case de la mosaïque
+/// 
+/// `root_transform`, décalé de `tile_offset` mis à l'échelle.
+/// 
+/// # Arguments
+/// * `config` - Configuration du logo
+/// * `viewport` - Taille de la zone visible (largeur, hauteur) en pixels
+/// * `tile_offset` - Décalage de la case (voir `tile_offsets`)
+pub fn tile_transform(config: &LogoConfig, viewport: Vec2, tile_offset: Vec2) -> Transform {
+    let mut transform = root_transform(config, viewport);
+    transform.translation += (tile_offset * transform.scale.truncate()).extend(0.0);
+    transform
+}
+
+/// Ajuste le logo à la fenêtre lors d'un redimensionnement
+/// 
+/// S'exécute quand :
+/// - un événement `WindowResized` est reçu
+/// - une nouvelle racine `LogoRoot` vient d'être créée (démarrage, régénération)
+/// 
+/// La taille utilisée est celle de la fenêtre principale, en pixels logiques
+/// (les unités du monde d'une `Camera2d` par défaut).
+pub fn fit_logo_to_window_system(
+    mut resized: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<LogoConfig>,
+    mut roots: Query<(&mut Transform, Ref<LogoRoot>, Option<&LogoTile>)>,
+) {
+    let window_changed = resized.read().count() > 0;
+    let root_added = roots.iter().any(|(_, root, _)| root.is_added());
+    if !window_changed && !root_added {
+        return;
+    }
+    
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    
+    // === CALCUL DU TRANSFORM CIBLE ===
+    let viewport = Vec2::new(window.width(), window.height());
+    
+    // === APPLICATION AUX RACINES ===
+    for (mut transform, _, tile) in &mut roots {
+        let target = tile_transform(&config, viewport, tile.map_or(Vec2::ZERO, |tile| tile.offset));
+        transform.translation = target.translation;
+        transform.scale = target.scale;
+    }
+}
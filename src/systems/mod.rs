@@ -7,8 +7,73 @@
 //! Module systems
 //! 
 //! Contient tous les systèmes Bevy de l'application.
-//! Actuellement : un seul système de setup pour l'initialisation.
+//! - setup : initialisation de la scène au démarrage
+//! - regenerate : reconstruction du logo à l'exécution
+//! - layout : ajustement du logo à la taille de la fenêtre
+//! - animation : animations basées sur le temps (fondu d'introduction, ...)
+//! - debug : aides visuelles de débogage (fil de fer, gizmos)
+//! - shadow : ombres portées des éléments
+//! - headless : rendu sans fenêtre vers une image PNG
+//! - camera : déplacement et zoom de la caméra à la souris
+//! - background : image de fond optionnelle
+//! - theme : changement de thème de couleurs (touche T)
+//! - selection : contour animé des parties sélectionnées
+//! - glow : variante lumineuse (matériau émissif et bloom)
+//! - stats : nombre de vertices, triangles et entités du logo
+//! - record : enregistrement de l'animation en séquence d'images PNG
 
 /// Module de configuration initiale
 /// Contient le système qui crée tous les éléments visuels au démarrage
 pub mod setup;
+
+/// Module de régénération
+/// Contient l'événement RegenerateLogo et le système qui reconstruit le logo
+pub mod regenerate;
+
+/// Module de mise en page
+/// Contient le système qui adapte le logo à la taille de la fenêtre
+pub mod layout;
+
+/// Module d'animation
+/// Contient les systèmes qui animent les éléments au fil du temps
+pub mod animation;
+
+/// Module de débogage
+/// Contient les systèmes d'aide visuelle (mode fil de fer, gizmos)
+pub mod debug;
+
+/// Module des ombres portées
+/// Contient le système qui ajoute une ombre sous chaque élément
+pub mod shadow;
+
+/// Module de rendu sans fenêtre
+/// Contient les systèmes qui capturent le logo dans une image PNG
+pub mod headless;
+
+/// Module de la caméra
+/// Contient les systèmes de glisser-déplacer (avec inertie) et de zoom de la caméra
+pub mod camera;
+
+/// Module d'enregistrement
+/// Contient les systèmes qui écrivent l'animation image par image
+pub mod record;
+
+/// Module des statistiques
+/// Contient la ressource LogoStats et le système qui la tient à jour
+pub mod stats;
+
+/// Module de la variante lumineuse
+/// Contient le système qui rend l'anneau arc-en-ciel et les petits cercles émissifs
+pub mod glow;
+
+/// Module de la sélection
+/// Contient les systèmes du contour en pointillés défilants des parties sélectionnées
+pub mod selection;
+
+/// Module des thèmes de couleurs
+/// Contient la ressource du thème courant et le système de changement (touche T)
+pub mod theme;
+
+/// Module de l'image de fond
+/// Contient le chargement du sprite de fond et son ajustement à la fenêtre
+pub mod background;
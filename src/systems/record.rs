@@ -0,0 +1,108 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                   FICHIER: src/systems/record.rs                         ║
+// ║  Enregistrement de l'animation image par image                           ║
+// ║  Rôle : Écrire une séquence PNG numérotée (pour en faire un GIF)        ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module record
+//! 
+//! Actif quand `LogoConfig::record_frames` est défini (voir `RecordSpec`) :
+//! 1. `start_recording_system` (Startup) : création du dossier de sortie
+//!    et passage du temps en pas fixes de 1 / fps seconde
+//! 2. `record_frame_system` (Update) : une capture de la fenêtre par frame,
+//!    écrite dans `frame_NNNN.png`
+//! 3. Une fois toutes les images écrites, l'application se termine
+//! 
+//! Les animations (fondu, éclatement, ...) lisent `Time` : avec des pas
+//! fixes, la séquence est reproductible image par image.
+
+use std::fs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::time::TimeUpdateStrategy;
+use crate::config::{LogoConfig, RecordSpec};
+use crate::systems::headless;
+
+/// État de l'enregistrement en cours
+#[derive(Resource)]
+pub struct FrameRecorder {
+    /// Paramètres de la séquence
+    spec: RecordSpec,
+    
+    /// Index de la prochaine image à demander
+    next_frame: usize,
+    
+    /// Nombre d'images reçues et écrites (mis à jour par les observateurs)
+    written: Arc<AtomicUsize>,
+}
+
+/// Prépare l'enregistrement si `LogoConfig::record_frames` est défini
+/// 
+/// - crée le dossier de sortie
+/// - remplace l'horloge réelle par des pas fixes (`TimeUpdateStrategy::ManualDuration`)
+/// - ajoute la ressource `FrameRecorder`, qui active `record_frame_system`
+/// 
+/// Si le dossier ne peut pas être créé, un avertissement est affiché
+/// et l'application fonctionne normalement.
+pub fn start_recording_system(mut commands: Commands, config: Res<LogoConfig>) {
+    let Some(spec) = config.record_frames.clone() else {
+        return;
+    };
+    
+    if let Err(error) = fs::create_dir_all(&spec.output_dir) {
+        println!("⚠️ Dossier {} impossible à créer : {}", spec.output_dir.display(), error);
+        return;
+    }
+    
+    // === TEMPS EN PAS FIXES ===
+    let step = Duration::from_secs_f64(1.0 / spec.fps.max(1) as f64);
+    commands.insert_resource(TimeUpdateStrategy::ManualDuration(step));
+    
+    println!("🎬 Enregistrement de {} images dans {}", spec.frame_count(), spec.output_dir.display());
+    commands.insert_resource(FrameRecorder {
+        spec,
+        next_frame: 0,
+        written: Arc::new(AtomicUsize::new(0)),
+    });
+}
+
+/// Capture une image par frame, puis termine l'application
+/// 
+/// Chaque frame demande une capture de la fenêtre principale ;
+/// l'observateur de `ScreenshotCaptured` écrit l'image dans
+/// `RecordSpec::frame_path`. Les captures arrivent quelques frames
+/// plus tard : l'application se termine quand toutes sont écrites.
+pub fn record_frame_system(
+    mut commands: Commands,
+    mut recorder: ResMut<FrameRecorder>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let frame_count = recorder.spec.frame_count();
+    
+    // === FIN DE L'ENREGISTREMENT ===
+    if recorder.written.load(Ordering::SeqCst) >= frame_count {
+        println!("🎬 {} images enregistrées", frame_count);
+        exit.write(AppExit::Success);
+        return;
+    }
+    if recorder.next_frame >= frame_count {
+        return;
+    }
+    
+    // === DEMANDE DE CAPTURE ===
+    let path = recorder.spec.frame_path(recorder.next_frame);
+    let written = recorder.written.clone();
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |trigger: Trigger<ScreenshotCaptured>| {
+            if let Err(error) = headless::save_png(&trigger.event().0, &path) {
+                println!("⚠️ Écriture de {} impossible : {}", path.display(), error);
+            }
+            written.fetch_add(1, Ordering::SeqCst);
+        },
+    );
+    recorder.next_frame += 1;
+}
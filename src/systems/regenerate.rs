@@ -0,0 +1,198 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                 FICHIER: src/systems/regenerate.rs                       ║
+// ║  Reconstruction du logo à l'exécution                                    ║
+// ║  Rôle : Recréer tous les éléments à partir de la LogoConfig courante    ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module regenerate
+//! 
+//! Permet de modifier la ressource `LogoConfig` pendant l'exécution
+//! et de voir le logo se reconstruire sans redémarrer l'application.
+//! 
+//! Fonctionnement :
+//! 1. Un système (ou l'utilisateur via la touche G) envoie `RegenerateLogo`
+//! 2. `regenerate_logo_system` supprime la racine `LogoRoot` et ses enfants
+//! 3. Les fonctions de création sont rappelées avec la configuration courante
+//! 
+//! La caméra ne fait pas partie de la hiérarchie du logo : elle est conservée.
+//! 
+//! Les touches +/- modifient le nombre de triangles extérieurs puis
+//! déclenchent une régénération (réglage en direct).
+//! 
+//! La ressource `ConfigMorph` fait passer progressivement le logo d'une
+//! configuration à une autre (`LogoConfig::lerp`), avec une régénération
+//! à chaque frame.
+
+use bevy::prelude::*;
+use crate::components::LogoRoot;
+use crate::config::{self, LogoConfig};
+use super::setup::spawn_logo;
+
+/// Événement demandant la reconstruction complète du logo
+/// 
+/// Envoyer cet événement après avoir modifié `LogoConfig` :
+/// ```ignore
+/// fn mon_systeme(mut config: ResMut<LogoConfig>, mut events: EventWriter<RegenerateLogo>) {
+///     config.circle_radius = 250.0;
+///     events.write(RegenerateLogo);
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct RegenerateLogo;
+
+/// Envoie `RegenerateLogo` quand la touche G est pressée
+/// 
+/// Pratique pour tester la régénération à la main.
+pub fn regenerate_on_key_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<RegenerateLogo>,
+) {
+    if keys.just_pressed(KeyCode::KeyG) {
+        events.write(RegenerateLogo);
+    }
+}
+
+/// Retourne le nombre de triangles extérieurs suivant ou précédent
+/// 
+/// Parcourt `EXTERIOR_COUNT_STEPS` (diviseurs de 360 entre 6 et 120).
+/// Un nombre absent de la liste passe à l'étape voisine la plus proche ;
+/// aux extrémités, la valeur reste bornée.
+/// 
+/// # Arguments
+/// * `current` - Nombre actuel de triangles
+/// * `increase` - Vrai pour l'étape supérieure, faux pour l'inférieure
+pub fn step_exterior_count(current: usize, increase: bool) -> usize {
+    let steps = &config::EXTERIOR_COUNT_STEPS;
+    let next = if increase {
+        steps.iter().copied().find(|&count| count > current)
+    } else {
+        steps.iter().rev().copied().find(|&count| count < current)
+    };
+    
+    next.unwrap_or(if increase { steps[steps.len() - 1] } else { steps[0] })
+}
+
+/// Ajuste le nombre de triangles extérieurs avec les touches + et -
+/// 
+/// Touches acceptées : + (ou =, même touche sans Maj) et - du clavier
+/// principal, + et - du pavé numérique.
+/// Modifie `LogoConfig::exterior_triangles_count` puis envoie
+/// `RegenerateLogo` pour reconstruire l'anneau immédiatement.
+pub fn adjust_exterior_count_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<LogoConfig>,
+    mut events: EventWriter<RegenerateLogo>,
+) {
+    let increase = keys.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]);
+    let decrease = keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]);
+    if increase == decrease {
+        return;
+    }
+    
+    let count = step_exterior_count(config.exterior_triangles_count, increase);
+    if count == config.exterior_triangles_count {
+        return;
+    }
+    
+    config.exterior_triangles_count = count;
+    events.write(RegenerateLogo);
+    println!("🔺 Triangles extérieurs : {}", count);
+}
+
+/// Transition animée entre deux configurations
+/// 
+/// Tant que la ressource existe, `config_morph_system` remplace
+/// `LogoConfig` par `LogoConfig::lerp(from, to, t)`, t allant de 0 à 1
+/// en `duration` secondes, puis retire la ressource.
+/// ```ignore
+/// commands.insert_resource(ConfigMorph::new(config.clone(), Preset::Poster.config(), 2.0));
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct ConfigMorph {
+    /// Configuration de départ (t = 0)
+    pub from: LogoConfig,
+    
+    /// Configuration d'arrivée (t = 1)
+    pub to: LogoConfig,
+    
+    /// Durée de la transition, en secondes
+    pub duration: f32,
+    
+    /// Temps écoulé depuis le début, en secondes
+    pub elapsed: f32,
+}
+
+impl ConfigMorph {
+    /// Crée une transition qui démarre à la frame suivante
+    pub fn new(from: LogoConfig, to: LogoConfig, duration: f32) -> Self {
+        Self { from, to, duration, elapsed: 0.0 }
+    }
+    
+    /// Avancement de la transition, entre 0 et 1
+    /// 
+    /// Une durée nulle ou négative termine la transition immédiatement.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+/// Fait avancer la transition `ConfigMorph` et régénère le logo
+/// 
+/// À exécuter avant `regenerate_logo_system`, seulement si la ressource
+/// existe (`resource_exists::<ConfigMorph>`). À la fin de la transition,
+/// `LogoConfig` vaut exactement `to` et la ressource est retirée.
+pub fn config_morph_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut morph: ResMut<ConfigMorph>,
+    mut config: ResMut<LogoConfig>,
+    mut events: EventWriter<RegenerateLogo>,
+) {
+    morph.elapsed += time.delta_secs();
+    let t = morph.progress();
+    
+    *config = LogoConfig::lerp(&morph.from, &morph.to, t);
+    events.write(RegenerateLogo);
+    
+    if t >= 1.0 {
+        commands.remove_resource::<ConfigMorph>();
+        println!("🔀 Transition de configuration terminée");
+    }
+}
+
+/// Reconstruit le logo à la réception de `RegenerateLogo`
+/// 
+/// # Étapes
+/// 1. Vider la file d'événements (plusieurs événements = une seule reconstruction)
+/// 2. Supprimer la racine du logo (la suppression est récursive : les enfants suivent)
+/// 3. Recréer les éléments avec la `LogoConfig` courante
+/// 
+/// Les meshes et matériaux des anciennes entités sont libérés
+/// automatiquement par Bevy quand plus aucun handle ne les référence.
+pub fn regenerate_logo_system(
+    mut events: EventReader<RegenerateLogo>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<LogoConfig>,
+    logo_roots: Query<Entity, With<LogoRoot>>,
+) {
+    // === LECTURE DES ÉVÉNEMENTS ===
+    if events.read().count() == 0 {
+        return;
+    }
+    
+    // === SUPPRESSION DES ANCIENS ÉLÉMENTS ===
+    for root in &logo_roots {
+        commands.entity(root).despawn();
+    }
+    
+    // === RECRÉATION ===
+    spawn_logo(&mut commands, &mut meshes, &mut materials, &config);
+    
+    println!("🔄 Logo régénéré");
+}
@@ -0,0 +1,187 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: src/systems/selection.rs                       ║
+// ║  Retour visuel de la sélection d'une partie du "R"                       ║
+// ║  Rôle : Contour en pointillés défilants ("fourmis en marche")           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module selection
+//! 
+//! Une partie du "R" est sélectionnée en lui ajoutant le composant
+//! `Selected` (et désélectionnée en le retirant). Ce module :
+//! - sélectionne ou désélectionne la partie cliquée (bouton gauche, hors
+//!   mode règle) et vide la sélection avec Échap
+//! - crée un contour en pointillés (`SelectionOutline`) sur chaque
+//!   partie nouvellement sélectionnée
+//! - supprime ce contour à la désélection
+//! - fait défiler les tirets (`LogoConfig::selection_dash_speed`), seulement
+//!   tant qu'une partie est sélectionnée, au rythme de `AnimationState`
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::build;
+use crate::components::{MainCamera, RPart, Selected, SelectionOutline};
+use crate::config::LogoConfig;
+use crate::geometry::{self, RPartDefinition};
+use crate::systems::animation::AnimationState;
+use crate::systems::debug::Ruler;
+
+/// Partie candidate au clic : entité, partie, position dans la scène, visibilité
+type PickablePart<'a> = (Entity, &'a RPart, &'a GlobalTransform, Option<&'a Visibility>, Has<Selected>);
+
+/// Retourne la partie située sous `point` (coordonnées du monde)
+/// 
+/// Chaque candidat est `(clé, index de la partie, transformation globale)` ;
+/// le point est ramené dans le repère de la partie puis testé contre le
+/// contour `letter_parts[index]`. Si plusieurs parties se recouvrent,
+/// la plus en avant (Z global le plus grand) l'emporte.
+/// 
+/// # Retour
+/// La clé du candidat touché, `None` si le point n'est sur aucune partie
+pub fn pick_part<T>(
+    point: Vec2,
+    letter_parts: &[RPartDefinition],
+    candidates: impl IntoIterator<Item = (T, usize, GlobalTransform)>,
+) -> Option<T> {
+    candidates
+        .into_iter()
+        .filter(|(_, index, transform)| {
+            let local = transform.affine().inverse().transform_point3(point.extend(0.0));
+            letter_parts
+                .get(*index)
+                .is_some_and(|part| geometry::point_in_polygon(local.truncate(), &part.points))
+        })
+        .max_by(|(_, _, a), (_, _, b)| a.translation().z.total_cmp(&b.translation().z))
+        .map(|(key, _, _)| key)
+}
+
+/// Sélectionne ou désélectionne la partie du "R" cliquée
+/// 
+/// Un clic gauche sur une partie visible bascule son composant `Selected` ;
+/// un clic hors des parties ne change rien. Inactif en mode règle
+/// (`Ruler::active`), où le clic sert à mesurer.
+pub fn select_part_on_click_system(
+    mut commands: Commands,
+    buttons: Res<ButtonInput<MouseButton>>,
+    ruler: Option<Res<Ruler>>,
+    config: Res<LogoConfig>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    parts: Query<PickablePart>,
+) {
+    if !buttons.just_pressed(MouseButton::Left) || ruler.is_some_and(|ruler| ruler.active) {
+        return;
+    }
+    let Some(cursor) = windows.single().ok().and_then(|window| window.cursor_position()) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Ok(point) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+    
+    let candidates = parts
+        .iter()
+        .filter(|(_, _, _, visibility, _)| visibility != &Some(&Visibility::Hidden))
+        .map(|(entity, part, transform, _, selected)| ((entity, selected), part.index, *transform));
+    let Some((entity, selected)) = pick_part(point, &config.letter_parts(), candidates) else {
+        return;
+    };
+    
+    if selected {
+        commands.entity(entity).remove::<Selected>();
+    } else {
+        commands.entity(entity).insert(Selected);
+    }
+}
+
+/// Vide la sélection avec la touche Échap
+pub fn clear_selection_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    selected: Query<Entity, With<Selected>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        for entity in &selected {
+            commands.entity(entity).remove::<Selected>();
+        }
+    }
+}
+
+/// Crée le contour de sélection des parties nouvellement sélectionnées
+pub fn spawn_selection_outline_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<LogoConfig>,
+    parts: Query<(Entity, &RPart), Added<Selected>>,
+) {
+    if parts.is_empty() {
+        return;
+    }
+    
+    let letter_parts = config.letter_parts();
+    for (entity, part) in &parts {
+        let Some(definition) = letter_parts.get(part.index) else {
+            continue;
+        };
+        let (mesh, material, transform) = build::build_selection_outline(&config, definition, 0.0);
+        commands.entity(entity).with_child((
+            Mesh2d(meshes.add(mesh)),
+            MeshMaterial2d(materials.add(material)),
+            transform,
+            SelectionOutline { index: part.index, offset: 0.0 },
+        ));
+    }
+}
+
+/// Supprime le contour des parties désélectionnées
+pub fn despawn_selection_outline_system(
+    mut commands: Commands,
+    mut deselected: RemovedComponents<Selected>,
+    children: Query<&Children>,
+    outlines: Query<(), With<SelectionOutline>>,
+) {
+    for entity in deselected.read() {
+        // La partie a pu être supprimée (régénération) : ses enfants aussi
+        let Ok(part_children) = children.get(entity) else {
+            continue;
+        };
+        for child in part_children.iter().filter(|child| outlines.contains(*child)) {
+            commands.entity(child).despawn();
+        }
+    }
+}
+
+/// Fait défiler les tirets des contours de sélection
+/// 
+/// Le mesh est recalculé à chaque frame avec le nouveau décalage
+/// (quelques dizaines de tirets : coût négligeable). À exécuter
+/// seulement si une partie est sélectionnée :
+/// `.run_if(any_with_component::<Selected>)`.
+/// Suit la pause et la vitesse de `AnimationState`.
+pub fn marching_ants_system(
+    time: Res<Time>,
+    animation: Res<AnimationState>,
+    config: Res<LogoConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut outlines: Query<(&mut SelectionOutline, &Mesh2d)>,
+) {
+    let delta = animation.delta_secs(&time);
+    if delta == 0.0 {
+        return;
+    }
+    let letter_parts = config.letter_parts();
+    
+    for (mut outline, mesh_handle) in &mut outlines {
+        let Some(definition) = letter_parts.get(outline.index) else {
+            continue;
+        };
+        outline.offset += config.selection_dash_speed * delta;
+        
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            *mesh = build::selection_outline_mesh(&config, definition, outline.offset);
+        }
+    }
+}
@@ -14,32 +14,52 @@
 //! - Les triangles extérieurs (arc-en-ciel)
 //! - Les triangles intérieurs (pentagone)
 //! - Les petits cercles centraux
-//! - Le logo "R" personnalisé
+//! - Le logo "R" personnalisé (ou une autre lettre, voir `LogoConfig::letter`)
 //! 
 //! Organisation du rendu par profondeur Z :
 //! - Z = 0.0  : Cercle principal (arrière-plan)
 //! - Z = 0.1  : Triangles extérieurs
 //! - Z = 0.2  : Triangles intérieurs
+//! - Z = 0.22 : Disque central (optionnel)
 //! - Z = 0.3  : Petits cercles
 //! - Z = 0.4+ : Logo "R" (8 parties de 0.40 à 0.47)
 
 use bevy::prelude::*;
-use crate::{config, materials, geometry};
+use bevy::core_pipeline::bloom::Bloom;
+use crate::build::{self, BuiltElement, LayerKind};
+use crate::geometry;
+use crate::systems::layout;
+use crate::geometry::RPartDefinition;
+use crate::config::{self, LogoConfig, RainbowStyle};
+use crate::components::{
+    CenterDot, CenterHub, CenterStar, CentroidMarker, ExteriorTriangle, GridBackground, InteriorTriangle, IntroFade, LogoRoot, LogoTile,
+    MainCamera, MainCircle, MergedLetter, ROutline, RPart, RPartHome, RainbowRing, SpiralBackground,
+};
 
 /// Système principal d'initialisation
 /// 
-/// Ce système est exécuté une seule fois au démarrage (Startup schedule).
-/// Il reçoit trois ressources mutables de Bevy :
+/// Ce système est exécuté au démarrage (Startup schedule).
+/// Il reçoit trois ressources mutables de Bevy et la configuration du logo :
 /// 
 /// # Arguments
 /// * `commands` - File de commandes pour créer/supprimer des entités
 /// * `meshes` - Collection Asset des meshes 3D/2D
 /// * `materials` - Collection Asset des matériaux (couleurs, textures)
+/// * `config` - Ressource `LogoConfig` (dimensions, nombres, couleurs)
+/// * `cameras` - Caméras principales déjà présentes
+/// * `logo_roots` - Racines de logo déjà présentes
 /// 
 /// # Ordre d'exécution
 /// 1. Création de la caméra (sans elle, rien n'est visible)
-/// 2. Création des éléments de fond vers l'avant (ordre Z croissant)
-/// 3. Affichage du résumé dans la console
+/// 2. Suppression d'un éventuel logo précédent
+/// 3. Création des éléments de fond vers l'avant (ordre Z croissant)
+/// 4. Affichage du résumé dans la console
+/// 
+/// # Ré-exécution
+/// Le système peut être relancé sans effet de bord : la caméra n'est créée
+/// que si aucune `MainCamera` n'existe, et l'ancien logo est supprimé
+/// avant d'être recréé (toutes ses `LogoRoot`, une par case de la
+/// mosaïque : aucune ne survit d'une exécution à l'autre).
 /// 
 /// # Note technique
 /// Les systèmes Bevy sont des fonctions qui reçoivent des paramètres
@@ -49,28 +69,292 @@ pub fn setup_system(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<LogoConfig>,
+    cameras: Query<(), With<MainCamera>>,
+    logo_roots: Query<Entity, With<LogoRoot>>,
 ) {
     // === CAMÉRA 2D ===
-    // Obligatoire : sans caméra, aucun élément n'est rendu
-    // Camera2d::default() crée une caméra orthographique 2D centrée
-    commands.spawn(Camera2d::default());
+    if cameras.is_empty() {
+        spawn_camera(&mut commands, &config);
+    }
+    
+    // === COULEUR DE FOND ===
+    commands.insert_resource(ClearColor(config.background_color));
+    
+    // === SUPPRESSION D'UN LOGO PRÉCÉDENT ===
+    // La suppression est récursive : les enfants suivent la racine
+    for root in &logo_roots {
+        commands.entity(root).despawn();
+    }
     
     // === CRÉATION DES ÉLÉMENTS VISUELS ===
+    spawn_logo(&mut commands, &mut meshes, &mut materials, &config);
+    
+    // === RÉSUMÉ CONSOLE ===
+    print_creation_summary(&config);
+}
+
+/// Crée la caméra principale
+/// 
+/// - Camera2d : caméra orthographique 2D centrée (obligatoire : sans
+///   caméra, aucun élément n'est rendu)
+/// - Msaa : anticrénelage des bords des formes (`LogoConfig::msaa_samples`)
+/// - MainCamera : marqueur utilisé par les systèmes de déplacement
+/// - Variante lumineuse : HDR et bloom si `LogoConfig::glow` est actif
+fn spawn_camera(commands: &mut Commands, config: &LogoConfig) {
+    let mut camera = commands.spawn((Camera2d, config.msaa(), MainCamera));
+    
+    // Le bloom exige une caméra HDR
+    if config.glow {
+        camera.insert((Camera { hdr: true, ..default() }, Bloom::NATURAL));
+    }
+}
+
+/// Crée tous les éléments visuels du logo (sans la caméra)
+/// 
+/// Appelée au démarrage par `setup_system`, et à nouveau par le système
+/// de régénération après suppression des anciens éléments.
+/// Chaque entité créée porte un composant marqueur (voir `components`)
+/// et est un enfant de l'entité racine `LogoRoot`.
+/// 
+/// # Mosaïque
+/// Avec `LogoConfig::tile`, le logo est construit une seule fois puis
+/// copié dans chaque case (voir `spawn_tile_copies`).
+/// 
+/// # Retour
+/// L'entité racine du logo (première case de la mosaïque)
+pub(crate) fn spawn_logo(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+) -> Entity {
+    // === ENTITÉ RACINE ===
+    // Transform et Visibility sont nécessaires pour propager
+    // position et visibilité aux enfants
+    // Décalage et étirement sont réappliqués par l'ajustement à la fenêtre (layout)
+    let offsets = layout::tile_offsets(config);
+    let root = commands
+        .spawn((
+            LogoRoot,
+            LogoTile { offset: offsets[0] },
+            Transform::from_translation((config.logo_offset + offsets[0]).extend(0.0))
+                .with_scale(config.logo_scale.extend(1.0)),
+            Visibility::default(),
+        ))
+        .id();
+    
     // Ordre logique : du fond vers l'avant (mais le Z détermine l'ordre réel)
+    if config.show_grid {
+        create_grid(commands, meshes, materials, config, root);
+    }
+    if config.show_spiral {
+        create_spiral(commands, meshes, materials, config, root);
+    }
+    create_main_circle(commands, meshes, materials, config, root);
+    create_exterior_triangles(commands, meshes, materials, config, root);
+    create_interior_triangles(commands, meshes, materials, config, root);
+    if config.center_hub {
+        create_center_hub(commands, meshes, materials, config, root);
+    }
+    let letter_parts = create_letter_logo(commands, meshes, materials, config, root);
     
-    create_main_circle(&mut commands, &mut meshes, &mut materials);
-    create_exterior_triangles(&mut commands, &mut meshes, &mut materials);
-    create_interior_triangles(&mut commands, &mut meshes, &mut materials);
-    create_r_logo(&mut commands, &mut meshes, &mut materials);
+    if config.show_centroids {
+        create_centroid_markers(commands, meshes, materials, config, root);
+    }
     
-    // === RÉSUMÉ CONSOLE ===
-    print_creation_summary();
+    // === VÉRIFICATION DES PROFONDEURS ===
+    warn_z_collisions(config, &letter_parts);
+    if config.debug_geometry {
+        report_part_overlaps(&letter_parts);
+    }
+    
+    spawn_tile_copies(commands, config, root, &offsets[1..]);
+    root
+}
+
+/// Copie le logo dans les autres cases de la mosaïque
+/// 
+/// La racine est clonée avec toute sa descendance une fois ses enfants
+/// créés (commande exécutée après celles de `spawn_logo`). Les copies
+/// reprennent les handles `Mesh2d` et `MeshMaterial2d` de l'original :
+/// meshes et matériaux ne sont envoyés qu'une fois au GPU.
+/// 
+/// Les matériaux étant partagés, une modification sur place (fondu,
+/// thème) s'applique à toutes les cases.
+fn spawn_tile_copies(commands: &mut Commands, config: &LogoConfig, root: Entity, offsets: &[Vec2]) {
+    for &offset in offsets {
+        let transform = Transform::from_translation((config.logo_offset + offset).extend(0.0))
+            .with_scale(config.logo_scale.extend(1.0));
+        commands.queue(move |world: &mut World| {
+            let copy = world
+                .entity_mut(root)
+                .clone_and_spawn_with(|builder| {
+                    builder.linked_cloning(true);
+                });
+            world
+                .entity_mut(copy)
+                .insert((LogoTile { offset }, transform));
+        });
+    }
+}
+
+/// Retourne les parties de la lettre avec l'échelle et les profondeurs de la configuration
+/// 
+/// Parties de `LogoConfig::letter_parts` (personnalisées ou de la lettre,
+/// à l'échelle `letter_scale`), dont les z_order sont attribués par
+/// `assign_z_orders` à partir de `LogoConfig::r_base_z` et
+/// `LogoConfig::r_z_step`.
+/// 
+/// # Retour
+/// `None` sans parties personnalisées, si la lettre n'est pas définie
+/// dans `geometry::get_letter_parts`
+pub(crate) fn letter_parts_for(config: &LogoConfig) -> Option<Vec<RPartDefinition>> {
+    if config.custom_parts.is_none() && !geometry::SUPPORTED_LETTERS.contains(&config.letter) {
+        return None;
+    }
+    let mut parts = config.letter_parts();
+    geometry::assign_z_orders(&mut parts, config.r_base_z, config.r_z_step);
+    Some(parts)
+}
+
+/// Signale dans la console les couches qui partagent la même profondeur
+/// 
+/// Rassemble le Z de chaque couche (anneau, triangles, cercles, parties
+/// de la lettre) et affiche un avertissement pour chaque collision trouvée
+/// par `geometry::find_z_collisions`.
+fn warn_z_collisions(config: &LogoConfig, letter_parts: &[RPartDefinition]) {
+    // === LISTE DES COUCHES ===
+    // La lettre est remplacée par ses parties, avec leurs Z réels
+    let layers = build::layer_depths(config, letter_parts);
+    
+    // === RECHERCHE DES COLLISIONS ===
+    let z_values: Vec<f32> = layers.iter().map(|(_, z)| *z).collect();
+    for (i, j) in geometry::find_z_collisions(&z_values) {
+        println!("⚠️ '{}' et '{}' partagent la même profondeur (Z={})",
+                 layers[i].0, layers[j].0, layers[i].1);
+    }
+}
+
+/// Affiche dans la console les parties de la lettre qui se chevauchent
+/// 
+/// Pour chaque paire trouvée par `geometry::find_part_overlaps`, indique
+/// si les contours se touchent réellement ou seulement leurs boîtes
+/// englobantes. Activé par `LogoConfig::debug_geometry`.
+fn report_part_overlaps(letter_parts: &[RPartDefinition]) {
+    let overlaps = geometry::find_part_overlaps(letter_parts);
+    
+    println!("\n📐 CHEVAUCHEMENTS DES PARTIES ({} paires) :", overlaps.len());
+    for overlap in overlaps {
+        let (first, second) = (&letter_parts[overlap.first].name, &letter_parts[overlap.second].name);
+        if overlap.edges_intersect {
+            println!("   • '{}' / '{}' : contours en contact", first, second);
+        } else {
+            println!("   • '{}' / '{}' : boîtes seules (espace ou inclusion)", first, second);
+        }
+    }
+}
+
+/// Retourne le fondu d'introduction d'une couche, si l'animation est active
+/// 
+/// # Arguments
+/// * `config` - Configuration (lecture de `intro_animation`)
+/// * `layer` - Rang d'apparition : 0 anneau, 1 triangles extérieurs,
+///   2 triangles intérieurs, 3 petits cercles, 4 logo "R"
+fn intro_fade(config: &LogoConfig, layer: usize) -> Option<IntroFade> {
+    config
+        .intro_animation
+        .then(|| IntroFade::new(layer as f32 * config::INTRO_LAYER_DELAY))
+}
+
+/// Spawne un élément construit par le module `build`
+/// 
+/// Ajoute le mesh et le matériau aux assets, puis crée l'entité avec :
+/// - Mesh2d : quel mesh afficher
+/// - MeshMaterial2d : quelle apparence appliquer
+/// - Transform : position, rotation, échelle (relatifs à la racine)
+/// - le marqueur de l'élément, pour le retrouver dans les requêtes
+/// - ChildOf : rattachement à l'entité racine du logo
+/// - IntroFade : si `fade` est fourni (voir `intro_fade`)
+/// 
+/// # Retour
+/// Les commandes de l'entité créée (pour lui ajouter des enfants)
+fn spawn_element<'a>(
+    commands: &'a mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    root: Entity,
+    (mesh, material, transform): BuiltElement,
+    marker: impl Bundle,
+    fade: Option<IntroFade>,
+) -> EntityCommands<'a> {
+    let mut entity = commands.spawn((
+        Mesh2d(meshes.add(mesh)),
+        MeshMaterial2d(materials.add(material)),
+        transform,
+        marker,
+        ChildOf(root),
+    ));
+    if let Some(fade) = fade {
+        entity.insert(fade);
+    }
+    entity
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 //                  FONCTIONS DE CRÉATION DES ÉLÉMENTS
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Crée la grille de référence
+/// 
+/// Grille grise semi-transparente, enfant de la racine : ses coordonnées
+/// sont celles du logo (une ligne passe par l'origine du "R").
+/// Elle n'a pas de fondu d'introduction.
+/// 
+/// # Position
+/// - Z : GRID_Z (-100) - derrière tous les éléments
+fn create_grid(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
+) {
+    let (mesh, material, transform) = build::build_grid(config);
+    
+    commands.spawn((
+        Mesh2d(meshes.add(mesh)),
+        MeshMaterial2d(materials.add(material)),
+        transform,
+        GridBackground,
+        ChildOf(root),
+    ));
+}
+
+/// Crée la spirale décorative de fond
+/// 
+/// Comme la grille : enfant de la racine, sans fondu d'introduction.
+/// 
+/// # Position
+/// - Z : SPIRAL_Z (-50) - devant la grille, derrière le logo
+fn create_spiral(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
+) {
+    let (mesh, material, transform) = build::build_spiral(config);
+    
+    commands.spawn((
+        Mesh2d(meshes.add(mesh)),
+        MeshMaterial2d(materials.add(material)),
+        transform,
+        SpiralBackground,
+        ChildOf(root),
+    ));
+}
+
 /// Crée le cercle principal (anneau épais)
 /// 
 /// Le cercle principal est l'élément de fond du logo.
@@ -79,7 +363,8 @@ pub fn setup_system(
 /// # Paramètres utilisés
 /// - CIRCLE_RADIUS : rayon extérieur
 /// - CIRCLE_THICKNESS : épaisseur de l'anneau
-/// - CIRCLE_SEGMENTS : qualité du rendu
+/// - CIRCLE_SEGMENTS : qualité du rendu (ou calcul adaptatif selon
+///   le rayon si `adaptive_segments` est actif)
 /// 
 /// # Position
 /// - X, Y : (0, 0) - centre de l'écran
@@ -88,35 +373,11 @@ fn create_main_circle(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
 ) {
-    // === CALCUL DES DIMENSIONS ===
-    let outer_radius = config::CIRCLE_RADIUS;
-    let inner_radius = config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS;
-    
-    // === CRÉATION DU MESH ===
-    let circle_mesh = geometry::create_circle_mesh(
-        outer_radius,
-        inner_radius,
-        config::CIRCLE_SEGMENTS
-    );
-    
-    // === AJOUT AUX ASSETS ===
-    // add() retourne un Handle<Mesh> qui référence le mesh
-    let circle_handle = meshes.add(circle_mesh);
-    
-    // === MATÉRIAU (COULEUR) ===
-    let circle_material = materials.add(materials::get_main_circle_color());
-    
-    // === CRÉATION DE L'ENTITÉ ===
-    // Une entité est créée avec 3 composants :
-    // - Mesh2d : quel mesh afficher
-    // - MeshMaterial2d : quelle apparence appliquer
-    // - Transform : position, rotation, échelle
-    commands.spawn((
-        Mesh2d(circle_handle),
-        MeshMaterial2d(circle_material),
-        Transform::from_xyz(0.0, 0.0, 0.0),
-    ));
+    let element = build::build_main_circle(config);
+    spawn_element(commands, meshes, materials, root, element, MainCircle, intro_fade(config, 0));
 }
 
 /// Crée les triangles extérieurs en arc-en-ciel
@@ -127,51 +388,30 @@ fn create_main_circle(
 /// # Caractéristiques
 /// - Nombre : EXTERIOR_TRIANGLES_COUNT (36)
 /// - Espacement : 10° entre chaque (360° / 36)
-/// - Couleur : progression HSL de 0° à 360°
+/// - Couleur : progression HSL sur 360° (départ et sens configurables)
 /// - Position : base du triangle sur le cercle
 /// - Z : 0.1 (devant le cercle principal)
 /// 
-/// # Algorithme
-/// Pour chaque position angulaire :
-/// 1. Calculer l'angle (i × 10°)
-/// 2. Calculer les 3 points du triangle
-/// 3. Créer le mesh triangulaire
-/// 4. Attribuer la couleur arc-en-ciel
-/// 5. Spawner l'entité
+/// Le calcul des triangles est fait par `build::build_exterior_triangles`.
+/// 
+/// # Style
+/// Avec `RainbowStyle::Gradient`, les triangles sont remplacés par un
+/// anneau unique en dégradé (voir `build::build_rainbow_ring`).
 fn create_exterior_triangles(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
 ) {
-    // === BOUCLE SUR TOUS LES TRIANGLES ===
-    for i in 0..config::EXTERIOR_TRIANGLES_COUNT {
-        // === CALCUL DE L'ANGLE ===
-        // Espacement régulier : 360° / nombre de triangles
-        let angle_degrees = (i as f32) * 10.0;
-        let base_angle = geometry::degrees_to_radians(angle_degrees);
-        
-        // === CALCUL DES POINTS ===
-        let (p1, p2, p3) = geometry::calculate_exterior_triangle_points(
-            base_angle,
-            config::CIRCLE_RADIUS,
-            config::SMALL_TRIANGLE_SIDE
-        );
-        
-        // === CRÉATION DU MESH ===
-        let triangle_mesh = geometry::create_triangle_from_points(p1, p2, p3);
-        let triangle_handle = meshes.add(triangle_mesh);
-        
-        // === COULEUR ARC-EN-CIEL ===
-        // Chaque triangle a une teinte différente
-        let color = materials::get_rainbow_color(i);
-        let triangle_material = materials.add(color);
-        
-        // === SPAWN ===
-        commands.spawn((
-            Mesh2d(triangle_handle),
-            MeshMaterial2d(triangle_material),
-            Transform::from_xyz(0.0, 0.0, 0.1),
-        ));
+    if config.rainbow_style == RainbowStyle::Gradient {
+        let element = build::build_rainbow_ring(config);
+        spawn_element(commands, meshes, materials, root, element, RainbowRing, intro_fade(config, 1));
+        return;
+    }
+    
+    for (i, element) in build::build_exterior_triangles(config).into_iter().enumerate() {
+        spawn_element(commands, meshes, materials, root, element, ExteriorTriangle { index: i }, intro_fade(config, 1));
     }
 }
 
@@ -183,142 +423,226 @@ fn create_exterior_triangles(
 /// 
 /// # Caractéristiques
 /// - Nombre : INTERIOR_TRIANGLES_COUNT (5)
-/// - Disposition : pentagone régulier (72° entre triangles)
+/// - Disposition : polygone régulier (360° / nombre entre triangles, 72° pour 5)
 /// - Orientation : un triangle pointe vers le haut (+90° offset)
-/// - Couleurs : palette de 5 couleurs distinctes
+/// - Couleurs : palette de 5 couleurs distinctes (répétée au-delà de 5),
+///   ou palette aléatoire si `random_palette_seed` est défini
 /// - Cercles centraux : blancs semi-transparents (alpha 0.7)
+/// - Étoiles sous les cercles : si `center_star_points` ≥ 3
 /// 
-/// # Ordre de création par triangle
-/// 1. Calculer l'angle de position
-/// 2. Calculer les 3 sommets du triangle
-/// 3. Créer et spawner le triangle (Z=0.2)
-/// 4. Calculer le centroïde
-/// 5. Créer et spawner le petit cercle (Z=0.3)
+/// Triangles (Z=0.2), étoiles (Z=0.25) et cercles (Z=0.3) sont calculés par
+/// `build::build_interior_triangles`, `build::build_center_stars` et
+/// `build::build_center_dots`.
 fn create_interior_triangles(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
 ) {
-    // === BOUCLE SUR LES 5 TRIANGLES ===
-    for i in 0..config::INTERIOR_TRIANGLES_COUNT {
-        // === ANGLE DE POSITION ===
-        // 72° d'espacement (360° / 5) + 90° pour orientation vers le haut
-        let angle_degrees = (i as f32) * 72.0 + 90.0;
-        let base_angle = geometry::degrees_to_radians(angle_degrees);
-        
-        // === RAYON INTÉRIEUR DISPONIBLE ===
-        let inner_radius = config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS;
-        
-        // === CALCUL DES POINTS DU TRIANGLE ===
-        let (p1, p2, p3) = geometry::calculate_interior_triangle_points(
-            base_angle,
-            inner_radius,
-            config::LARGE_TRIANGLE_SIDE
-        );
-        
-        // === CALCUL DU CENTROÏDE ===
-        // Le centroïde servira de position pour le petit cercle
-        let triangle_center = geometry::calculate_triangle_centroid(p1, p2, p3);
-        
-        // === CRÉATION DU TRIANGLE ===
-        let triangle_mesh = geometry::create_triangle_from_points(p1, p2, p3);
-        let triangle_handle = meshes.add(triangle_mesh);
-        
-        // Couleur spécifique à ce triangle
-        let triangle_color = materials::get_interior_triangle_color(i);
-        let triangle_material = materials.add(triangle_color);
-        
-        // Spawn du triangle à Z=0.2
-        commands.spawn((
-            Mesh2d(triangle_handle),
-            MeshMaterial2d(triangle_material),
-            Transform::from_xyz(0.0, 0.0, 0.2),
-        ));
-        
-        // === CRÉATION DU PETIT CERCLE CENTRAL ===
-        let small_circle_mesh = geometry::create_filled_circle_mesh(
-            config::SMALL_CIRCLE_RADIUS,
-            config::SMALL_CIRCLE_SEGMENTS
-        );
-        let small_circle_handle = meshes.add(small_circle_mesh);
-        
-        // Matériau blanc semi-transparent
-        let small_circle_material = materials.add(materials::get_small_circle_color());
-        
-        // Spawn du cercle au centroïde à Z=0.3
-        commands.spawn((
-            Mesh2d(small_circle_handle),
-            MeshMaterial2d(small_circle_material),
-            Transform::from_xyz(triangle_center.x, triangle_center.y, 0.3),
-        ));
+    // === TRIANGLES ===
+    for (i, element) in build::build_interior_triangles(config).into_iter().enumerate() {
+        spawn_element(commands, meshes, materials, root, element, InteriorTriangle { index: i }, intro_fade(config, 2));
+    }
+    
+    // === ÉTOILES SOUS LES PETITS CERCLES (OPTIONNELLES) ===
+    for (i, element) in build::build_center_stars(config).into_iter().enumerate() {
+        spawn_element(commands, meshes, materials, root, element, CenterStar { index: i }, intro_fade(config, 3));
+    }
+    
+    // === PETITS CERCLES CENTRAUX ===
+    for (i, element) in build::build_center_dots(config).into_iter().enumerate() {
+        spawn_element(commands, meshes, materials, root, element, CenterDot { index: i }, intro_fade(config, 3));
     }
 }
 
-/// Crée le logo "R" complet
+/// Crée le disque central qui unifie le fond de la lettre
 /// 
-/// Cette fonction génère toutes les parties du logo "R" de manière unifiée.
-/// Le logo est composé de 8 polygones distincts qui forment ensemble
-/// la lettre "R" stylisée.
+/// Cercle plein calculé par `build::build_center_hub`.
 /// 
-/// # Avantages de cette approche
-/// - Code unifié : une seule boucle pour toutes les parties
-/// - Maintenance facile : ajout/suppression de parties simple
-/// - Cohérence : même couleur et même logique pour toutes les parties
-/// - Debug efficace : messages descriptifs pour chaque partie
+/// # Position
+/// - X, Y : (0, 0) - centre du logo
+/// - Z : 0.22 - devant les triangles intérieurs, derrière les étoiles,
+///   les petits cercles et la lettre
+fn create_center_hub(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
+) {
+    let element = build::build_center_hub(config);
+    spawn_element(commands, meshes, materials, root, element, CenterHub, intro_fade(config, 2));
+}
+
+/// Crée les repères de débogage aux centroïdes des triangles
+/// 
+/// Calculés par `build::build_centroid_markers`, sans fondu d'introduction.
+/// Enfants de la racine : ils disparaissent avec elle à la régénération.
+fn create_centroid_markers(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
+) {
+    for element in build::build_centroid_markers(config) {
+        spawn_element(commands, meshes, materials, root, element, CentroidMarker, None);
+    }
+}
+
+/// Crée la lettre du logo ("R" par défaut, voir `LogoConfig::letter`)
+/// 
+/// Cette fonction génère toutes les parties de la lettre de manière unifiée.
+/// Le "R" est composé de 8 polygones distincts qui forment ensemble
+/// la lettre stylisée ; les autres lettres suivent le même principe.
 /// 
 /// # Configuration
-/// - Couleur : orange vif (modifiable en une ligne)
-/// - Z-order : 0.40 à 0.47 (défini dans chaque RPartDefinition)
-/// - Géométrie : définie dans geometry::get_all_r_parts()
+/// - Couleur : orange vif (`build::LETTER_COLOR`)
+/// - Z-order : 0.40 à 0.47 par défaut (r_base_z + index × r_z_step)
+/// - Géométrie : définie dans geometry::get_letter_parts()
+/// 
+/// # Lettre non supportée
+/// Un avertissement est affiché et aucune partie n'est créée
+/// (le reste du logo est conservé).
+/// 
+/// # Retour
+/// Les parties créées, avec leurs profondeurs (vide si lettre inconnue)
 /// 
 /// # Process par partie
-/// 1. Validation (minimum 3 points)
-/// 2. Création du mesh polygonal
-/// 3. Application du matériau coloré
-/// 4. Spawn à la position centrale avec Z-order approprié
+/// 1. Validation (minimum 3 points ; en mode sûr, voir `LogoConfig::safe_mode`,
+///    toute partie présentant un défaut est ignorée)
+/// 2. Construction du mesh, du matériau et du Transform (`build_letter_part`)
+/// 3. Spawn avec `RPartHome` (position de repos et direction d'éclatement)
+/// 4. Contour optionnel (si `r_outline_width` > 0)
 /// 5. Log de confirmation
-fn create_r_logo(
+fn create_letter_logo(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
-) {
-    // === COULEUR UNIQUE POUR TOUT LE LOGO ===
-    // Modifier cette ligne pour changer la couleur de tout le "R"
-    // Exemples :
-    // - Rouge : Color::srgba(1.0, 0.0, 0.0, 0.8)
-    // - Bleu : Color::srgba(0.2, 0.6, 1.0, 0.8)
-    // - Vert : Color::srgba(0.2, 0.8, 0.3, 0.8)
-    let r_color = Color::srgba(1.0, 0.5, 0.0, 0.8); // Orange
+    config: &LogoConfig,
+    root: Entity,
+) -> Vec<RPartDefinition> {
+    // === RECHERCHE DE LA LETTRE ===
+    let Some(letter_parts) = letter_parts_for(config) else {
+        println!("⚠️ Lettre '{}' non supportée (disponibles : {:?})",
+                 config.letter, geometry::SUPPORTED_LETTERS);
+        return Vec::new();
+    };
+    
+    // === LETTRE FUSIONNÉE ===
+    // Option merge_r_parts : une seule entité au lieu d'une par partie
+    if config.merge_r_parts && create_merged_letter(commands, meshes, materials, config, root, &letter_parts) {
+        return letter_parts;
+    }
     
     // === PARCOURS DE TOUTES LES PARTIES ===
-    for part in geometry::get_all_r_parts() {
+    let rejections = build::safe_mode_rejections(config, &letter_parts);
+    for (index, part) in letter_parts.iter().enumerate() {
         // === VALIDATION ===
+        // Mode sûr : aucune partie défectueuse n'est dessinée
+        if let Some((_, defect)) = rejections.iter().find(|(rejected, _)| *rejected == index) {
+            println!("⚠️ '{}' ignoré (mode sûr) : {}", part.name, defect);
+            continue;
+        }
+        
         // Un polygone nécessite au moins 3 points
         if part.points.len() < 3 {
             println!("⚠️ '{}' ignoré : moins de 3 points", part.name);
             continue;
         }
         
-        // === CRÉATION DU MESH POLYGONAL ===
-        let mesh = geometry::create_polygon_from_points(&part.points);
-        let mesh_handle = meshes.add(mesh);
-        
-        // === MATÉRIAU ===
-        let material = materials.add(ColorMaterial::from(r_color));
+        // Un contour qui se croise est mal triangulé, mais reste affiché
+        // pour que l'erreur de saisie soit visible
+        if !geometry::is_simple_polygon(&part.points) {
+            println!("⚠️ '{}' : le contour se croise lui-même (rendu incorrect)", part.name);
+        }
         
         // === SPAWN DE LA PARTIE ===
-        // Position : centre (0, 0)
-        // Z : défini dans part.z_order pour chaque partie
-        commands.spawn((
-            Mesh2d(mesh_handle),
-            MeshMaterial2d(material),
-            Transform::from_xyz(0.0, 0.0, part.z_order),
-        ));
+        // RPartHome : position de repos et direction d'éclatement
+        let element = build::build_letter_part(part);
+        let home = RPartHome {
+            transform: element.2,
+            direction: geometry::polygon_centroid(&part.points).normalize_or_zero(),
+        };
+        let mut entity = spawn_element(
+            commands, meshes, materials, root, element,
+            (RPart { name: part.name.clone(), index }, home), intro_fade(config, 4)
+        );
+        
+        // === CONTOUR OPTIONNEL ===
+        // Enfant de la partie : son Z est relatif à celui de la partie
+        if config.r_outline_width > 0.0 {
+            let (outline_mesh, outline_material, outline_transform) = build::build_letter_outline(config, part);
+            entity.with_child((
+                Mesh2d(meshes.add(outline_mesh)),
+                MeshMaterial2d(materials.add(outline_material)),
+                outline_transform,
+                ROutline { index },
+            ));
+        }
         
         // === LOG DE CONFIRMATION ===
         println!("   ✨ '{}' créé avec {} points (Z={})", 
                  part.name, part.points.len(), part.z_order);
     }
+    
+    // === BILAN DU MODE SÛR ===
+    if config.safe_mode {
+        println!("🛡️ Mode sûr : {} partie(s) ignorée(s) sur {}", rejections.len(), letter_parts.len());
+    }
+    
+    letter_parts
+}
+
+/// Crée la lettre en une seule entité `MergedLetter` (option `merge_r_parts`)
+/// 
+/// Les parties dessinables (`build::drawable_letter_parts`) sont
+/// fusionnées par `build::build_merged_letter`. Les contours éventuels
+/// deviennent des enfants de l'entité fusionnée, à la même profondeur
+/// que derrière leurs parties séparées.
+/// 
+/// # Retour
+/// `false` si aucune partie n'est dessinable : la lettre passe alors par
+/// la création partie par partie, qui détaille les parties ignorées
+fn create_merged_letter(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    config: &LogoConfig,
+    root: Entity,
+    letter_parts: &[RPartDefinition],
+) -> bool {
+    let drawable = build::drawable_letter_parts(config, letter_parts);
+    let parts: Vec<RPartDefinition> = drawable.iter().map(|(_, part)| part.clone()).collect();
+    let Some(element) = build::build_merged_letter(&parts) else {
+        if !letter_parts.is_empty() {
+            println!("ℹ️ Fusion de la lettre impossible : toutes les parties sont ignorées");
+        }
+        return false;
+    };
+    let merged_z = element.2.translation.z;
+    
+    let mut entity = spawn_element(commands, meshes, materials, root, element, MergedLetter, intro_fade(config, 4));
+    
+    // === CONTOURS OPTIONNELS ===
+    // Profondeur relative à l'entité fusionnée, et non plus à la partie
+    if config.r_outline_width > 0.0 {
+        for (index, part) in &drawable {
+            let index = *index;
+            let (outline_mesh, outline_material, mut outline_transform) = build::build_letter_outline(config, part);
+            outline_transform.translation.z += part.z_order - merged_z;
+            entity.with_child((
+                Mesh2d(meshes.add(outline_mesh)),
+                MeshMaterial2d(materials.add(outline_material)),
+                outline_transform,
+                ROutline { index },
+            ));
+        }
+    }
+    
+    println!("   ✨ Lettre fusionnée : {} parties en un seul mesh (Z={})", drawable.len(), merged_z);
+    true
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -334,13 +658,18 @@ fn create_r_logo(
 /// - Les paramètres de configuration utilisés
 /// - L'organisation des couches Z
 /// 
+/// Le coût de rendu (`LogoStats` : entités, vertices, triangles) est
+/// mesuré sur les meshes réellement créés, donc après application des
+/// commandes : `update_logo_stats_system` l'affiche à la suite de ce
+/// résumé dès son premier calcul.
+/// 
 /// Utile pour :
 /// - Vérifier que l'initialisation s'est bien passée
 /// - Débugger en cas de problème visuel
 /// - Documenter la structure du rendu
-fn print_creation_summary() {
-    // Comptage dynamique des parties du logo R
-    let r_parts_count = geometry::get_all_r_parts().len();
+fn print_creation_summary(config: &LogoConfig) {
+    // Comptage dynamique des parties de la lettre
+    let letter_parts_count = letter_parts_for(config).map_or(0, |parts| parts.len());
     
     println!("\n╔══════════════════════════════════════════════════════════╗");
     println!("║           CRÉATION TERMINÉE - RÉSUMÉ                     ║");
@@ -348,25 +677,47 @@ fn print_creation_summary() {
     
     println!("\n📊 ÉLÉMENTS CRÉÉS :");
     println!("   ✓ 1 cercle principal (anneau épais)");
-    println!("   ✓ {} triangles extérieurs arc-en-ciel", config::EXTERIOR_TRIANGLES_COUNT);
-    println!("   ✓ {} triangles intérieurs colorés", config::INTERIOR_TRIANGLES_COUNT);
-    println!("   ✓ {} petits cercles centraux", config::INTERIOR_TRIANGLES_COUNT);
-    println!("   ✓ {} parties du logo 'R'", r_parts_count);
+    match config.rainbow_style {
+        RainbowStyle::Discrete => println!("   ✓ {} triangles extérieurs arc-en-ciel", config.exterior_triangles_count),
+        RainbowStyle::Gradient => println!("   ✓ 1 anneau arc-en-ciel en dégradé"),
+    }
+    println!("   ✓ {} triangles intérieurs colorés", config.interior_triangles_count);
+    println!("   ✓ {} petits cercles centraux", config.interior_triangles_count);
+    if config.center_hub {
+        println!("   ✓ 1 disque central");
+    }
+    if let Some((rows, cols, _)) = config.tile {
+        println!("   ✓ Mosaïque de {} × {} logos", rows, cols);
+    }
+    println!("   ✓ {} parties du logo '{}'", letter_parts_count, config.letter);
     
     println!("\n🔧 PARAMÈTRES DE CONFIGURATION :");
-    println!("   • Rayon principal : {} px", config::CIRCLE_RADIUS);
-    println!("   • Épaisseur anneau : {} px", config::CIRCLE_THICKNESS);
-    println!("   • Rayon intérieur : {} px", config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS);
-    println!("   • Qualité cercles : {} segments", config::CIRCLE_SEGMENTS);
-    println!("   • Taille triangles extérieurs : {} px", config::SMALL_TRIANGLE_SIDE);
-    println!("   • Taille triangles intérieurs : {} px", config::LARGE_TRIANGLE_SIDE);
+    println!("   • Rayon principal : {} px", config.circle_radius);
+    println!("   • Épaisseur anneau : {} px", config.circle_thickness);
+    println!("   • Rayon intérieur : {} px", config.inner_radius());
+    println!("   • Segments anneau : {}{}", config.ring_segments,
+             if config.adaptive_segments { " (ignoré : mode adaptatif)" } else { "" });
+    println!("   • Segments petits cercles : {}", config.dot_segments);
+    if config.center_hub {
+        println!("   • Segments disque central : {}", config.hub_segments);
+    }
+    println!("   • Taille triangles extérieurs : {} px", config.small_triangle_side);
+    println!("   • Taille triangles intérieurs : {} px", config.large_triangle_side);
     
+    // Affichage de l'arrière vers l'avant
     println!("\n🎬 ORGANISATION DES COUCHES (Z) :");
-    println!("   • Z = 0.0  : Cercle principal (arrière-plan)");
-    println!("   • Z = 0.1  : Triangles extérieurs arc-en-ciel");
-    println!("   • Z = 0.2  : Triangles intérieurs colorés");
-    println!("   • Z = 0.3  : Petits cercles blancs");
-    println!("   • Z = 0.4+ : Logo 'R' ({} parties)", r_parts_count);
+    let mut layers: Vec<(LayerKind, f32)> = build::logo_layers(config).collect();
+    layers.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (kind, z) in layers {
+        match kind {
+            LayerKind::Grid if !config.show_grid => {}
+            LayerKind::Spiral if !config.show_spiral => {}
+            LayerKind::CenterHub if !config.center_hub => {}
+            LayerKind::Letter => println!("   • Z = {}+ : Logo '{}' ({} parties)",
+                                          config.r_base_z, config.letter, letter_parts_count),
+            _ => println!("   • Z = {} : {}", z, kind.label()),
+        }
+    }
     
     println!("\n╔══════════════════════════════════════════════════════════╗");
     println!("║  🚀 Application Bevy prête - Fenêtre ouverte             ║");
@@ -0,0 +1,65 @@
+
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                    FICHIER: src/systems/shadow.rs                        ║
+// ║  Ombres portées du logo                                                  ║
+// ║  Rôle : Ajouter une ombre décalée sous chaque élément                   ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module shadow
+//! 
+//! Quand `LogoConfig::drop_shadow` est actif, chaque élément du logo
+//! reçoit une copie noire semi-transparente :
+//! - même mesh (le handle est partagé, aucun mesh n'est dupliqué)
+//! - décalée de `shadow_offset`
+//! - légèrement derrière l'élément (`SHADOW_Z_OFFSET`)
+//! - enfant de `LogoRoot`, pour suivre les déplacements du logo entier
+
+use bevy::prelude::*;
+use crate::components::{DropShadow, LogoElementFilter, LogoRoot, ROutline};
+use crate::config::{self, LogoConfig};
+
+/// Éléments du logo créés depuis le dernier passage, hors contours du "R"
+type NewShadowCasterFilter = (Added<Mesh2d>, LogoElementFilter, Without<ROutline>);
+
+/// Crée les ombres portées des éléments nouvellement créés
+/// 
+/// Traite les éléments du logo apparus depuis le dernier passage
+/// (démarrage ou régénération). Les contours du "R" sont ignorés :
+/// l'ombre de la partie qu'ils entourent suffit.
+pub fn spawn_drop_shadows_system(
+    mut commands: Commands,
+    config: Res<LogoConfig>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    roots: Query<(), With<LogoRoot>>,
+    elements: Query<(&Mesh2d, &Transform, &ChildOf), NewShadowCasterFilter>,
+) {
+    if !config.drop_shadow || elements.is_empty() {
+        return;
+    }
+    
+    // === MATÉRIAU PARTAGÉ PAR TOUTES LES OMBRES ===
+    let shadow_material = materials.add(ColorMaterial::from(
+        Color::srgba(0.0, 0.0, 0.0, config.shadow_opacity),
+    ));
+    
+    for (mesh, transform, child_of) in &elements {
+        // Seuls les enfants directs de la racine sont ombrés
+        let root = child_of.parent();
+        if roots.get(root).is_err() {
+            continue;
+        }
+        
+        // === POSITION DE L'OMBRE ===
+        // Même transform que l'élément, décalé en XY et reculé en Z
+        let mut shadow_transform = *transform;
+        shadow_transform.translation += config.shadow_offset.extend(-config::SHADOW_Z_OFFSET);
+        
+        commands.spawn((
+            Mesh2d(mesh.0.clone()),
+            MeshMaterial2d(shadow_material.clone()),
+            shadow_transform,
+            DropShadow,
+            ChildOf(root),
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                    FICHIER: src/systems/stats.rs                         ║
+// ║  Statistiques de rendu du logo                                           ║
+// ║  Rôle : Compter vertices, triangles et entités réellement créés         ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module stats
+//! 
+//! La ressource `LogoStats` mesure le coût du logo à partir des meshes
+//! réellement créés (et non par des formules) : elle permet de comparer
+//! différents réglages de `LogoConfig` et de vérifier un budget dans les tests.
+//! 
+//! Elle est recalculée à chaque apparition de nouveaux éléments
+//! (démarrage, régénération, ombres portées) et affichée dans la console :
+//! le premier calcul complète le résumé de démarrage de `setup_system`.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use crate::components::LogoRoot;
+
+/// Coût de rendu du logo
+/// 
+/// - `vertices` : somme des vertices des meshes de tous les éléments
+/// - `triangles` : somme des triangles (meshes `TriangleList` uniquement)
+/// - `entities` : nombre d'éléments affichés (entités avec un mesh)
+/// 
+/// Un mesh partagé par plusieurs entités (ombres) est compté pour chacune :
+/// il est dessiné autant de fois.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LogoStats {
+    /// Nombre total de vertices
+    pub vertices: usize,
+    
+    /// Nombre total de triangles
+    pub triangles: usize,
+    
+    /// Nombre d'entités affichant un mesh
+    pub entities: usize,
+}
+
+impl LogoStats {
+    /// Ajoute le coût d'une entité affichant `mesh`
+    /// 
+    /// Triangles : indices / 3 si le mesh est indexé, vertices / 3 sinon.
+    /// Les meshes de lignes (grille) n'ajoutent aucun triangle.
+    pub fn add_mesh(&mut self, mesh: &Mesh) {
+        let vertices = mesh.count_vertices();
+        self.vertices += vertices;
+        self.entities += 1;
+        
+        if mesh.primitive_topology() == PrimitiveTopology::TriangleList {
+            let corners = mesh.indices().map_or(vertices, |indices| indices.len());
+            self.triangles += corners / 3;
+        }
+    }
+    
+    /// Calcule les statistiques d'une liste de meshes (une entité par mesh)
+    pub fn from_meshes<'a>(meshes: impl IntoIterator<Item = &'a Mesh>) -> Self {
+        let mut stats = Self::default();
+        for mesh in meshes {
+            stats.add_mesh(mesh);
+        }
+        stats
+    }
+}
+
+/// Recalcule `LogoStats` quand de nouveaux éléments apparaissent
+/// 
+/// Parcourt tous les descendants de la racine `LogoRoot` (contours et
+/// ombres compris) et additionne le coût de leur mesh.
+/// 
+/// Les meshes n'existent qu'une fois les commandes du setup appliquées :
+/// le premier calcul est donc affiché comme la suite du résumé de
+/// démarrage, les suivants sur une seule ligne.
+pub fn update_logo_stats_system(
+    mut stats: ResMut<LogoStats>,
+    mut summary_printed: Local<bool>,
+    meshes: Res<Assets<Mesh>>,
+    added: Query<(), Added<Mesh2d>>,
+    roots: Query<Entity, With<LogoRoot>>,
+    children: Query<&Children>,
+    elements: Query<&Mesh2d>,
+) {
+    if added.is_empty() {
+        return;
+    }
+    
+    // === SOMME SUR TOUS LES DESCENDANTS DE LA RACINE ===
+    let logo_meshes = roots
+        .iter()
+        .flat_map(|root| children.iter_descendants(root))
+        .filter_map(|entity| elements.get(entity).ok())
+        .filter_map(|mesh| meshes.get(&mesh.0));
+    *stats = LogoStats::from_meshes(logo_meshes);
+    
+    if !*summary_printed {
+        print_stats_summary(&stats);
+        *summary_printed = true;
+        return;
+    }
+    println!("📈 Coût du logo : {} vertices, {} triangles, {} entités",
+             stats.vertices, stats.triangles, stats.entities);
+}
+
+/// Affiche le premier calcul de `LogoStats`, à la suite du résumé de création
+fn print_stats_summary(stats: &LogoStats) {
+    println!("\n📈 COÛT DU RENDU :");
+    println!("   • Entités affichées : {}", stats.entities);
+    println!("   • Vertices : {}", stats.vertices);
+    println!("   • Triangles : {}", stats.triangles);
+    println!();
+}
@@ -0,0 +1,174 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                    FICHIER: src/systems/theme.rs                         ║
+// ║  Changement de thème de couleurs à l'exécution                           ║
+// ║  Rôle : Recolorer le logo existant avec la touche T                      ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Module theme
+//! 
+//! La touche T passe au thème suivant (`Theme::next`) et recolore
+//! l'anneau, les triangles intérieurs et la lettre en modifiant leurs
+//! matériaux sur place : aucune entité n'est recréée.
+//! 
+//! L'opacité courante de chaque matériau est conservée (fondu
+//! d'introduction, opacité globale).
+//! 
+//! Les éléments recréés par une régénération (`RegenerateLogo`) sortent
+//! du setup aux couleurs `Theme::Classic` : `reapply_theme_system` leur
+//! réapplique le thème courant, pour que `CurrentTheme` et l'affichage
+//! restent d'accord.
+//! 
+//! L'événement `RecolorEvent` recolore de la même façon une seule couche
+//! (`LayerKind`), indépendamment des thèmes : pour les scripts et les tests.
+
+use bevy::prelude::*;
+use crate::build::LayerKind;
+use crate::config::LogoConfig;
+use crate::components::{
+    CenterDot, CenterHub, CenterStar, ExteriorTriangle, GridBackground, InteriorTriangle, MainCircle, MergedLetter,
+    RPart, RainbowRing, SpiralBackground,
+};
+use crate::materials::Theme;
+
+/// Thème de couleurs appliqué au logo
+/// 
+/// `Theme::Classic` par défaut, qui correspond aux couleurs du setup.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CurrentTheme(pub Theme);
+
+/// Éléments recolorés par un thème, avec leur marqueur
+type ThemedElement<'a> = (
+    &'a MeshMaterial2d<ColorMaterial>,
+    Option<&'a InteriorTriangle>,
+    Has<MainCircle>,
+    Has<RPart>,
+    Has<MergedLetter>,
+);
+
+/// Filtre des éléments concernés par les thèmes
+type ThemedFilter = Or<(With<MainCircle>, With<InteriorTriangle>, With<RPart>, With<MergedLetter>)>;
+
+/// Éléments concernés par les thèmes, créés depuis le dernier passage
+type NewThemedFilter = (ThemedFilter, Added<MeshMaterial2d<ColorMaterial>>);
+
+/// Passe au thème suivant avec la touche T et recolore le logo
+pub fn cycle_theme_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<LogoConfig>,
+    mut theme: ResMut<CurrentTheme>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    elements: Query<ThemedElement, ThemedFilter>,
+) {
+    if !keys.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+    theme.0 = theme.0.next();
+    
+    for (material_handle, interior, is_ring, is_part, is_merged) in &elements {
+        apply_theme(theme.0, &config, &mut materials, material_handle, interior, is_ring, is_part || is_merged);
+    }
+    
+    println!("🎨 Thème : {}", theme.0.label());
+}
+
+/// Réapplique le thème courant aux éléments nouvellement créés
+/// 
+/// À exécuter après `regenerate_logo_system` : le logo régénéré est
+/// construit aux couleurs d'origine, qui sont déjà celles de
+/// `Theme::Classic` (rien à faire dans ce cas).
+pub fn reapply_theme_system(
+    theme: Res<CurrentTheme>,
+    config: Res<LogoConfig>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    elements: Query<ThemedElement, NewThemedFilter>,
+) {
+    if theme.0 == Theme::Classic {
+        return;
+    }
+    
+    for (material_handle, interior, is_ring, is_part, is_merged) in &elements {
+        apply_theme(theme.0, &config, &mut materials, material_handle, interior, is_ring, is_part || is_merged);
+    }
+}
+
+/// Recolore un élément selon `theme`, en conservant l'alpha courant
+/// (fondus, opacité globale)
+fn apply_theme(
+    theme: Theme,
+    config: &LogoConfig,
+    materials: &mut Assets<ColorMaterial>,
+    material_handle: &MeshMaterial2d<ColorMaterial>,
+    interior: Option<&InteriorTriangle>,
+    is_ring: bool,
+    is_letter: bool,
+) {
+    let color = match (interior, is_ring, is_letter) {
+        (Some(triangle), _, _) => theme.interior_color(config, triangle.index),
+        (_, true, _) => theme.ring_color(),
+        (_, _, true) => theme.letter_color(),
+        _ => return,
+    };
+    
+    if let Some(material) = materials.get_mut(&material_handle.0) {
+        material.color = color.with_alpha(material.color.alpha());
+    }
+}
+
+/// Demande de changement de couleur d'une couche du logo
+/// 
+/// # Exemple
+/// ```ignore
+/// fn mon_systeme(mut events: EventWriter<RecolorEvent>) {
+///     events.write(RecolorEvent { selector: LayerKind::MainCircle, color: Color::WHITE });
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct RecolorEvent {
+    /// Couche à recolorer (la lettre entière pour `LayerKind::Letter`)
+    pub selector: LayerKind,
+    
+    /// Nouvelle couleur ; seule sa teinte compte, l'alpha courant est conservé
+    pub color: Color,
+}
+
+/// Éléments recolorables, avec tous les marqueurs qui déterminent leur couche
+type RecolorElement<'a> = (
+    &'a MeshMaterial2d<ColorMaterial>,
+    (Has<GridBackground>, Has<SpiralBackground>, Has<MainCircle>),
+    (Has<ExteriorTriangle>, Has<RainbowRing>, Has<InteriorTriangle>),
+    (Has<CenterHub>, Has<CenterStar>, Has<CenterDot>),
+    (Has<RPart>, Has<MergedLetter>),
+);
+
+/// Recolore les éléments de la couche demandée par chaque `RecolorEvent`
+/// 
+/// Les matériaux existants sont modifiés sur place (aucune entité ni
+/// matériau n'est recréé) ; leur alpha courant est conservé, comme pour
+/// les thèmes. Une régénération du logo rétablit les couleurs d'origine.
+pub fn recolor_system(
+    mut events: EventReader<RecolorEvent>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    elements: Query<RecolorElement>,
+) {
+    for event in events.read() {
+        for (material_handle, (grid, spiral, ring), (exterior, rainbow, interior), (hub, star, dot), (part, merged)) in &elements {
+            let selected = match event.selector {
+                LayerKind::Grid => grid,
+                LayerKind::Spiral => spiral,
+                LayerKind::MainCircle => ring,
+                LayerKind::ExteriorTriangles => exterior || rainbow,
+                LayerKind::InteriorTriangles => interior,
+                LayerKind::CenterHub => hub,
+                LayerKind::CenterStars => star,
+                LayerKind::CenterDots => dot,
+                LayerKind::Letter => part || merged,
+            };
+            if !selected {
+                continue;
+            }
+            if let Some(material) = materials.get_mut(&material_handle.0) {
+                material.color = event.color.with_alpha(material.color.alpha());
+            }
+        }
+    }
+}
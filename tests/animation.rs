@@ -0,0 +1,111 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: tests/animation.rs                        ║
+// ║  Tests des systèmes d'animation                                          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::systems::animation::{global_opacity_system, intro_fade_system, ring_hue_cycle_system};
+use LogoRust_Bevy_20250929::{materials, AnimationState, BaseAlpha, GlobalOpacity, IntroFade, LogoConfig, MainCircle};
+
+#[test]
+fn ring_hue_cycle_shifts_hue_in_place() {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(1));
+    world.insert_resource(time);
+    world.init_resource::<AnimationState>();
+    world.insert_resource(LogoConfig {
+        ring_hue_cycle_speed: 30.0,
+        ..Default::default()
+    });
+    let mut assets = Assets::<ColorMaterial>::default();
+    let handle = assets.add(materials::get_main_circle_color());
+    let before = Hsva::from(assets.get(&handle).unwrap().color);
+    world.insert_resource(assets);
+    world.spawn((MainCircle, MeshMaterial2d(handle.clone())));
+
+    world.run_system_once(ring_hue_cycle_system).unwrap();
+
+    let assets = world.resource::<Assets<ColorMaterial>>();
+    let after = Hsva::from(assets.get(&handle).unwrap().color);
+    assert_eq!(assets.len(), 1);
+    assert!((after.hue - (before.hue + 30.0)).abs() < 0.01, "{} -> {}", before.hue, after.hue);
+    assert!((after.saturation - before.saturation).abs() < 1e-4);
+    assert!((after.value - before.value).abs() < 1e-4);
+}
+
+#[test]
+fn global_opacity_only_touches_logo_elements() {
+    let mut world = World::new();
+    world.insert_resource(GlobalOpacity(0.5));
+    let mut assets = Assets::<ColorMaterial>::default();
+    let ring = assets.add(materials::get_main_circle_color());
+    let host = assets.add(ColorMaterial::from(Color::WHITE));
+    world.insert_resource(assets);
+    let ring_entity = world.spawn((MainCircle, MeshMaterial2d(ring.clone()))).id();
+    let host_entity = world.spawn(MeshMaterial2d(host.clone())).id();
+
+    world.run_system_once(global_opacity_system).unwrap();
+
+    let assets = world.resource::<Assets<ColorMaterial>>();
+    assert_eq!(assets.get(&ring).unwrap().color.alpha(), 0.5);
+    assert_eq!(assets.get(&host).unwrap().color.alpha(), 1.0);
+    assert!(world.get::<BaseAlpha>(ring_entity).is_some());
+    assert!(world.get::<BaseAlpha>(host_entity).is_none());
+}
+
+#[test]
+fn ring_hue_cycle_advances_a_shared_material_once_per_frame() {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(1));
+    world.insert_resource(time);
+    world.init_resource::<AnimationState>();
+    world.insert_resource(LogoConfig {
+        ring_hue_cycle_speed: 30.0,
+        ..Default::default()
+    });
+    let mut assets = Assets::<ColorMaterial>::default();
+    let handle = assets.add(materials::get_main_circle_color());
+    let before = Hsva::from(assets.get(&handle).unwrap().color);
+    world.insert_resource(assets);
+    // Trois copies d'un logo pavé, un seul matériau
+    for _ in 0..3 {
+        world.spawn((MainCircle, MeshMaterial2d(handle.clone())));
+    }
+
+    world.run_system_once(ring_hue_cycle_system).unwrap();
+
+    let after = Hsva::from(world.resource::<Assets<ColorMaterial>>().get(&handle).unwrap().color);
+    assert!((after.hue - (before.hue + 30.0)).abs() < 0.01, "{} -> {}", before.hue, after.hue);
+}
+
+#[test]
+fn intro_fade_of_tiles_sharing_a_material_reaches_the_original_alpha() {
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    world.init_resource::<AnimationState>();
+    world.insert_resource(GlobalOpacity(1.0));
+    let mut assets = Assets::<ColorMaterial>::default();
+    let handle = assets.add(ColorMaterial::from(Color::srgba(1.0, 0.5, 0.0, 0.8)));
+    world.insert_resource(assets);
+    // Trois copies d'un logo pavé, un seul matériau
+    let tiles: Vec<Entity> = (0..3)
+        .map(|_| world.spawn((IntroFade::new(0.0), MeshMaterial2d(handle.clone()))).id())
+        .collect();
+    let alpha = |world: &World| world.resource::<Assets<ColorMaterial>>().get(&handle).unwrap().color.alpha();
+
+    // Mi-parcours : chaque copie applique la moitié du même alpha final
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.25));
+    world.run_system_once(intro_fade_system).unwrap();
+    assert!((alpha(&world) - 0.4).abs() < 1e-4, "{}", alpha(&world));
+
+    // Fin du fondu : l'alpha d'origine est rétabli
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.5));
+    world.run_system_once(intro_fade_system).unwrap();
+    assert!((alpha(&world) - 0.8).abs() < 1e-4, "{}", alpha(&world));
+    assert!(tiles.iter().all(|&tile| world.get::<IntroFade>(tile).is_none()));
+}
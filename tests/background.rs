@@ -0,0 +1,42 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: tests/background.rs                       ║
+// ║  Tests de l'image de fond                                                ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use LogoRust_Bevy_20250929::config::BACKGROUND_IMAGE_Z;
+use LogoRust_Bevy_20250929::systems::background::{background_size, fit_background_to_view_system};
+use LogoRust_Bevy_20250929::{BackgroundImage, MainCamera};
+
+#[test]
+fn background_size_grows_with_the_projection_scale() {
+    let window = Vec2::new(1280.0, 720.0);
+
+    assert_eq!(background_size(window, 1.0), window);
+    // Dézoom : la vue couvre deux fois plus de monde
+    assert_eq!(background_size(window, 2.0), window * 2.0);
+}
+
+#[test]
+fn background_follows_the_panned_and_zoomed_camera() {
+    let mut world = World::new();
+    let window = Window::default();
+    let window_size = window.size();
+    world.spawn((window, PrimaryWindow));
+    world.spawn((
+        MainCamera,
+        Transform::from_xyz(300.0, -120.0, 0.0),
+        Projection::Orthographic(OrthographicProjection { scale: 0.5, ..OrthographicProjection::default_2d() }),
+    ));
+    let background = world
+        .spawn((Sprite::default(), Transform::from_xyz(0.0, 0.0, BACKGROUND_IMAGE_Z), BackgroundImage))
+        .id();
+
+    world.run_system_once(fit_background_to_view_system).unwrap();
+
+    let (sprite, transform) = world.query::<(&Sprite, &Transform)>().get(&world, background).unwrap();
+    assert_eq!(sprite.custom_size, Some(window_size * 0.5));
+    assert_eq!(transform.translation, Vec3::new(300.0, -120.0, BACKGROUND_IMAGE_Z));
+}
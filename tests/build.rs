@@ -0,0 +1,233 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: tests/build.rs                          ║
+// ║  Tests de la construction des éléments du logo (sans App Bevy)           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::geometry::{PolygonDefect, RPartDefinition};
+use LogoRust_Bevy_20250929::{build, geometry, LogoConfig};
+
+#[test]
+fn one_exterior_triangle_per_configured_count() {
+    let config = LogoConfig {
+        exterior_triangles_count: 12,
+        stable_layer_sort: false,
+        ..default()
+    };
+
+    let triangles = build::build_exterior_triangles(&config);
+
+    assert_eq!(triangles.len(), 12);
+    assert!(triangles
+        .iter()
+        .all(|(_, _, transform)| transform.translation.z == build::Z_EXTERIOR_TRIANGLES));
+}
+
+#[test]
+fn center_dots_sit_on_interior_triangle_centroids() {
+    let config = LogoConfig::default();
+
+    let dots = build::build_center_dots(&config);
+    let triangles = build::build_interior_triangles(&config);
+    assert_eq!(dots.len(), config.interior_triangles_count);
+    assert_eq!(triangles.len(), config.interior_triangles_count);
+
+    for (i, (_, material, transform)) in dots.iter().enumerate() {
+        let angle = geometry::interior_triangle_angle_degrees(i, config.interior_triangles_count);
+        let (p1, p2, p3) = geometry::calculate_interior_triangle_points(
+            geometry::degrees_to_radians(angle),
+            config.inner_radius(),
+            config.large_triangle_side,
+        );
+        let centroid = geometry::calculate_triangle_centroid(p1, p2, p3);
+
+        assert!(transform.translation.truncate().distance(centroid) < 1e-4);
+        assert_eq!(material.color, Color::srgba(1.0, 1.0, 1.0, 0.7));
+    }
+}
+
+#[test]
+fn logo_layers_are_strictly_increasing_in_z() {
+    let layers: Vec<(build::LayerKind, f32)> = build::logo_layers(&LogoConfig::default()).collect();
+
+    assert_eq!(layers.first().map(|(kind, _)| *kind), Some(build::LayerKind::Grid));
+    assert_eq!(layers.last().map(|(kind, _)| *kind), Some(build::LayerKind::Letter));
+    assert!(layers.windows(2).all(|pair| pair[0].1 < pair[1].1), "{layers:?}");
+}
+
+#[test]
+fn center_stars_point_outward_from_logo_center() {
+    let config = LogoConfig {
+        center_star_points: 5,
+        ..Default::default()
+    };
+    let stars = build::build_center_stars(&config);
+    assert_eq!(stars.len(), config.interior_triangles_count);
+
+    for (_, _, transform) in &stars {
+        // La première pointe (axe +X du mesh) suit la direction centre → étoile
+        let tip = (transform.rotation * Vec3::X).truncate();
+        let outward = transform.translation.truncate().normalize();
+        assert!(tip.dot(outward) > 0.999, "{tip:?} / {outward:?}");
+    }
+
+    let without_stars = LogoConfig::default();
+    assert!(build::build_center_stars(&without_stars).is_empty());
+}
+
+#[test]
+fn interior_bases_scale_with_radius_fraction() {
+    let full = LogoConfig::default();
+    let tucked = LogoConfig {
+        interior_radius_fraction: 0.8,
+        ..Default::default()
+    };
+
+    for i in 0..full.interior_triangles_count {
+        let (a1, a2, _) = build::interior_triangle_points(&full, i);
+        let (b1, b2, _) = build::interior_triangle_points(&tucked, i);
+
+        assert!((a1.length() - full.inner_radius()).abs() < 1e-3);
+        assert!((b1.length() - 0.8 * full.inner_radius()).abs() < 1e-3);
+        assert!((b2.length() - 0.8 * a2.length()).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn thirty_six_exterior_triangles_are_ten_degrees_apart() {
+    let config = LogoConfig {
+        exterior_triangles_count: 36,
+        ..Default::default()
+    };
+
+    let triples = build::exterior_triangle_transforms(&config);
+
+    assert_eq!(triples.len(), 36);
+    // Angle de la base : milieu des deux premiers sommets
+    let base_angle = |(p1, p2, _): (Vec2, Vec2, Vec2)| ((p1 + p2) / 2.0).to_angle().to_degrees();
+    for pair in triples.windows(2) {
+        let step = (base_angle(pair[1]) - base_angle(pair[0])).rem_euclid(360.0);
+        assert!((step - 10.0).abs() < 1e-3, "{step}");
+    }
+}
+
+#[test]
+fn center_hub_sits_between_interior_triangles_and_letter() {
+    let config = LogoConfig::default();
+    let (_, _, transform) = build::build_center_hub(&config);
+
+    assert_eq!(transform.translation, Vec3::new(0.0, 0.0, config.z_hub));
+    let layers: Vec<(build::LayerKind, f32)> = build::logo_layers(&config).collect();
+    assert!(layers.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    assert!(config.z_interior < config.z_hub && config.z_hub < config.r_base_z);
+}
+
+#[test]
+fn one_centroid_marker_per_triangle_at_its_centroid() {
+    let config = LogoConfig::default();
+    let markers = build::build_centroid_markers(&config);
+
+    assert_eq!(markers.len(), config.exterior_triangles_count + config.interior_triangles_count);
+    let (p1, p2, p3) = build::interior_triangle_points(&config, 0);
+    let centroid = geometry::calculate_triangle_centroid(p1, p2, p3);
+    let (_, _, transform) = &markers[config.exterior_triangles_count];
+    assert_eq!(transform.translation.truncate(), centroid);
+}
+
+#[test]
+fn positive_exterior_gap_narrows_the_triangle_base() {
+    let tight = build::exterior_triangle_transforms(&LogoConfig::default());
+    let spaced = build::exterior_triangle_transforms(&LogoConfig {
+        exterior_gap_degrees: 2.0,
+        ..default()
+    });
+
+    for ((t1, t2, t3), (s1, s2, s3)) in tight.iter().zip(&spaced) {
+        assert!(s1.distance(*s2) < t1.distance(*t2));
+        assert_eq!(s3, t3);
+    }
+}
+
+#[test]
+fn merged_letter_keeps_every_part_triangle_in_one_mesh() {
+    let parts = LogoConfig::default().letter_parts();
+    let (mesh, material, transform) = build::build_merged_letter(&parts).unwrap();
+
+    let separate: usize = parts.iter().map(|part| build::build_letter_part(part).0.indices().unwrap().len()).sum();
+    assert_eq!(mesh.indices().unwrap().len(), separate);
+    assert_eq!(material.color, build::LETTER_COLOR);
+
+    let lowest = parts.iter().map(|part| part.z_order).fold(f32::INFINITY, f32::min);
+    assert_eq!(transform.translation.z, lowest);
+}
+
+#[test]
+fn center_hub_uses_its_own_segment_count() {
+    let config = LogoConfig { center_hub: true, hub_segments: 12, ring_segments: 200, ..Default::default() };
+    let (mesh, _, _) = build::build_center_hub(&config);
+
+    // Disque plein : un centre plus un vertex par segment
+    assert_eq!(mesh.count_vertices(), 13);
+}
+
+#[test]
+fn stable_layer_sort_gives_each_triangle_and_dot_its_own_depth() {
+    let config = LogoConfig::default();
+    let depths = |elements: Vec<build::BuiltElement>| -> Vec<f32> {
+        elements.iter().map(|(_, _, transform)| transform.translation.z).collect()
+    };
+
+    for (layer, z) in [
+        (depths(build::build_exterior_triangles(&config)), config.z_exterior),
+        (depths(build::build_interior_triangles(&config)), config.z_interior),
+        (depths(build::build_center_dots(&config)), config.z_dots),
+    ] {
+        assert!(layer.windows(2).all(|pair| pair[0] < pair[1]), "{layer:?}");
+        assert!(layer.iter().all(|depth| (depth - z).abs() < 0.01));
+    }
+
+    let flat = LogoConfig { stable_layer_sort: false, ..config.clone() };
+    assert!(depths(build::build_exterior_triangles(&flat)).iter().all(|&depth| depth == flat.z_exterior));
+}
+
+#[test]
+fn safe_mode_reports_each_defective_part_by_index() {
+    let triangle = vec![Vec2::ZERO, Vec2::new(40.0, 0.0), Vec2::new(0.0, 40.0)];
+    let bowtie = vec![Vec2::ZERO, Vec2::new(40.0, 40.0), Vec2::new(40.0, 0.0), Vec2::new(0.0, 30.0)];
+    let parts = vec![
+        RPartDefinition::new("Triangle", triangle, 0.0),
+        RPartDefinition::new("Nœud papillon", bowtie, 0.0),
+    ];
+
+    let safe = LogoConfig { safe_mode: true, ..Default::default() };
+    assert_eq!(build::safe_mode_rejections(&safe, &parts), [(1, PolygonDefect::SelfIntersecting)]);
+    assert!(build::safe_mode_rejections(&LogoConfig::default(), &parts).is_empty());
+}
+
+#[test]
+fn letter_outline_stays_behind_its_part_whatever_the_z_step_sign() {
+    let part = &LogoConfig::default().letter_parts()[0];
+    for r_z_step in [0.01, -0.01] {
+        let config = LogoConfig { r_z_step, r_outline_width: 2.0, ..Default::default() };
+        let (_, _, transform) = build::build_letter_outline(&config, part);
+        assert_eq!(transform.translation.z, -0.005);
+    }
+}
+
+#[test]
+fn drawable_letter_parts_keep_their_original_index() {
+    let triangle = vec![Vec2::ZERO, Vec2::new(40.0, 0.0), Vec2::new(0.0, 40.0)];
+    let bowtie = vec![Vec2::ZERO, Vec2::new(40.0, 40.0), Vec2::new(40.0, 0.0), Vec2::new(0.0, 30.0)];
+    let parts = vec![
+        RPartDefinition::new("Nœud papillon", bowtie, 0.0),
+        RPartDefinition::new("Segment", vec![Vec2::ZERO, Vec2::X], 0.0),
+        RPartDefinition::new("Triangle", triangle, 0.0),
+    ];
+
+    let safe = LogoConfig { safe_mode: true, ..Default::default() };
+    let indices: Vec<usize> = build::drawable_letter_parts(&safe, &parts).into_iter().map(|(index, _)| index).collect();
+    assert_eq!(indices, [2]);
+
+    let indices: Vec<usize> = build::drawable_letter_parts(&LogoConfig::default(), &parts).into_iter().map(|(index, _)| index).collect();
+    assert_eq!(indices, [0, 2]);
+}
@@ -0,0 +1,46 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/camera.rs                          ║
+// ║  Tests de l'enregistrement de la caméra                                  ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::{CameraState, LogoConfig, LogoError};
+
+#[test]
+fn camera_state_round_trips_and_rejects_corrupt_files() {
+    let dir = std::env::temp_dir().join(format!("logo_camera_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("camera.json");
+    let state = CameraState { translation: Vec2::new(120.0, -45.5), scale: 0.5 };
+    state.save(&path).unwrap();
+    assert_eq!(CameraState::load(&path).unwrap(), state);
+
+    // Fichier absent : erreur d'entrée/sortie (caméra par défaut conservée)
+    assert!(matches!(CameraState::load(dir.join("absent.json")), Err(LogoError::Io(_))));
+
+    // JSON tronqué ou échelle nulle : rejetés
+    std::fs::write(&path, r#"{ "translation": [1.0, 2.0], "sca"#).unwrap();
+    assert!(matches!(CameraState::load(&path), Err(LogoError::Parse(_))));
+    std::fs::write(&path, r#"{ "translation": [1.0, 2.0], "scale": 0.0 }"#).unwrap();
+    assert!(matches!(CameraState::load(&path), Err(LogoError::Parse(_))));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn restored_zoom_is_clamped_to_the_configured_range() {
+    let config = LogoConfig { zoom_min: 0.5, zoom_max: 4.0, ..Default::default() };
+    let translation = Vec2::new(10.0, 20.0);
+
+    // Échelle 0.01 : zoom ×100, au-delà de zoom_max
+    let close = CameraState { translation, scale: 0.01 }.clamp_zoom(&config);
+    assert_eq!(close, CameraState { translation, scale: 0.25 });
+
+    // Échelle 10 : zoom ×0.1, en deçà de zoom_min
+    let far = CameraState { translation, scale: 10.0 }.clamp_zoom(&config);
+    assert_eq!(far.scale, 2.0);
+
+    let inside = CameraState { translation, scale: 1.0 };
+    assert_eq!(inside.clamp_zoom(&config), inside);
+}
@@ -0,0 +1,217 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/config.rs                          ║
+// ║  Tests de la validation et de la construction de la configuration        ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::{ConfigError, LogoConfig, LogoError, LogoPlugin, Preset, RainbowStyle};
+
+#[test]
+fn builder_produces_valid_config() {
+    let config = LogoConfig::builder()
+        .circle_radius(250.0)
+        .exterior_count(24)
+        .background(Color::BLACK)
+        .build()
+        .expect("configuration valide");
+
+    assert_eq!(config.circle_radius, 250.0);
+    assert_eq!(config.exterior_triangles_count, 24);
+    assert_eq!(config.background_color, Color::BLACK);
+    assert!(LogoConfig::default().validate().is_ok());
+}
+
+#[test]
+fn builder_rejects_zero_segments() {
+    let result = LogoConfig::builder().ring_segments(0).build();
+
+    assert_eq!(
+        result,
+        Err(ConfigError::TooFew { field: "ring_segments", value: 0, min: 3 })
+    );
+}
+
+#[test]
+fn each_circle_kind_validates_its_own_segment_count() {
+    let dots = LogoConfig::builder().small_circles(10.0, 2).build();
+    assert_eq!(dots, Err(ConfigError::TooFew { field: "dot_segments", value: 2, min: 3 }));
+
+    let hub = LogoConfig::builder().hub_segments(2).build();
+    assert_eq!(hub, Err(ConfigError::TooFew { field: "hub_segments", value: 2, min: 3 }));
+
+    // Anneau fin et petits cercles grossiers : combinaison valide
+    assert!(LogoConfig::builder().ring_segments(512).small_circles(10.0, 3).build().is_ok());
+}
+
+#[test]
+fn every_preset_is_valid() {
+    for preset in [Preset::Thumbnail, Preset::Default, Preset::Poster] {
+        let config = LogoConfig::preset(preset);
+        assert_eq!(config.validate(), Ok(()), "{preset:?}");
+    }
+
+    let poster = LogoConfig::preset(Preset::Poster);
+    assert_eq!(poster.ring_segments, 256);
+    assert_eq!(poster.msaa_samples, 8);
+
+    let thumbnail = LogoConfig::preset(Preset::Thumbnail);
+    let default = LogoConfig::default();
+    assert!(thumbnail.ring_segments < default.ring_segments);
+    assert_eq!(thumbnail.circle_radius / default.circle_radius, thumbnail.letter_scale);
+}
+
+#[test]
+fn default_layer_depths_keep_historical_order() {
+    let config = LogoConfig::default();
+
+    let depths = [
+        config.z_ring,
+        config.z_exterior,
+        config.z_interior,
+        config.z_stars,
+        config.z_dots,
+        config.r_base_z,
+    ];
+    assert_eq!(depths, [0.0, 0.1, 0.2, 0.25, 0.3, 0.4]);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn colliding_layer_depths_are_rejected() {
+    let config = LogoConfig {
+        z_dots: 0.2,
+        ..Default::default()
+    };
+
+    assert!(matches!(config.validate(), Err(ConfigError::LayerCollision { z, .. }) if z == 0.2));
+}
+
+#[test]
+fn degenerate_grid_spiral_and_margin_are_rejected() {
+    let grid = LogoConfig { grid_cell_size: 0.0, ..Default::default() };
+    assert_eq!(grid.validate(), Err(ConfigError::NonPositive { field: "grid_cell_size", value: 0.0 }));
+
+    let spiral = LogoConfig { show_spiral: true, spiral_radius: 0.0, ..Default::default() };
+    assert_eq!(spiral.validate(), Err(ConfigError::NonPositive { field: "spiral_radius", value: 0.0 }));
+
+    let turns = LogoConfig { spiral_turns: f32::INFINITY, ..Default::default() };
+    assert!(matches!(turns.validate(), Err(ConfigError::NonPositive { field: "spiral_turns", .. })));
+
+    let margin = LogoConfig { pan_margin: -1.0, ..Default::default() };
+    assert_eq!(margin.validate(), Err(ConfigError::Negative { field: "pan_margin", value: -1.0 }));
+    assert!(LogoConfig { pan_margin: 0.0, ..Default::default() }.validate().is_ok());
+}
+
+#[test]
+fn exterior_gaps_covering_a_full_turn_are_rejected() {
+    let config = LogoConfig { exterior_triangles_count: 36, exterior_gap_degrees: 10.0, ..Default::default() };
+    assert_eq!(config.validate(), Err(ConfigError::InvalidExteriorGap { gap: 10.0, count: 36 }));
+    assert!(LogoConfig { exterior_gap_degrees: -5.0, ..Default::default() }.validate().is_ok());
+}
+
+#[test]
+fn non_positive_letter_scale_is_rejected() {
+    let config = LogoConfig { letter_scale: 0.0, ..Default::default() };
+    assert_eq!(config.validate(), Err(ConfigError::NonPositive { field: "letter_scale", value: 0.0 }));
+}
+
+#[test]
+fn negative_fit_margin_is_rejected() {
+    let config = LogoConfig { fit_margin: -10.0, ..Default::default() };
+    assert_eq!(config.validate(), Err(ConfigError::Negative { field: "fit_margin", value: -10.0 }));
+}
+
+#[test]
+fn negative_or_infinite_tile_spacing_is_rejected() {
+    let negative = LogoConfig { tile: Some((2, 2, -1.0)), ..Default::default() };
+    assert_eq!(negative.validate(), Err(ConfigError::Negative { field: "tile", value: -1.0 }));
+
+    let infinite = LogoConfig { tile: Some((2, 2, f32::INFINITY)), ..Default::default() };
+    assert!(matches!(infinite.validate(), Err(ConfigError::Negative { field: "tile", .. })));
+}
+
+#[test]
+fn non_positive_r_z_step_is_rejected() {
+    let config = LogoConfig { r_z_step: -0.01, ..Default::default() };
+    assert_eq!(config.validate(), Err(ConfigError::NonPositive { field: "r_z_step", value: -0.01 }));
+}
+
+#[test]
+fn opacities_outside_zero_one_are_rejected() {
+    let shadow = LogoConfig { shadow_opacity: 1.5, ..Default::default() };
+    assert_eq!(shadow.validate(), Err(ConfigError::OutOfUnitRange { field: "shadow_opacity", value: 1.5 }));
+
+    let global = LogoConfig { global_opacity: -0.1, ..Default::default() };
+    assert_eq!(global.validate(), Err(ConfigError::OutOfUnitRange { field: "global_opacity", value: -0.1 }));
+}
+
+#[test]
+#[should_panic(expected = "grid_cell_size doit être strictement positif")]
+fn plugin_refuses_an_invalid_config() {
+    let config = LogoConfig { show_grid: true, grid_cell_size: 0.0, ..Default::default() };
+
+    App::new().add_plugins(LogoPlugin::new(config));
+}
+
+#[test]
+fn lerp_endpoints_match_the_source_configs() {
+    let a = LogoConfig::default();
+    let b = LogoConfig {
+        circle_radius: 320.0,
+        circle_thickness: 40.0,
+        exterior_triangles_count: 24,
+        interior_triangles_count: 7,
+        small_circle_radius: 22.0,
+        background_color: Color::WHITE,
+        ..Default::default()
+    };
+
+    assert_eq!(LogoConfig::lerp(&a, &b, 0.0), a);
+    assert_eq!(LogoConfig::lerp(&a, &b, 1.0).circle_radius, b.circle_radius);
+    assert_eq!(LogoConfig::lerp(&a, &b, 1.0).exterior_triangles_count, 24);
+
+    let middle = LogoConfig::lerp(&a, &b, 0.5);
+    assert_eq!(middle.circle_radius, (a.circle_radius + b.circle_radius) / 2.0);
+    assert_eq!(middle.exterior_triangles_count, 30);
+    assert_eq!(middle.interior_triangles_count, 6);
+}
+
+#[test]
+fn toml_config_round_trips_and_reports_errors() {
+    let dir = std::env::temp_dir().join(format!("logo_config_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // === ALLER-RETOUR COMPLET ===
+    let config = LogoConfig {
+        circle_radius: 250.0,
+        letter: 'S',
+        rainbow_style: RainbowStyle::Gradient,
+        tile: Some((2, 3, 10.0)),
+        background_color: Color::srgb(0.1, 0.2, 0.3),
+        ..Default::default()
+    };
+    let full = dir.join("full.toml");
+    std::fs::write(&full, config.to_toml_string().unwrap()).unwrap();
+    assert_eq!(LogoConfig::from_toml_path(&full).unwrap(), config);
+
+    // === FICHIER PARTIEL : valeurs par défaut pour le reste ===
+    let partial = dir.join("partial.toml");
+    std::fs::write(&partial, "exterior_triangles_count = 24\n").unwrap();
+    let loaded = LogoConfig::from_toml_path(&partial).unwrap();
+    assert_eq!(loaded, LogoConfig { exterior_triangles_count: 24, ..Default::default() });
+
+    // === ERREURS ===
+    let broken = dir.join("broken.toml");
+    std::fs::write(&broken, "circle_radius = \"grand\"\n").unwrap();
+    assert!(matches!(LogoConfig::from_toml_path(&broken), Err(LogoError::Parse(_))));
+
+    let invalid = dir.join("invalid.toml");
+    std::fs::write(&invalid, "ring_segments = 0\n").unwrap();
+    assert!(matches!(
+        LogoConfig::from_toml_path(&invalid),
+        Err(LogoError::InvalidConfig(ConfigError::TooFew { field: "ring_segments", .. }))
+    ));
+
+    assert!(matches!(LogoConfig::from_toml_path(dir.join("absent.toml")), Err(LogoError::Io(_))));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
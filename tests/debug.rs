@@ -0,0 +1,67 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/debug.rs                           ║
+// ║  Tests des outils de débogage                                            ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::render::settings::WgpuFeatures;
+use LogoRust_Bevy_20250929::systems::debug::{step_inspected_part, toggle_wireframe_system};
+use LogoRust_Bevy_20250929::{required_features, LogoConfig, Ruler, WireframeSupport};
+
+#[test]
+fn inspected_part_index_wraps_around_the_part_count() {
+    assert_eq!(step_inspected_part(None, true, 5), Some(0));
+    assert_eq!(step_inspected_part(None, false, 5), Some(4));
+    assert_eq!(step_inspected_part(Some(4), true, 5), Some(0));
+    assert_eq!(step_inspected_part(Some(0), false, 5), Some(4));
+    assert_eq!(step_inspected_part(Some(2), true, 5), Some(3));
+}
+
+#[test]
+fn letter_without_parts_has_nothing_to_inspect() {
+    assert_eq!(step_inspected_part(None, true, 0), None);
+}
+
+#[test]
+fn ruler_measures_after_two_clicks_and_clears_on_the_third() {
+    let mut ruler = Ruler::default();
+
+    ruler.click(Vec2::new(0.0, 0.0));
+    assert_eq!(ruler.distance(), None);
+
+    ruler.click(Vec2::new(30.0, 40.0));
+    assert_eq!(ruler.distance(), Some(50.0));
+
+    ruler.click(Vec2::new(5.0, 5.0));
+    assert!(ruler.points.is_empty());
+    assert_eq!(ruler.distance(), None);
+}
+
+#[test]
+fn wireframe_toggle_does_nothing_without_gpu_support() {
+    let mut world = World::new();
+    world.insert_resource(LogoConfig::default());
+    world.init_resource::<WireframeSupport>();
+    let mut keys = ButtonInput::<KeyCode>::default();
+    keys.press(KeyCode::KeyW);
+    world.insert_resource(keys);
+
+    world.run_system_once(toggle_wireframe_system).unwrap();
+    assert!(!world.resource::<LogoConfig>().wireframe);
+
+    world.insert_resource(WireframeSupport { available: true });
+    world.run_system_once(toggle_wireframe_system).unwrap();
+    assert!(world.resource::<LogoConfig>().wireframe);
+}
+
+#[test]
+fn line_polygon_mode_is_only_required_for_startup_wireframe() {
+    assert_eq!(required_features(&LogoConfig::default()), WgpuFeatures::empty());
+
+    let wireframe = LogoConfig {
+        wireframe: true,
+        ..LogoConfig::default()
+    };
+    assert_eq!(required_features(&wireframe), WgpuFeatures::POLYGON_MODE_LINE);
+}
@@ -0,0 +1,67 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/describe.rs                        ║
+// ║  Tests de la description de la scène sans fenêtre                        ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use LogoRust_Bevy_20250929::systems::layout;
+use LogoRust_Bevy_20250929::{build, geometry};
+use LogoRust_Bevy_20250929::{describe_scene, LogoConfig};
+
+#[test]
+fn description_has_one_entry_per_r_part() {
+    let description = describe_scene(&LogoConfig::default());
+
+    let names: Vec<&str> = description.elements_of_kind("letter_part").filter_map(|e| e.name.as_deref()).collect();
+    let parts = geometry::get_all_r_parts();
+    let expected: Vec<&str> = parts.iter().map(|part| &*part.name).collect();
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn description_serializes_to_json() {
+    let description = describe_scene(&LogoConfig::default());
+
+    let json = serde_json::to_string(&description).unwrap();
+
+    assert!(json.contains("\"kind\":\"main_circle\""));
+}
+
+#[test]
+fn center_hub_is_described_only_when_enabled() {
+    assert_eq!(describe_scene(&LogoConfig::default()).elements_of_kind("center_hub").count(), 0);
+
+    let config = LogoConfig::builder().center_hub(90.0, bevy::color::Color::BLACK).build().unwrap();
+    let hubs: Vec<_> = describe_scene(&config).elements_of_kind("center_hub").cloned().collect();
+    assert_eq!(hubs.len(), 1);
+    assert_eq!(hubs[0].translation[2], config.z_hub);
+}
+
+#[test]
+fn merged_letter_replaces_the_separate_parts() {
+    let config = LogoConfig { merge_r_parts: true, ..Default::default() };
+    let description = describe_scene(&config);
+
+    assert_eq!(description.elements_of_kind("merged_letter").count(), 1);
+    assert_eq!(description.elements_of_kind("letter_part").count(), 0);
+}
+
+#[test]
+fn centroid_markers_are_described_only_when_enabled() {
+    assert_eq!(describe_scene(&LogoConfig::default()).elements_of_kind("centroid_marker").count(), 0);
+
+    let config = LogoConfig { show_centroids: true, ..Default::default() };
+    let markers = describe_scene(&config).elements_of_kind("centroid_marker").count();
+    assert_eq!(markers, build::build_centroid_markers(&config).len());
+    assert!(markers > 0);
+}
+
+#[test]
+fn tiling_lists_one_root_position_per_cell() {
+    assert_eq!(describe_scene(&LogoConfig::default()).tiles, [[0.0, 0.0]]);
+
+    let config = LogoConfig { tile: Some((2, 3, 20.0)), ..Default::default() };
+    let description = describe_scene(&config);
+    let expected: Vec<[f32; 2]> = layout::tile_offsets(&config).iter().map(|offset| offset.to_array()).collect();
+    assert_eq!(description.tiles, expected);
+    assert_eq!(description.tiles.len(), 6);
+}
@@ -0,0 +1,35 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: tests/draw.rs                           ║
+// ║  Tests des formes créées à la demande                                    ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::{spawn_circle, spawn_line, spawn_polygon};
+
+#[test]
+fn draw_helpers_spawn_one_mesh_entity_per_shape_at_the_requested_depth() {
+    let mut world = World::new();
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<ColorMaterial>>();
+
+    world
+        .run_system_once(
+            |mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<ColorMaterial>>| {
+                spawn_circle(&mut commands, &mut meshes, &mut materials, Vec2::new(10.0, 20.0), 5.0, Color::WHITE, 1.0);
+                let square = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+                spawn_polygon(&mut commands, &mut meshes, &mut materials, &square, Color::BLACK, 2.0);
+                spawn_line(&mut commands, &mut meshes, &mut materials, (Vec2::ZERO, Vec2::X * 10.0), 2.0, Color::BLACK, 3.0);
+            },
+        )
+        .unwrap();
+
+    let mut depths: Vec<Vec3> = world
+        .query_filtered::<&Transform, (With<Mesh2d>, With<MeshMaterial2d<ColorMaterial>>)>()
+        .iter(&world)
+        .map(|transform| transform.translation)
+        .collect();
+    depths.sort_by(|a, b| a.z.total_cmp(&b.z));
+    assert_eq!(depths, vec![Vec3::new(10.0, 20.0, 1.0), Vec3::new(0.0, 0.0, 2.0), Vec3::new(0.0, 0.0, 3.0)]);
+    assert_eq!(world.resource::<Assets<Mesh>>().len(), 3);
+}
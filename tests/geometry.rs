@@ -0,0 +1,896 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: tests/geometry.rs                         ║
+// ║  Tests des fonctions de géométrie et de création de mesh                 ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use LogoRust_Bevy_20250929::{config, geometry};
+use LogoRust_Bevy_20250929::LogoError;
+
+/// Nombre d'éléments d'un attribut de mesh (0 si absent)
+fn attribute_len(mesh: &Mesh, attribute: bevy::render::mesh::MeshVertexAttribute) -> usize {
+    mesh.attribute(attribute).map(VertexAttributeValues::len).unwrap_or(0)
+}
+
+/// Nombre d'indices d'un mesh (0 s'il n'est pas indexé)
+fn index_len(mesh: &Mesh) -> usize {
+    mesh.indices().map(|indices| indices.len()).unwrap_or(0)
+}
+
+/// Aires signées des triangles d'un mesh indexé, dans l'ordre des indices
+fn triangle_signed_areas(mesh: &Mesh) -> Vec<f32> {
+    let indices: Vec<usize> = mesh.indices().expect("mesh indexé").iter().collect();
+    indices
+        .chunks(3)
+        .map(|triangle| {
+            let points = triangle.iter().map(|&i| Vec2::from_slice(&position(mesh, i))).collect::<Vec<_>>();
+            geometry::polygon_signed_area(&points)
+        })
+        .collect()
+}
+
+/// Position du vertex d'index `index`
+fn position(mesh: &Mesh, index: usize) -> [f32; 3] {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions[index],
+        _ => panic!("positions absentes ou d'un type inattendu"),
+    }
+}
+
+#[test]
+fn filled_circle_with_colors_has_one_color_per_vertex() {
+    let mesh = geometry::create_filled_circle_mesh_with_colors(
+        50.0,
+        32,
+        Color::WHITE,
+        Color::srgb(0.8, 0.2, 0.1),
+    );
+
+    let vertex_count = attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION);
+    assert_eq!(vertex_count, 33);
+    assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_COLOR), vertex_count);
+}
+
+#[test]
+fn ring_with_colors_has_one_color_per_vertex() {
+    let mesh = geometry::create_circle_mesh_with_colors(
+        200.0,
+        170.0,
+        64,
+        Color::BLACK,
+        Color::WHITE,
+    );
+
+    let vertex_count = attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION);
+    assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_COLOR), vertex_count);
+}
+
+#[test]
+fn interior_angles_for_six_triangles_are_sixty_degrees_apart() {
+    let angles: Vec<f32> = (0..6)
+        .map(|i| geometry::interior_triangle_angle_degrees(i, 6))
+        .collect();
+
+    assert_eq!(angles[0], 90.0);
+    for pair in angles.windows(2) {
+        assert!((pair[1] - pair[0] - 60.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn assigned_z_orders_are_increasing_and_unique() {
+    let mut parts: Vec<geometry::RPartDefinition> = (0..10)
+        .map(|_| geometry::RPartDefinition {
+            name: "Partie".into(),
+            points: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
+            z_order: 0.0,
+        })
+        .collect();
+
+    geometry::assign_z_orders(&mut parts, 0.40, 0.01);
+
+    let z_values: Vec<f32> = parts.iter().map(|part| part.z_order).collect();
+    assert_eq!(z_values[0], 0.40);
+    assert!(z_values.windows(2).all(|pair| pair[1] > pair[0]));
+    assert!(geometry::find_z_collisions(&z_values).is_empty());
+}
+
+#[test]
+fn chaikin_with_zero_iterations_returns_input() {
+    let points = vec![Vec2::ZERO, Vec2::new(1.0, 2.0), Vec2::new(3.0, -1.0)];
+    assert_eq!(geometry::chaikin_smooth(&points, 0, false), points);
+}
+
+#[test]
+fn chaikin_one_iteration_rounds_a_square_into_an_octagon() {
+    let square = [
+        Vec2::new(-4.0, -4.0),
+        Vec2::new(4.0, -4.0),
+        Vec2::new(4.0, 4.0),
+        Vec2::new(-4.0, 4.0),
+    ];
+
+    let smoothed = geometry::chaikin_smooth(&square, 1, true);
+
+    // 2 points par côté, les coins d'origine ont disparu
+    assert_eq!(smoothed.len(), 8);
+    assert!(square.iter().all(|corner| !smoothed.contains(corner)));
+
+    // Chaque point est à ¼ ou ¾ d'un côté : |x| ou |y| vaut 2, l'autre 4
+    for p in &smoothed {
+        let (ax, ay) = (p.x.abs(), p.y.abs());
+        assert!((ax == 4.0 && ay == 2.0) || (ax == 2.0 && ay == 4.0), "{p:?}");
+    }
+}
+
+#[test]
+fn simplify_collapses_a_nearly_straight_line_to_its_endpoints() {
+    let line: Vec<Vec2> = (0..=10)
+        .map(|i| Vec2::new(i as f32, if i % 2 == 0 { 0.0 } else { 0.05 }))
+        .collect();
+
+    let simplified = geometry::simplify_polyline(&line, 0.1);
+    assert_eq!(simplified, vec![line[0], line[10]]);
+
+    // epsilon nul : aucune simplification
+    assert_eq!(geometry::simplify_polyline(&line, 0.0), line);
+}
+
+#[test]
+fn resample_ten_unit_line_every_two_units_gives_six_points() {
+    let line = [Vec2::ZERO, Vec2::new(10.0, 0.0)];
+
+    let resampled = geometry::resample_uniform(&line, 2.0);
+
+    assert_eq!(resampled.len(), 6);
+    for (i, p) in resampled.iter().enumerate() {
+        assert!((p.x - 2.0 * i as f32).abs() < 1e-4);
+    }
+
+    // Espacement invalide : chemin inchangé
+    assert_eq!(geometry::resample_uniform(&line, 0.0), line.to_vec());
+}
+
+#[test]
+fn catmull_rom_passes_through_every_control_point() {
+    let controls = [
+        Vec2::new(-100.0, 0.0),
+        Vec2::new(-30.0, 60.0),
+        Vec2::new(40.0, -20.0),
+        Vec2::new(100.0, 50.0),
+    ];
+
+    for closed in [false, true] {
+        let curve = geometry::catmull_rom_spline(&controls, 8, closed);
+        for control in &controls {
+            assert!(
+                curve.iter().any(|p| p.distance(*control) < 1e-4),
+                "{control:?} absent (fermée : {closed})"
+            );
+        }
+    }
+
+    assert_eq!(geometry::catmull_rom_spline(&controls, 8, false).len(), 3 * 8 + 1);
+}
+
+#[test]
+fn offset_square_by_one_unit_grows_each_side() {
+    let square = [
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(-1.0, 1.0),
+    ];
+
+    // Même résultat quel que soit le sens de parcours
+    let mut clockwise = square;
+    clockwise.reverse();
+    for polygon in [square, clockwise] {
+        let grown = geometry::offset_polygon(&polygon, 1.0);
+        for (p, original) in grown.iter().zip(polygon) {
+            assert!(p.distance(original * 2.0) < 1e-5, "{p:?}");
+        }
+    }
+
+    let shrunk = geometry::offset_polygon(&square, -0.5);
+    assert!(shrunk.iter().zip(square).all(|(p, original)| p.distance(original * 0.5) < 1e-5));
+}
+
+#[test]
+fn mirror_across_x_negates_y_and_keeps_orientation() {
+    let triangle = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(0.0, 5.0)];
+
+    let mirrored = geometry::mirror_points(&triangle, geometry::Axis::X);
+
+    for p in &triangle {
+        assert!(mirrored.contains(&Vec2::new(p.x, -p.y)));
+    }
+    assert_eq!(
+        geometry::polygon_signed_area(&mirrored).signum(),
+        geometry::polygon_signed_area(&triangle).signum()
+    );
+}
+
+#[test]
+fn grid_line_count_matches_rows_plus_columns() {
+    // 100 × 60 par cases de 10 : 11 colonnes (x = -50..50), 7 rangées (y = -30..30)
+    let grid = geometry::create_grid_mesh(100.0, 60.0, 10.0);
+
+    let line_count = attribute_len(&grid, Mesh::ATTRIBUTE_POSITION) / 2;
+    assert_eq!(line_count, 11 + 7);
+}
+
+#[test]
+fn grid_with_degenerate_cell_size_is_empty_or_bounded() {
+    for cell_size in [0.0, -10.0, f32::NAN, f32::INFINITY] {
+        let grid = geometry::create_grid_mesh(100.0, 60.0, cell_size);
+        assert_eq!(attribute_len(&grid, Mesh::ATTRIBUTE_POSITION), 0, "case {cell_size}");
+    }
+
+    // Case minuscule : nombre de lignes plafonné au lieu d'épuiser la mémoire
+    let grid = geometry::create_grid_mesh(100.0, 60.0, 1e-30);
+    let max_lines = 2 * (2 * geometry::MAX_GRID_LINES_PER_SIDE as usize + 1);
+    assert_eq!(attribute_len(&grid, Mesh::ATTRIBUTE_POSITION) / 2, max_lines);
+}
+
+#[test]
+fn thick_line_is_one_quad_of_the_requested_width() {
+    let line = geometry::create_thick_line_mesh(Vec2::ZERO, Vec2::new(10.0, 0.0), 2.0);
+
+    assert_eq!(attribute_len(&line, Mesh::ATTRIBUTE_POSITION), 4);
+    assert_eq!(index_len(&line), 2 * 3);
+    assert_eq!(position(&line, 0), [0.0, 1.0, 0.0]);
+    assert_eq!(position(&line, 2), [10.0, -1.0, 0.0]);
+}
+
+#[test]
+fn zero_length_thick_line_is_a_flat_quad() {
+    let point = Vec2::new(3.0, 4.0);
+    let line = geometry::create_thick_line_mesh(point, point, 2.0);
+
+    // Même topologie, sans surface : rien à afficher, mais pas de NaN
+    assert_eq!(attribute_len(&line, Mesh::ATTRIBUTE_POSITION), 4);
+    assert_eq!(index_len(&line), 2 * 3);
+    assert!(triangle_signed_areas(&line).iter().all(|area| area.abs() < 1e-6));
+    assert!((0..4).all(|i| position(&line, i).iter().all(|v| v.is_finite())));
+}
+
+#[test]
+fn arrow_is_a_head_triangle_plus_a_shaft_quad() {
+    let arrow = geometry::create_arrow_mesh(Vec2::ZERO, Vec2::new(10.0, 0.0), 1.0, 3.0, 2.0);
+
+    assert_eq!(attribute_len(&arrow, Mesh::ATTRIBUTE_POSITION), 3 + 4);
+    assert_eq!(index_len(&arrow), 3 + 2 * 3);
+    // La pointe arrive exactement sur l'extrémité
+    assert_eq!(position(&arrow, 1), [10.0, 0.0, 0.0]);
+}
+
+#[test]
+fn short_and_zero_length_arrows_keep_only_the_head() {
+    // Plus courte que la pointe : le corps disparaît
+    let short = geometry::create_arrow_mesh(Vec2::ZERO, Vec2::new(2.0, 0.0), 1.0, 3.0, 2.0);
+    assert_eq!(attribute_len(&short, Mesh::ATTRIBUTE_POSITION), 3);
+    assert_eq!(index_len(&short), 3);
+
+    let point = Vec2::new(3.0, 4.0);
+    let zero = geometry::create_arrow_mesh(point, point, 1.0, 3.0, 2.0);
+    assert_eq!(attribute_len(&zero, Mesh::ATTRIBUTE_POSITION), 3);
+    assert_eq!(index_len(&zero), 3);
+    assert!(triangle_signed_areas(&zero)[0].abs() < 1e-6);
+}
+
+#[test]
+fn dashed_line_of_length_ten_has_three_dashes() {
+    // Tirets sur [0, 2], [4, 6] et [8, 10]
+    let dashed = geometry::create_dashed_line_mesh(Vec2::ZERO, Vec2::new(10.0, 0.0), 1.0, 2.0, 2.0);
+
+    // 4 vertices par tiret (un quadrilatère)
+    assert_eq!(attribute_len(&dashed, Mesh::ATTRIBUTE_POSITION), 3 * 4);
+}
+
+#[test]
+fn dashed_outline_pattern_continues_around_corners() {
+    // Périmètre 40, période 4 : 10 tirets
+    let square = [Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)];
+    let vertices = |offset: f32| {
+        let mesh = geometry::create_dashed_outline_mesh(&square, 1.0, 2.0, 2.0, offset);
+        attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION)
+    };
+
+    assert_eq!(vertices(0.0), 10 * 4);
+    // Décalé de 1 : les tirets [9, 11] et [29, 31] sont coupés par un coin
+    assert_eq!(vertices(1.0), 12 * 4);
+    // Le motif est périodique
+    assert_eq!(vertices(4.0), vertices(0.0));
+}
+
+#[test]
+fn recommended_segments_grow_with_radius() {
+    let small = geometry::recommended_segments(50.0, 0.5);
+    let medium = geometry::recommended_segments(200.0, 0.5);
+    let large = geometry::recommended_segments(800.0, 0.5);
+
+    assert!(small < medium && medium < large, "{small} {medium} {large}");
+
+    // L'écart réel reste sous la tolérance
+    let sagitta = 200.0 * (1.0 - (std::f32::consts::PI / medium as f32).cos());
+    assert!(sagitta <= 0.5);
+}
+
+#[test]
+fn ring_has_two_vertices_and_six_indices_per_segment() {
+    for segments in [3, 16, 64] {
+        let ring = geometry::create_circle_mesh(200.0, 170.0, segments);
+
+        assert_eq!(attribute_len(&ring, Mesh::ATTRIBUTE_POSITION), 2 * segments);
+        assert_eq!(index_len(&ring), 6 * segments);
+    }
+}
+
+#[test]
+fn ring_and_filled_circle_share_counter_clockwise_winding() {
+    let ring = triangle_signed_areas(&geometry::create_circle_mesh(200.0, 170.0, 64));
+    let disc = triangle_signed_areas(&geometry::create_filled_circle_mesh(15.0, 32));
+
+    assert_eq!(ring[0].signum(), disc[0].signum());
+    assert!(ring.iter().chain(&disc).all(|&area| area > 0.0));
+}
+
+#[test]
+fn gear_body_and_teeth_are_counter_clockwise() {
+    let gear = triangle_signed_areas(&geometry::create_gear_mesh(12, 40.0, 80.0, 100.0, 0.5));
+
+    assert!(gear.iter().all(|&area| area > 0.0));
+}
+
+#[test]
+fn radial_uv_ring_duplicates_seam_vertices() {
+    let segments = 32;
+    let ring = geometry::create_circle_mesh_with_radial_uvs(200.0, 170.0, segments);
+
+    let vertex_count = attribute_len(&ring, Mesh::ATTRIBUTE_POSITION);
+    assert_eq!(attribute_len(&ring, Mesh::ATTRIBUTE_UV_0), vertex_count);
+    assert_eq!(vertex_count, 2 * (segments + 1));
+
+    let Some(VertexAttributeValues::Float32x2(uvs)) = ring.attribute(Mesh::ATTRIBUTE_UV_0) else {
+        panic!("UV absentes ou d'un type inattendu");
+    };
+    // Raccord extérieur : même position, U = 0 puis U = 1
+    let first = Vec3::from(position(&ring, 0));
+    let seam = Vec3::from(position(&ring, segments));
+    assert!(first.distance(seam) < 1e-3);
+    assert_eq!((uvs[0], uvs[segments]), ([0.0, 1.0], [1.0, 1.0]));
+    // Raccord intérieur
+    assert_eq!((uvs[segments + 1], uvs[2 * segments + 1]), ([0.0, 0.0], [1.0, 0.0]));
+    assert!(triangle_signed_areas(&ring).iter().all(|&area| area > 0.0));
+}
+
+#[test]
+fn ring_first_vertex_is_on_the_positive_x_axis() {
+    let ring = geometry::create_circle_mesh(200.0, 170.0, 64);
+    assert_eq!(position(&ring, 0), [200.0, 0.0, 0.0]);
+}
+
+#[test]
+fn filled_circle_has_center_plus_one_vertex_per_segment() {
+    for segments in [3, 16, 32] {
+        let disc = geometry::create_filled_circle_mesh(15.0, segments);
+
+        assert_eq!(attribute_len(&disc, Mesh::ATTRIBUTE_POSITION), segments + 1);
+        assert_eq!(index_len(&disc), 3 * segments);
+        assert_eq!(position(&disc, 0), [0.0, 0.0, 0.0]);
+    }
+}
+
+#[test]
+fn filled_circle_with_too_few_segments_has_valid_indices() {
+    for segments in [0, 1, 2, 3] {
+        let disc = geometry::create_filled_circle_mesh(15.0, segments);
+
+        let vertex_count = attribute_len(&disc, Mesh::ATTRIBUTE_POSITION);
+        assert!(vertex_count >= 4, "{segments} segments : {vertex_count} vertices");
+        assert!(
+            disc.indices().unwrap().iter().all(|index| index < vertex_count),
+            "{segments} segments : index hors limites"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "au moins 3 dents")]
+fn gear_with_two_teeth_panics() {
+    geometry::create_gear_mesh(2, 40.0, 80.0, 100.0, 0.5);
+}
+
+#[test]
+fn gear_vertex_count_grows_with_teeth() {
+    let counts: Vec<usize> = [3, 8, 24]
+        .iter()
+        .map(|&teeth| {
+            let gear = geometry::create_gear_mesh(teeth, 40.0, 80.0, 100.0, 0.5);
+            attribute_len(&gear, Mesh::ATTRIBUTE_POSITION)
+        })
+        .collect();
+
+    assert!(counts.windows(2).all(|pair| pair[1] > pair[0]), "{counts:?}");
+}
+
+/// Vrai si les segments [a, b] et [c, d] se coupent (intersection stricte)
+fn segments_cross(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    let side = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    side(a, b, c) * side(a, b, d) < 0.0 && side(c, d, a) * side(c, d, b) < 0.0
+}
+
+#[test]
+fn heart_outline_is_closed_and_simple() {
+    for segments in [16, 64, 256] {
+        let outline = geometry::heart_outline(100.0, segments);
+        let count = outline.len();
+        assert_eq!(count, segments);
+
+        // Fermé : le segment de retour au premier point n'est pas un "trou"
+        let edges: Vec<(Vec2, Vec2)> = (0..count)
+            .map(|i| (outline[i], outline[(i + 1) % count]))
+            .collect();
+        let longest = edges.iter().map(|(a, b)| a.distance(*b)).fold(0.0, f32::max);
+        let closing = outline[count - 1].distance(outline[0]);
+        assert!(closing <= longest, "segments = {segments}");
+
+        // Simple : deux arêtes non adjacentes ne se croisent jamais
+        for i in 0..count {
+            for j in (i + 2)..count {
+                if i == 0 && j == count - 1 {
+                    continue;
+                }
+                let (a, b) = edges[i];
+                let (c, d) = edges[j];
+                assert!(!segments_cross(a, b, c, d), "segments = {segments}, arêtes {i} et {j}");
+            }
+        }
+    }
+}
+
+#[test]
+fn heart_is_centered_and_scaled_to_size() {
+    let outline = geometry::heart_outline(200.0, 128);
+    let (min, max) = geometry::bounding_box(&outline);
+
+    assert!(((min + max) / 2.0).length() < 1e-3);
+    assert!(((max - min).max_element() - 200.0).abs() < 1e-3);
+}
+
+#[test]
+fn spiral_centerline_runs_from_start_to_end_radius() {
+    let centerline = geometry::spiral_centerline(3.5, 10.0, 200.0, 224);
+
+    assert_eq!(centerline.len(), 225);
+    assert!((centerline[0].length() - 10.0).abs() < 1e-3);
+    assert!((centerline[224].length() - 200.0).abs() < 1e-3);
+
+    let mesh = geometry::create_spiral_mesh(3.5, 10.0, 200.0, 4.0, 224);
+    assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION), 2 * 225);
+}
+
+#[test]
+fn spiral_collapsed_to_a_point_is_an_empty_mesh() {
+    let mesh = geometry::create_spiral_mesh(6.0, 0.0, 0.0, 3.0, 216);
+
+    assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION), 0);
+    assert_eq!(index_len(&mesh), 0);
+}
+
+#[test]
+fn square_is_simple_polygon() {
+    let square = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Vec2::new(0.0, 10.0),
+    ];
+
+    assert!(geometry::is_simple_polygon(&square));
+}
+
+#[test]
+fn bowtie_is_not_simple_polygon() {
+    let bowtie = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Vec2::new(10.0, 0.0),
+        Vec2::new(0.0, 10.0),
+    ];
+
+    assert!(!geometry::is_simple_polygon(&bowtie));
+}
+
+#[test]
+fn letter_parts_are_simple_polygons() {
+    for letter in geometry::SUPPORTED_LETTERS {
+        for part in geometry::get_letter_parts(letter).unwrap() {
+            assert!(geometry::is_simple_polygon(&part.points), "{letter} : {}", part.name);
+        }
+    }
+}
+
+#[test]
+fn part_overlaps_distinguish_touching_and_separated_parts() {
+    let square = |x: f32, y: f32| geometry::RPartDefinition {
+        name: "carré".into(),
+        points: vec![
+            Vec2::new(x, y),
+            Vec2::new(x + 10.0, y),
+            Vec2::new(x + 10.0, y + 10.0),
+            Vec2::new(x, y + 10.0),
+        ],
+        z_order: 0.0,
+    };
+    // 0 et 1 partagent un bord ; 2 est isolé
+    let parts = [square(0.0, 0.0), square(10.0, 0.0), square(50.0, 50.0)];
+
+    let overlaps = geometry::find_part_overlaps(&parts);
+
+    assert_eq!(overlaps, vec![geometry::PartOverlap { first: 0, second: 1, edges_intersect: true }]);
+}
+
+#[test]
+fn auto_ccw_flips_clockwise_triangle() {
+    let clockwise = [Vec2::new(0.0, 0.0), Vec2::new(0.0, 10.0), Vec2::new(10.0, 0.0)];
+    let mut expected = clockwise;
+    geometry::ensure_ccw(&mut expected);
+
+    let mesh = geometry::create_triangle_with_winding(
+        clockwise[0],
+        clockwise[1],
+        clockwise[2],
+        geometry::Winding::AutoCcw,
+    );
+    let points: Vec<Vec2> = (0..3).map(|i| Vec2::from_slice(&position(&mesh, i))).collect();
+
+    assert!(geometry::polygon_signed_area(&points) > 0.0);
+    assert_eq!(points, expected);
+}
+
+#[test]
+fn r_logo_bounds_match_known_extremes() {
+    let (min, max) = geometry::r_logo_bounds();
+
+    assert_eq!(min, Vec2::new(-160.0, -80.0));
+    assert_eq!(max, Vec2::new(160.0, 90.0));
+}
+
+#[test]
+fn interior_apex_points_inward_or_outward() {
+    let side = 80.0;
+    let height = side * 3.0_f32.sqrt() / 2.0;
+
+    let ratio = config::EQUILATERAL_HEIGHT_RATIO;
+
+    let (b1, b2, inward) = geometry::calculate_interior_triangle_points_directed(0.0, 150.0, side, true, ratio);
+    let (c1, c2, outward) = geometry::calculate_interior_triangle_points_directed(0.0, 150.0, side, false, ratio);
+
+    // Même base, sommet de part et d'autre du cercle intérieur
+    assert_eq!((b1, b2), (c1, c2));
+    assert!(inward.distance(Vec2::new(150.0 - height, 0.0)) < 1e-4);
+    assert!(outward.distance(Vec2::new(150.0 + height, 0.0)) < 1e-4);
+}
+
+#[test]
+fn height_ratio_scales_apex_distance_from_base() {
+    let side = 30.0;
+    let radius = 200.0;
+
+    // Distance radiale du sommet au cercle portant la base
+    let apex_distance = |(_, _, apex): (Vec2, Vec2, Vec2)| (apex.length() - radius).abs();
+
+    let unit = apex_distance(geometry::calculate_exterior_triangle_points_with_gap(0.0, radius, side, 0.0, 1.0));
+    let doubled = apex_distance(geometry::calculate_exterior_triangle_points_with_gap(0.0, radius, side, 0.0, 2.0));
+    assert!((unit - side).abs() < 1e-3);
+    assert!((doubled - 2.0 * unit).abs() < 1e-3);
+
+    let unit = apex_distance(geometry::calculate_interior_triangle_points_directed(1.0, radius, side, true, 1.0));
+    let doubled = apex_distance(geometry::calculate_interior_triangle_points_directed(1.0, radius, side, true, 2.0));
+    assert!((doubled - 2.0 * unit).abs() < 1e-3);
+}
+
+#[test]
+fn point_in_polygon_follows_the_even_odd_rule() {
+    // "L" concave : le coin supérieur droit est hors du polygone
+    let l_shape = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(20.0, 0.0),
+        Vec2::new(20.0, 10.0),
+        Vec2::new(10.0, 10.0),
+        Vec2::new(10.0, 20.0),
+        Vec2::new(0.0, 20.0),
+    ];
+
+    assert!(geometry::point_in_polygon(Vec2::new(5.0, 15.0), &l_shape));
+    assert!(geometry::point_in_polygon(Vec2::new(15.0, 5.0), &l_shape));
+    assert!(!geometry::point_in_polygon(Vec2::new(15.0, 15.0), &l_shape));
+    assert!(!geometry::point_in_polygon(Vec2::new(-1.0, 5.0), &l_shape));
+    assert!(!geometry::point_in_polygon(Vec2::ZERO, &l_shape[..2]));
+}
+
+#[test]
+fn snap_to_grid_rounds_to_nearest_cell() {
+    assert_eq!(geometry::snap_to_grid(Vec2::new(12.3, -7.8), 5.0), Vec2::new(10.0, -10.0));
+    assert_eq!(geometry::snap_to_grid(Vec2::new(12.3, -7.8), 0.0), Vec2::new(12.3, -7.8));
+}
+
+#[test]
+fn distance_to_segment_interior_and_beyond_endpoint() {
+    let a = Vec2::new(0.0, 0.0);
+    let b = Vec2::new(10.0, 0.0);
+
+    // Projection à l'intérieur du segment : distance perpendiculaire
+    assert!((geometry::distance_point_to_segment(Vec2::new(4.0, 3.0), a, b) - 3.0).abs() < 1e-5);
+
+    // Au-delà de b : distance à l'extrémité
+    assert!((geometry::distance_point_to_segment(Vec2::new(13.0, 4.0), a, b) - 5.0).abs() < 1e-5);
+
+    // Segment dégénéré
+    assert!((geometry::distance_point_to_segment(Vec2::new(3.0, 4.0), a, a) - 5.0).abs() < 1e-5);
+}
+
+#[test]
+fn star_mesh_has_two_corners_per_point() {
+    let star = geometry::create_star_mesh(5, 20.0, 10.0);
+
+    assert_eq!(attribute_len(&star, Mesh::ATTRIBUTE_POSITION), 11);
+    assert_eq!(index_len(&star), 30);
+    assert_eq!(position(&star, 1), [20.0, 0.0, 0.0]);
+}
+
+#[test]
+fn nonzero_fill_covers_whole_pentagram() {
+    // Pentagramme : sommets d'un pentagone reliés un sur deux
+    let radius = 100.0;
+    let vertex = |k: usize| {
+        let angle = std::f32::consts::FRAC_PI_2 + k as f32 * std::f32::consts::TAU / 5.0;
+        Vec2::new(angle.cos(), angle.sin()) * radius
+    };
+    let pentagram: Vec<Vec2> = (0..5).map(|i| vertex(i * 2 % 5)).collect();
+
+    let mesh = geometry::create_polygon_nonzero_fill(&pentagram);
+
+    // Aire remplie : somme des aires des triangles
+    let Some(bevy::render::mesh::Indices::U32(indices)) = mesh.indices() else {
+        panic!("indices U32 attendus");
+    };
+    let filled: f32 = indices
+        .chunks(3)
+        .map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| Vec2::from_slice(&position(&mesh, i as usize)));
+            (b - a).perp_dot(c - a).abs() / 2.0
+        })
+        .sum();
+
+    // Aire attendue : étoile à 10 sommets (pointes et creux alternés)
+    let inner_radius = radius * (2.0 * std::f32::consts::PI / 5.0).cos() / (std::f32::consts::PI / 5.0).cos();
+    let star: Vec<Vec2> = (0..10)
+        .map(|k| {
+            let angle = std::f32::consts::FRAC_PI_2 + k as f32 * std::f32::consts::PI / 5.0;
+            let r = if k % 2 == 0 { radius } else { inner_radius };
+            Vec2::new(angle.cos(), angle.sin()) * r
+        })
+        .collect();
+    let expected = geometry::polygon_signed_area(&star).abs();
+
+    assert!((filled - expected).abs() / expected < 0.01, "{filled} / {expected}");
+}
+
+#[test]
+fn part_can_be_built_from_runtime_string_name() {
+    let index = 3;
+    let name = format!("Partie importée {index}");
+
+    let part = geometry::RPartDefinition::new(name.clone(), vec![Vec2::ZERO, Vec2::X, Vec2::Y], 0.5);
+
+    assert_eq!(part.name, name);
+    assert_eq!(part.points.len(), 3);
+    assert_eq!(part.z_order, 0.5);
+    assert!(geometry::RPartDefinition::default().points.is_empty());
+}
+
+#[test]
+fn circle_start_angle_rotates_first_vertex() {
+    let mesh = geometry::create_circle_mesh_from(100.0, 80.0, 16, std::f32::consts::PI);
+
+    let first = Vec2::from_slice(&position(&mesh, 0));
+    assert!(first.distance(Vec2::new(-100.0, 0.0)) < 1e-4, "{first}");
+    // Premier vertex intérieur, même angle
+    let first_inner = Vec2::from_slice(&position(&mesh, 16));
+    assert!(first_inner.distance(Vec2::new(-80.0, 0.0)) < 1e-4, "{first_inner}");
+}
+
+#[test]
+fn polygon_with_too_few_points_is_an_error() {
+    let result = geometry::try_create_polygon_from_points(&[Vec2::ZERO, Vec2::X]);
+
+    assert!(matches!(result, Err(LogoError::TooFewPoints { count: 2, min: 3 })));
+}
+
+#[test]
+fn concave_polygon_is_ear_clipped_without_overlap() {
+    // Carré de côté 2 entaillé en haut : le fan depuis (0, 2) déborderait
+    let notched = [Vec2::new(0.0, 2.0), Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(2.0, 2.0), Vec2::ONE];
+    let areas = triangle_signed_areas(&geometry::create_polygon_from_points(&notched));
+
+    assert_eq!(areas.len(), 3);
+    assert!(areas.iter().all(|&area| area > 0.0), "{areas:?}");
+    assert!((areas.iter().sum::<f32>() - geometry::polygon_signed_area(&notched)).abs() < 1e-5);
+}
+
+#[test]
+fn obj_export_lists_vertices_then_one_based_faces() {
+    let square = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+    let mesh = geometry::try_create_polygon_from_points(&square).unwrap();
+
+    let obj = geometry::to_obj(&mesh).unwrap();
+    let lines: Vec<&str> = obj.lines().collect();
+    assert_eq!(lines.iter().filter(|line| line.starts_with("v ")).count(), 4);
+    assert_eq!(&lines[4..], ["f 1 2 3", "f 1 3 4"]);
+}
+
+#[test]
+fn r_parts_load_from_json_and_report_errors() {
+    let dir = std::env::temp_dir().join(format!("logo_parts_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let valid = dir.join("valid.json");
+    std::fs::write(&valid, r#"[{ "name": "Barre", "points": [[0, 0], [10, 0], [10, 5]] }]"#).unwrap();
+    let parts = geometry::load_r_parts(&valid).unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].name, "Barre");
+    assert_eq!(parts[0].points[2], Vec2::new(10.0, 5.0));
+
+    let short = dir.join("short.json");
+    std::fs::write(&short, r#"[{ "name": "Trait", "points": [[0, 0], [10, 0]] }]"#).unwrap();
+    assert!(matches!(geometry::load_r_parts(&short), Err(LogoError::TooFewPoints { count: 2, .. })));
+
+    let broken = dir.join("broken.json");
+    std::fs::write(&broken, "pas du json").unwrap();
+    assert!(matches!(geometry::load_r_parts(&broken), Err(LogoError::Parse(_))));
+
+    assert!(matches!(geometry::load_r_parts(dir.join("absent.json")), Err(LogoError::Io(_))));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn polygon_defects_are_detected_in_order() {
+    use geometry::PolygonDefect;
+
+    let square = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+    let bow_tie = [Vec2::ZERO, Vec2::new(3.0, 1.0), Vec2::new(3.0, 0.0), Vec2::new(0.0, 2.0)];
+    let collinear = [Vec2::ZERO, Vec2::X, Vec2::new(2.0, 0.0)];
+
+    assert_eq!(geometry::polygon_defect(&square), None);
+    assert_eq!(geometry::polygon_defect(&square[..2]), Some(PolygonDefect::TooFewPoints(2)));
+    assert_eq!(geometry::polygon_defect(&collinear), Some(PolygonDefect::ZeroArea));
+    assert_eq!(geometry::polygon_defect(&bow_tie), Some(PolygonDefect::SelfIntersecting));
+    assert!(geometry::get_all_r_parts().iter().all(|part| geometry::polygon_defect(&part.points).is_none()));
+}
+
+#[test]
+fn normalize_angle_wraps_into_one_turn() {
+    use std::f32::consts::PI;
+
+    assert!((geometry::normalize_angle(3.0 * PI) - PI).abs() < 1e-5);
+    assert!((geometry::normalize_angle(-PI / 2.0) - 1.5 * PI).abs() < 1e-5);
+    assert_eq!(geometry::normalize_angle(0.0), 0.0);
+    assert!((0.0..2.0 * PI).contains(&geometry::normalize_angle(-1e-9)));
+}
+
+#[test]
+fn angle_from_origin_to_up_is_a_quarter_turn() {
+    let angle = geometry::angle_between_points(Vec2::ZERO, Vec2::new(0.0, 1.0));
+
+    assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert_eq!(geometry::angle_between_points(Vec2::ONE, Vec2::new(3.0, 1.0)), 0.0);
+}
+
+#[test]
+fn exterior_triangle_apex_points_outward_for_every_angle() {
+    let radius = 200.0;
+    for degrees in -360..=720 {
+        let angle = geometry::degrees_to_radians(degrees as f32);
+        let (p1, p2, p3) = geometry::calculate_exterior_triangle_points(angle, radius, 30.0);
+
+        assert!(p3.length() > radius, "{degrees}° : sommet à {}", p3.length());
+        // Même direction que le milieu de la base : le sommet est au-delà, pas en face
+        let base_middle = (p1 + p2) / 2.0;
+        assert!(p3.length() > base_middle.length());
+        assert!(p3.normalize().dot(base_middle.normalize()) > 0.999, "{degrees}°");
+    }
+}
+
+#[test]
+fn merged_meshes_offset_indices_and_apply_transforms() {
+    let triangle = geometry::create_triangle_from_points(Vec2::ZERO, Vec2::X, Vec2::Y);
+    let merged = geometry::merge_meshes(&[
+        (triangle.clone(), Transform::IDENTITY),
+        (triangle, Transform::from_xyz(10.0, 0.0, 0.5)),
+    ]);
+
+    let indices: Vec<usize> = merged.indices().unwrap().iter().collect();
+    assert_eq!(indices.len(), 6);
+    assert!(indices[3..].iter().all(|&index| index >= 3));
+
+    let Some(VertexAttributeValues::Float32x3(positions)) = merged.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        panic!("positions manquantes");
+    };
+    assert_eq!(positions.len(), 6);
+    assert!(positions[3..].iter().all(|&[x, _, z]| x >= 10.0 && z == 0.5));
+}
+
+// === DÉFINITION DU "R" ===
+// Géométrie de référence : toute modification doit être volontaire
+
+#[test]
+fn r_has_the_eight_documented_parts_in_order() {
+    let parts = geometry::get_all_r_parts();
+    let names: Vec<&str> = parts.iter().map(|part| &*part.name).collect();
+
+    assert_eq!(names, [
+        "Haut du R",
+        "Gauche du R",
+        "Arrondi du R",
+        "Centre du R",
+        "Pied gauche du R",
+        "Milieu du R",
+        "Jambe droite du R",
+        "Pied droit du R",
+    ]);
+}
+
+#[test]
+fn r_z_orders_increase_strictly_from_040_to_047() {
+    let parts = geometry::get_all_r_parts();
+    let z_orders: Vec<f32> = parts.iter().map(|part| part.z_order).collect();
+
+    assert!(z_orders.windows(2).all(|pair| pair[0] < pair[1]), "{z_orders:?}");
+    for (index, z) in z_orders.iter().enumerate() {
+        assert!((z - (0.40 + 0.01 * index as f32)).abs() < 1e-6, "partie {index} : Z = {z}");
+    }
+}
+
+#[test]
+fn r_part_outlines_are_pinned() {
+    let parts = geometry::get_all_r_parts();
+    let point_counts: Vec<usize> = parts.iter().map(|part| part.points.len()).collect();
+
+    assert!(point_counts.iter().all(|&count| count >= 3));
+    assert_eq!(point_counts, [4, 4, 5, 4, 6, 4, 4, 4]);
+    assert_eq!(geometry::r_logo_bounds(), (Vec2::new(-160.0, -80.0), Vec2::new(160.0, 90.0)));
+    assert_eq!(parts[0].points, [
+        Vec2::new(-140.0, 90.0),
+        Vec2::new(60.0, 90.0),
+        Vec2::new(60.0, 50.0),
+        Vec2::new(-100.0, 50.0),
+    ]);
+    assert_eq!(parts[7].points, [
+        Vec2::new(160.0, -50.0),
+        Vec2::new(30.0, -50.0),
+        Vec2::new(30.0, -80.0),
+        Vec2::new(120.0, -80.0),
+    ]);
+}
+
+#[test]
+fn auto_close_accepts_open_and_explicitly_closed_contours() {
+    let open = [Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)];
+    let mut closed = open.to_vec();
+    closed.push(Vec2::new(0.0, 0.0001));
+
+    let from_open = geometry::create_polygon_auto_close(&open);
+    let from_closed = geometry::create_polygon_auto_close(&closed);
+
+    assert_eq!(from_closed.count_vertices(), 4);
+    assert_eq!(
+        from_closed.attribute(Mesh::ATTRIBUTE_POSITION).unwrap().as_float3(),
+        from_open.attribute(Mesh::ATTRIBUTE_POSITION).unwrap().as_float3()
+    );
+    let indices = |mesh: &Mesh| mesh.indices().unwrap().iter().collect::<Vec<_>>();
+    assert_eq!(indices(&from_closed), indices(&from_open));
+}
@@ -0,0 +1,55 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: tests/headless.rs                         ║
+// ║  Tests de la préparation du rendu hors écran                             ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use std::sync::{Arc, Mutex};
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::systems::headless::{prepare_headless_target_system, HeadlessCapture};
+use LogoRust_Bevy_20250929::systems::setup::setup_system;
+use LogoRust_Bevy_20250929::{run_headless, LogoConfig, MainCamera};
+
+/// Prépare la cible hors écran et renvoie l'effacement de la caméra
+fn prepared_clear_color(config: LogoConfig) -> ClearColorConfig {
+    let mut world = World::new();
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<ColorMaterial>>();
+    world.init_resource::<Assets<Image>>();
+    world.insert_resource(config);
+    world.insert_resource(HeadlessCapture::new("logo.png".into(), Arc::new(Mutex::new(None))));
+
+    world.run_system_once(setup_system).unwrap();
+    world.run_system_once(prepare_headless_target_system).unwrap();
+
+    let camera = world.query_filtered::<&Camera, With<MainCamera>>().single(&world).unwrap();
+    assert!(matches!(camera.target, bevy::render::camera::RenderTarget::Image(_)));
+    camera.clear_color
+}
+
+#[test]
+fn transparent_export_clears_the_target_with_zero_alpha() {
+    let transparent = prepared_clear_color(LogoConfig {
+        transparent_export: true,
+        ..LogoConfig::default()
+    });
+    let ClearColorConfig::Custom(color) = transparent else {
+        panic!("effacement attendu : couleur transparente, obtenu {transparent:?}");
+    };
+    assert_eq!(color.alpha(), 0.0);
+
+    // Sans l'option, la caméra garde le fond de la scène (`ClearColor`)
+    assert!(matches!(prepared_clear_color(LogoConfig::default()), ClearColorConfig::Default));
+}
+
+#[test]
+fn invalid_config_is_refused_before_rendering() {
+    let path = std::env::temp_dir().join(format!("logo_invalid_{}.png", std::process::id()));
+    let config = LogoConfig { ring_segments: 0, ..LogoConfig::default() };
+
+    let error = run_headless(config, &path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(error.to_string().contains("ring_segments"), "{error}");
+    assert!(!path.exists());
+}
@@ -0,0 +1,72 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: tests/layout.rs                         ║
+// ║  Tests de l'ajustement du logo à la fenêtre                              ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::systems::layout;
+use LogoRust_Bevy_20250929::LogoConfig;
+
+#[test]
+fn logo_offset_shifts_fitted_root_without_changing_scale() {
+    let viewport = Vec2::new(1280.0, 720.0);
+    let centered = layout::root_transform(&LogoConfig::default(), viewport);
+    let shifted = layout::root_transform(
+        &LogoConfig {
+            logo_offset: Vec2::new(-300.0, 50.0),
+            ..Default::default()
+        },
+        viewport,
+    );
+
+    assert_eq!(shifted.translation - centered.translation, Vec3::new(-300.0, 50.0, 0.0));
+    assert_eq!(shifted.scale, centered.scale);
+}
+
+#[test]
+fn tile_offsets_form_a_centered_grid_spaced_by_the_logo_size() {
+    let config = LogoConfig {
+        tile: Some((2, 3, 10.0)),
+        ..Default::default()
+    };
+    let (min, max) = layout::logo_bounds(&config);
+    let pitch = max - min + Vec2::splat(10.0);
+
+    let offsets = layout::tile_offsets(&config);
+    assert_eq!(offsets.len(), 6);
+    assert_eq!(offsets[0], Vec2::new(-pitch.x, pitch.y / 2.0));
+    assert_eq!(offsets[5], Vec2::new(pitch.x, -pitch.y / 2.0));
+    assert_eq!(offsets.iter().sum::<Vec2>(), Vec2::ZERO);
+    assert_eq!(layout::tile_offsets(&LogoConfig::default()), [Vec2::ZERO]);
+}
+
+#[test]
+fn empty_tiling_still_has_one_row_and_one_column() {
+    let row = LogoConfig { tile: Some((0, 3, 10.0)), ..Default::default() };
+    assert_eq!(layout::tile_offsets(&row).len(), 3);
+
+    let none = LogoConfig { tile: Some((0, 0, 10.0)), ..Default::default() };
+    assert_eq!(layout::tile_offsets(&none), [Vec2::ZERO]);
+}
+
+#[test]
+fn logo_scale_stretches_the_root_and_still_fits_the_viewport() {
+    let viewport = Vec2::new(1280.0, 720.0);
+    let uniform = layout::root_transform(&LogoConfig::default(), viewport);
+    let config = LogoConfig {
+        logo_scale: Vec2::new(2.0, 0.5),
+        ..Default::default()
+    };
+    let stretched = layout::root_transform(&config, viewport);
+
+    assert!((stretched.scale.x / stretched.scale.y - 4.0).abs() < 1e-4);
+
+    // Les coins du logo étiré restent dans la zone visible (marge comprise)
+    let (min, max) = layout::logo_bounds(&config);
+    let half = viewport / 2.0 - Vec2::splat(config.fit_margin) + Vec2::splat(1e-3);
+    for corner in [min, max] {
+        let world = stretched.transform_point(corner.extend(0.0)).truncate();
+        assert!(world.abs().cmple(half).all(), "{world} hors de {half}");
+    }
+    assert_ne!(stretched.scale, uniform.scale);
+}
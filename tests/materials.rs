@@ -0,0 +1,112 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                      FICHIER: tests/materials.rs                         ║
+// ║  Tests des couleurs et matériaux                                         ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::prelude::*;
+use bevy::sprite::AlphaMode2d;
+use LogoRust_Bevy_20250929::{build, materials, LogoConfig};
+
+#[test]
+fn interior_colors_scale_with_count() {
+    for count in [3, 4, 5, 6, 12] {
+        assert_eq!(materials::interior_colors(count).len(), count);
+    }
+
+    // Au-delà de la palette, les couleurs se répètent
+    let colors = materials::interior_colors(6);
+    assert_eq!(colors[5], colors[0]);
+}
+
+#[test]
+fn random_palette_is_deterministic() {
+    assert_eq!(materials::random_palette(8, 42), materials::random_palette(8, 42));
+    assert_ne!(materials::random_palette(8, 42), materials::random_palette(8, 43));
+    assert_eq!(materials::random_palette(8, 42).len(), 8);
+}
+
+/// Écart de teinte (en degrés, dans [0, 180]) entre deux couleurs
+fn hue_distance(a: Color, b: Color) -> f32 {
+    let difference = (Hsva::from(a).hue - Hsva::from(b).hue).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+#[test]
+fn complementary_shifts_hue_by_half_turn() {
+    let red = materials::color_from_hsv(10.0, 0.8, 0.8);
+    let accent = materials::complementary(red);
+
+    assert!((Hsva::from(accent).hue - 190.0).abs() < 0.1);
+    assert!((hue_distance(red, accent) - 180.0).abs() < 0.1);
+}
+
+#[test]
+fn analogous_shifts_hue_by_spread() {
+    let base = materials::color_from_hsv(350.0, 0.7, 0.9);
+    let (before, after) = materials::analogous(base, 30.0);
+
+    assert!((Hsva::from(before).hue - 320.0).abs() < 0.1);
+    assert!((Hsva::from(after).hue - 20.0).abs() < 0.1);
+    assert!((hue_distance(base, after) - 30.0).abs() < 0.1);
+}
+
+#[test]
+fn main_circle_alpha_matches_argument() {
+    let translucent = materials::get_main_circle_color_with_alpha(0.4);
+    assert!((translucent.color.alpha() - 0.4).abs() < 1e-6);
+    assert_eq!(translucent.alpha_mode, AlphaMode2d::Blend);
+
+    let opaque = materials::get_main_circle_color();
+    assert_eq!(opaque.color.alpha(), 1.0);
+}
+
+#[test]
+fn classic_theme_reproduces_original_colors() {
+    let theme = materials::Theme::Classic;
+    let config = LogoConfig { interior_triangles_count: 7, ..Default::default() };
+
+    assert_eq!(theme.ring_color(), materials::get_main_circle_color().color);
+    assert_eq!(theme.letter_color(), LogoRust_Bevy_20250929::build::LETTER_COLOR);
+    for i in 0..7 {
+        assert_eq!(theme.interior_color(&config, i), materials::get_interior_triangle_color(i).color);
+    }
+}
+
+#[test]
+fn themes_cycle_back_to_classic() {
+    let mut theme = materials::Theme::Classic;
+    for _ in 0..materials::Theme::ALL.len() {
+        theme = theme.next();
+    }
+
+    assert_eq!(theme, materials::Theme::Classic);
+}
+
+#[test]
+fn rainbow_spans_the_full_wheel_for_any_count() {
+    for count in [3, 12, 36, 50] {
+        let step = 360.0 / count as f32;
+        let last = materials::rainbow_hue(count - 1, count, 0.0, false);
+        assert!((last - (360.0 - step)).abs() < 1e-3, "{count} triangles : {last}");
+
+        // Sens horaire : même écart, parcouru à l'envers
+        let last_clockwise = materials::rainbow_hue(count - 1, count, 0.0, true);
+        assert!((last_clockwise - step).abs() < 1e-3, "{count} triangles : {last_clockwise}");
+    }
+}
+
+#[test]
+fn interior_gradient_gives_adjacent_triangles_adjacent_hues() {
+    let config = LogoConfig { interior_gradient: true, interior_triangles_count: 6, ..Default::default() };
+    let colors: Vec<Color> = build::build_interior_triangles(&config)
+        .iter()
+        .map(|(_, material, _)| material.color)
+        .collect();
+
+    assert_eq!(colors.len(), 6);
+    for (index, pair) in colors.windows(2).enumerate() {
+        assert!((hue_distance(pair[0], pair[1]) - 60.0).abs() < 0.5, "triangles {index} et {}", index + 1);
+    }
+    // Le dernier rejoint le premier
+    assert!((hue_distance(colors[5], colors[0]) - 60.0).abs() < 0.5);
+}
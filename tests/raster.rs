@@ -0,0 +1,39 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/raster.rs                          ║
+// ║  Tests du rendu sur le processeur                                        ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::color::{Alpha, ColorToPacked};
+use LogoRust_Bevy_20250929::{build, rasterize, LogoConfig};
+
+#[test]
+fn rasterized_logo_colors_pixels_over_the_background() {
+    let config = LogoConfig::default();
+    let image = rasterize(&config, 128, 96);
+    assert_eq!(image.dimensions(), (128, 96));
+
+    let background = config.background_color.to_srgba().to_u8_array();
+    let colored = image.pixels().filter(|pixel| pixel.0 != background).count();
+    assert!(colored > 0);
+    assert!(colored < 128 * 96, "le fond doit rester visible autour de l'anneau");
+
+    // Centre de l'image : "Milieu du R", orange
+    let [red, green, blue, _] = image.get_pixel(64, 48).0;
+    assert!(red > green && green > blue, "{:?}", (red, green, blue));
+}
+
+#[test]
+fn transparent_export_leaves_corners_fully_transparent() {
+    let config = LogoConfig {
+        transparent_export: true,
+        ..LogoConfig::default()
+    };
+    let image = rasterize(&config, 128, 96);
+
+    for (x, y) in [(0, 0), (127, 0), (0, 95), (127, 95)] {
+        assert_eq!(image.get_pixel(x, y).0[3], 0, "coin ({x}, {y})");
+    }
+    // Centre : "Milieu du R", dont l'alpha est conservé tel quel sur fond transparent
+    let letter_alpha = (build::LETTER_COLOR.alpha() * 255.0).round() as u8;
+    assert_eq!(image.get_pixel(64, 48).0[3], letter_alpha, "alpha de la lettre");
+}
@@ -0,0 +1,85 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                       FICHIER: tests/selection.rs                        ║
+// ║  Tests de la sélection des parties du "R"                                ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::geometry::{self, RPartDefinition};
+use LogoRust_Bevy_20250929::systems::selection::{clear_selection_system, marching_ants_system, pick_part};
+use LogoRust_Bevy_20250929::{AnimationState, LogoConfig, RPart, Selected, SelectionOutline};
+
+/// Carré de côté 2 × `half`, centré sur l'origine
+fn square(half: f32) -> RPartDefinition {
+    let points = vec![
+        Vec2::new(-half, -half),
+        Vec2::new(half, -half),
+        Vec2::new(half, half),
+        Vec2::new(-half, half),
+    ];
+    RPartDefinition::new("Carré", points, 0.0)
+}
+
+#[test]
+fn click_picks_the_frontmost_part_under_the_point() {
+    let parts = [square(10.0), square(30.0)];
+    let candidates = || {
+        [
+            ("petit", 0, GlobalTransform::from_xyz(100.0, 0.0, 0.5)),
+            ("grand", 1, GlobalTransform::from_xyz(100.0, 0.0, 0.4)),
+        ]
+    };
+
+    // Le point est ramené dans le repère de la partie (décalée de 100 en X)
+    assert_eq!(pick_part(Vec2::new(105.0, 0.0), &parts, candidates()), Some("petit"));
+    assert_eq!(pick_part(Vec2::new(120.0, 0.0), &parts, candidates()), Some("grand"));
+    assert_eq!(pick_part(Vec2::new(0.0, 0.0), &parts, candidates()), None);
+}
+
+#[test]
+fn click_on_a_real_letter_part_hits_that_part() {
+    let config = LogoConfig::default();
+    let parts = config.letter_parts();
+    let target = geometry::polygon_centroid(&parts[0].points);
+
+    let candidates = parts.iter().enumerate().map(|(index, part)| (index, index, GlobalTransform::from_xyz(0.0, 0.0, part.z_order)));
+    let picked = pick_part(target, &parts, candidates).unwrap();
+    assert!(geometry::point_in_polygon(target, &parts[picked].points));
+}
+
+#[test]
+fn escape_clears_every_selection() {
+    let mut world = World::new();
+    let mut keys = ButtonInput::<KeyCode>::default();
+    keys.press(KeyCode::Escape);
+    world.insert_resource(keys);
+    let part = |index| RPart { name: "Partie".into(), index };
+    world.spawn((part(0), Selected));
+    world.spawn((part(1), Selected));
+
+    world.run_system_once(clear_selection_system).unwrap();
+
+    assert_eq!(world.query_filtered::<(), With<Selected>>().iter(&world).count(), 0);
+}
+
+#[test]
+fn marching_ants_stand_still_while_animations_are_paused() {
+    let mut world = World::new();
+    world.insert_resource(LogoConfig::default());
+    world.init_resource::<Assets<Mesh>>();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_millis(100));
+    world.insert_resource(time);
+    world.insert_resource(AnimationState { paused: true, ..default() });
+    let mesh = world.resource_mut::<Assets<Mesh>>().add(Mesh::from(Rectangle::default()));
+    let outline = world.spawn((SelectionOutline { index: 0, offset: 0.0 }, Mesh2d(mesh))).id();
+
+    world.run_system_once(marching_ants_system).unwrap();
+    assert_eq!(world.get::<SelectionOutline>(outline).unwrap().offset, 0.0);
+
+    world.resource_mut::<AnimationState>().paused = false;
+    world.run_system_once(marching_ants_system).unwrap();
+    assert!(world.get::<SelectionOutline>(outline).unwrap().offset > 0.0);
+}
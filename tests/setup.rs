@@ -0,0 +1,95 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: tests/setup.rs                          ║
+// ║  Tests du système d'initialisation de la scène                           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::systems::setup::setup_system;
+use LogoRust_Bevy_20250929::geometry::RPartDefinition;
+use LogoRust_Bevy_20250929::{LogoConfig, LogoRoot, LogoTile, MainCamera, MainCircle, ROutline, RPart};
+
+/// Monde minimal : les collections d'assets et la configuration suffisent au setup
+fn setup_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<ColorMaterial>>();
+    world.insert_resource(LogoConfig::default());
+    world
+}
+
+#[test]
+fn running_setup_twice_keeps_one_camera_and_one_root() {
+    let mut world = setup_world();
+
+    world.run_system_once(setup_system).unwrap();
+    world.run_system_once(setup_system).unwrap();
+
+    let cameras = world.query_filtered::<(), With<MainCamera>>().iter(&world).count();
+    let roots = world.query_filtered::<(), With<LogoRoot>>().iter(&world).count();
+    assert_eq!(cameras, 1);
+    assert_eq!(roots, 1);
+}
+
+#[test]
+fn tiled_setup_spawns_one_full_logo_per_cell_with_shared_handles() {
+    let mut world = setup_world();
+    world.insert_resource(LogoConfig {
+        tile: Some((2, 3, 20.0)),
+        r_outline_width: 2.0,
+        ..Default::default()
+    });
+
+    world.run_system_once(setup_system).unwrap();
+
+    let offsets: Vec<Vec2> = world.query_filtered::<&LogoTile, With<LogoRoot>>().iter(&world).map(|tile| tile.offset).collect();
+    assert_eq!(offsets.len(), 6);
+    assert!(offsets.iter().enumerate().all(|(i, a)| offsets[i + 1..].iter().all(|b| a != b)));
+
+    // Un anneau par case, tous sur le même mesh et le même matériau
+    let rings: Vec<(AssetId<Mesh>, AssetId<ColorMaterial>)> = world
+        .query_filtered::<(&Mesh2d, &MeshMaterial2d<ColorMaterial>), With<MainCircle>>()
+        .iter(&world)
+        .map(|(mesh, material)| (mesh.id(), material.id()))
+        .collect();
+    assert_eq!(rings.len(), 6);
+    assert!(rings.iter().all(|ring| *ring == rings[0]));
+
+    // Les contours, petits-enfants de la racine, sont copiés aussi
+    let outlines = world.query_filtered::<(), With<ROutline>>().iter(&world).count();
+    assert_eq!(outlines, 6 * 8);
+}
+
+#[test]
+fn tiling_with_zero_rows_spawns_a_single_row() {
+    let mut world = setup_world();
+    world.insert_resource(LogoConfig {
+        tile: Some((0, 3, 10.0)),
+        ..Default::default()
+    });
+
+    world.run_system_once(setup_system).unwrap();
+
+    let roots = world.query_filtered::<(), With<LogoRoot>>().iter(&world).count();
+    assert_eq!(roots, 3);
+}
+
+#[test]
+fn safe_mode_skips_self_intersecting_custom_parts() {
+    let mut world = setup_world();
+    let triangle = vec![Vec2::ZERO, Vec2::new(40.0, 0.0), Vec2::new(0.0, 40.0)];
+    let bowtie = vec![Vec2::ZERO, Vec2::new(40.0, 40.0), Vec2::new(40.0, 0.0), Vec2::new(0.0, 30.0)];
+    world.insert_resource(LogoConfig {
+        custom_parts: Some(vec![
+            RPartDefinition::new("Triangle", triangle, 0.0),
+            RPartDefinition::new("Nœud papillon", bowtie, 0.0),
+        ]),
+        safe_mode: true,
+        ..Default::default()
+    });
+
+    world.run_system_once(setup_system).unwrap();
+
+    let names: Vec<String> = world.query::<&RPart>().iter(&world).map(|part| part.name.to_string()).collect();
+    assert_eq!(names, ["Triangle"]);
+}
@@ -0,0 +1,30 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: tests/stats.rs                           ║
+// ║  Tests des statistiques de rendu                                         ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use LogoRust_Bevy_20250929::{build, geometry, LogoConfig, LogoStats};
+
+#[test]
+fn stats_sum_vertices_and_triangles_of_meshes() {
+    let disc = geometry::create_filled_circle_mesh(10.0, 32);
+    let ring = geometry::create_circle_mesh(100.0, 80.0, 16);
+    let stats = LogoStats::from_meshes([&disc, &ring]);
+
+    assert_eq!(stats.entities, 2);
+    assert_eq!(stats.vertices, 33 + 32);
+    assert_eq!(stats.triangles, 32 + 32);
+}
+
+#[test]
+fn default_logo_stays_within_budget() {
+    let config = LogoConfig::default();
+    let mut meshes = vec![build::build_main_circle(&config).0];
+    meshes.extend(build::build_exterior_triangles(&config).into_iter().map(|(mesh, ..)| mesh));
+    meshes.extend(build::build_interior_triangles(&config).into_iter().map(|(mesh, ..)| mesh));
+    meshes.extend(build::build_center_dots(&config).into_iter().map(|(mesh, ..)| mesh));
+
+    let stats = LogoStats::from_meshes(&meshes);
+    assert_eq!(stats.entities, meshes.len());
+    assert!(stats.triangles < 5_000, "{stats:?}");
+}
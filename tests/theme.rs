@@ -0,0 +1,85 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: tests/theme.rs                          ║
+// ║  Tests du recoloriage à l'exécution                                      ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use LogoRust_Bevy_20250929::build::{self, LayerKind};
+use LogoRust_Bevy_20250929::materials::Theme;
+use LogoRust_Bevy_20250929::systems::theme::{cycle_theme_system, reapply_theme_system, recolor_system};
+use LogoRust_Bevy_20250929::{CurrentTheme, InteriorTriangle, LogoConfig, MainCircle, RecolorEvent};
+
+#[test]
+fn recolor_event_changes_only_the_selected_layer_in_place() {
+    let config = LogoConfig::default();
+    let mut world = World::new();
+    world.init_resource::<Events<RecolorEvent>>();
+
+    // === ANNEAU ET UN TRIANGLE INTÉRIEUR ===
+    let mut assets = Assets::<ColorMaterial>::default();
+    let (_, ring_material, _) = build::build_main_circle(&config);
+    let ring = assets.add(ring_material);
+    let (_, interior_material, _) = build::build_interior_triangles(&config).remove(0);
+    let interior_color = interior_material.color;
+    let interior = assets.add(interior_material);
+    world.insert_resource(assets);
+    world.spawn((MainCircle, MeshMaterial2d(ring.clone())));
+    world.spawn((InteriorTriangle { index: 0 }, MeshMaterial2d(interior.clone())));
+
+    world.send_event(RecolorEvent { selector: LayerKind::MainCircle, color: Color::srgb(0.0, 0.0, 1.0) });
+    world.run_system_once(recolor_system).unwrap();
+
+    let assets = world.resource::<Assets<ColorMaterial>>();
+    assert_eq!(assets.len(), 2);
+    assert_eq!(assets.get(&ring).unwrap().color, Color::srgb(0.0, 0.0, 1.0));
+    assert_eq!(assets.get(&interior).unwrap().color, interior_color);
+}
+
+#[test]
+fn regenerated_elements_take_the_current_theme() {
+    let config = LogoConfig::default();
+    let mut world = World::new();
+    world.insert_resource(CurrentTheme(Theme::Ocean));
+    world.insert_resource(config.clone());
+
+    // Anneau tout juste recréé, aux couleurs d'origine et en plein fondu
+    let mut assets = Assets::<ColorMaterial>::default();
+    let (_, mut ring_material, _) = build::build_main_circle(&config);
+    ring_material.color.set_alpha(0.25);
+    let ring = assets.add(ring_material);
+    world.insert_resource(assets);
+    world.spawn((MainCircle, MeshMaterial2d(ring.clone())));
+
+    world.run_system_once(reapply_theme_system).unwrap();
+
+    let color = world.resource::<Assets<ColorMaterial>>().get(&ring).unwrap().color;
+    assert_eq!(color, Theme::Ocean.ring_color().with_alpha(0.25));
+}
+
+#[test]
+fn cycling_back_to_classic_restores_the_configured_interior_colors() {
+    let config = LogoConfig { random_palette_seed: Some(7), ..Default::default() };
+    let mut world = World::new();
+    world.init_resource::<CurrentTheme>();
+    world.init_resource::<ButtonInput<KeyCode>>();
+    world.insert_resource(config.clone());
+
+    let mut assets = Assets::<ColorMaterial>::default();
+    let (_, interior_material, _) = build::build_interior_triangles(&config).remove(2);
+    let original = interior_material.color;
+    let interior = assets.add(interior_material);
+    world.insert_resource(assets);
+    world.spawn((InteriorTriangle { index: 2 }, MeshMaterial2d(interior.clone())));
+
+    // Un appui sur T par thème : le dernier ramène à Classic
+    for _ in 0..Theme::ALL.len() {
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.reset_all();
+        keys.press(KeyCode::KeyT);
+        world.run_system_once(cycle_theme_system).unwrap();
+    }
+
+    assert_eq!(world.resource::<CurrentTheme>().0, Theme::Classic);
+    assert_eq!(world.resource::<Assets<ColorMaterial>>().get(&interior).unwrap().color, original);
+}